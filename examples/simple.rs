@@ -21,9 +21,9 @@ fn main() {
     let args: Vec<String> = args().collect();
     if args.len() > 1 {
         for arg in &args[1..] {
-            let mut file = File::open(arg).expect(&format!("Failed to open `{}`", arg));
+            let mut file = File::open(arg).unwrap_or_else(|_| panic!("Failed to open `{}`", arg));
             let mut raw_data = vec![];
-            file.read_to_end(&mut raw_data).expect(&format!("Failed to read file `{}`", arg));
+            file.read_to_end(&mut raw_data).unwrap_or_else(|_| panic!("Failed to read file `{}`", arg));
             let data = String::from_utf8_lossy(&raw_data);
             match Vcf::parse(&data) {
                 Err(err) => panic_error(&data, err),
@@ -0,0 +1,291 @@
+//! A directory of the people of a [`Vcf`], grouped by the organization and
+//! units of their `ORG` with their `TITLE` and `ROLE`.
+//!
+//! Organizations, units and people are ordered by a collation that
+//! ignores case and accents, taking `SORT-AS` into account.
+
+use serde_json::{json, Value as Json};
+
+use std::cmp::Ordering;
+
+use crate::parse::{escape_markup, unescape_text};
+use crate::types::Organization;
+use crate::{TypeOrRaw, VCard, Value, Vcf};
+
+/// The organizations of a [`Vcf`], built by [`Vcf::directory`].
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Directory<'v, 'a> {
+    pub organizations: Vec<Unit<'v, 'a>>,
+}
+
+/// An organization or one of its units, with the units below it and the
+/// people directly in it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Unit<'v, 'a> {
+    pub name: String,
+    pub units: Vec<Unit<'v, 'a>>,
+    pub people: Vec<Member<'v, 'a>>,
+    sort_key: String,
+}
+
+/// A person in a [`Unit`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Member<'v, 'a> {
+    pub vcard: &'v VCard<'a>,
+    pub name: String,
+    pub titles: Vec<String>,
+    pub roles: Vec<String>,
+    sort_key: String,
+}
+
+impl<'a> Vcf<'a> {
+    /// Group the vcards by their `ORG` values, a vcard with several
+    /// appearing under each. Vcards without a typed `ORG` naming an
+    /// organization are left out, units after an empty one are ignored.
+    pub fn directory(&self) -> Directory<'_, 'a> {
+        let mut directory = Directory::default();
+        for vcard in &self.0 {
+            let mut paths: Vec<Vec<(String, String)>> = vec![];
+            for property in vcard.iter() {
+                let org = match &property.value {
+                    Value::Organization(TypeOrRaw::Type(org)) => org,
+                    _ => continue,
+                };
+                let path = path(org, &property.params.sort_as());
+                if !path.is_empty() && !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+            for path in paths {
+                let mut units = &mut directory.organizations;
+                let mut people = None;
+                for (name, sort_key) in path {
+                    let index = match units.iter().position(|x| x.name == name) {
+                        Some(index) => index,
+                        None => {
+                            units.push(Unit {
+                                name,
+                                units: vec![],
+                                people: vec![],
+                                sort_key: collation_key(&sort_key),
+                            });
+                            units.len() - 1
+                        }
+                    };
+                    let unit = &mut units[index];
+                    people = Some(&mut unit.people);
+                    units = &mut unit.units;
+                }
+                if let Some(people) = people {
+                    people.push(member(vcard));
+                }
+            }
+        }
+        sort(&mut directory.organizations);
+        directory
+    }
+}
+
+/// The parts of an `ORG` with the keys to sort them by, up to the first
+/// empty one since the units after it have nothing to hang from.
+fn path(org: &Organization, sort_as: &[String]) -> Vec<(String, String)> {
+    org.parts()
+        .zip(org.sort_keys(sort_as))
+        .take_while(|(part, _)| !part.is_empty())
+        .map(|(part, key)| (part.to_string(), key))
+        .collect()
+}
+
+fn member<'v, 'a>(vcard: &'v VCard<'a>) -> Member<'v, 'a> {
+    let texts = |f: fn(&Value) -> bool| {
+        vcard
+            .iter()
+            .filter(|x| f(&x.value))
+            .map(|x| unescape_text(&x.value.to_string()))
+            .collect::<Vec<_>>()
+    };
+    let name = texts(|x| matches!(x, Value::Fn(_)))
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    Member {
+        vcard,
+        sort_key: collation_key(&person_sort_key(vcard).unwrap_or_else(|| name.clone())),
+        name,
+        titles: texts(|x| matches!(x, Value::Title(_))),
+        roles: texts(|x| matches!(x, Value::Role(_))),
+    }
+}
+
+/// The family then given names of `N`, or its `SORT-AS`.
+fn person_sort_key(vcard: &VCard) -> Option<String> {
+    vcard.iter().find_map(|x| match &x.value {
+        Value::N(TypeOrRaw::Type(name)) => {
+            let sort_as = x.params.sort_as();
+            let key = if sort_as.is_empty() {
+                name.surname
+                    .iter()
+                    .chain(&name.given)
+                    .map(|x| unescape_text(x))
+                    .collect()
+            } else {
+                sort_as
+            };
+            Some(key.join(" ")).filter(|x| !x.trim().is_empty())
+        }
+        _ => None,
+    })
+}
+
+fn sort(units: &mut [Unit]) {
+    units.sort_by(|a, b| collate(&a.sort_key, &a.name, &b.sort_key, &b.name));
+    for unit in units {
+        unit.people
+            .sort_by(|a, b| collate(&a.sort_key, &a.name, &b.sort_key, &b.name));
+        sort(&mut unit.units);
+    }
+}
+
+fn collate(a_key: &str, a: &str, b_key: &str, b: &str) -> Ordering {
+    a_key.cmp(b_key).then_with(|| a.cmp(b))
+}
+
+/// Fold case and the accents of Latin letters, so `Émile` sorts with
+/// `emile` before `Zoë`.
+fn collation_key(text: &str) -> String {
+    let mut key = String::with_capacity(text.len());
+    for x in text.trim().chars().flat_map(char::to_lowercase) {
+        let folded = match x {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+            'æ' => "ae",
+            'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+            'ď' | 'đ' | 'ð' => "d",
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+            'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+            'ĥ' | 'ħ' => "h",
+            'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+            'ĵ' => "j",
+            'ķ' => "k",
+            'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+            'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+            'œ' => "oe",
+            'ŕ' | 'ŗ' | 'ř' => "r",
+            'ś' | 'ŝ' | 'ş' | 'š' => "s",
+            'ß' => "ss",
+            'ţ' | 'ť' | 'ŧ' => "t",
+            'þ' => "th",
+            'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+            'ŵ' => "w",
+            'ý' | 'ÿ' | 'ŷ' => "y",
+            'ź' | 'ż' | 'ž' => "z",
+            _ => {
+                key.push(x);
+                continue;
+            }
+        };
+        key.push_str(folded);
+    }
+    key
+}
+
+impl<'v, 'a> Directory<'v, 'a> {
+    /// The directory as indented text, two spaces a level, with each
+    /// person's titles after their name and roles in brackets.
+    pub fn to_text(&self) -> String {
+        let mut output = String::new();
+        for org in &self.organizations {
+            write_text(&mut output, org, 0);
+        }
+        output
+    }
+
+    /// The directory as JSON, each unit an object with `name`, `units` and
+    /// `people` and each person one with `name`, `titles` and `roles`.
+    pub fn to_json(&self) -> Json {
+        json!({ "organizations": self.organizations.iter().map(unit_json).collect::<Vec<_>>() })
+    }
+
+    /// The directory as nested HTML lists.
+    pub fn to_html(&self) -> String {
+        let mut output = String::from("<ul class=\"directory\">\n");
+        for org in &self.organizations {
+            write_html(&mut output, org, "org");
+        }
+        output.push_str("</ul>\n");
+        output
+    }
+}
+
+impl<'v, 'a> Member<'v, 'a> {
+    /// The name followed by the titles and the roles in brackets, such as
+    /// `Jane Doe, Director (Project Lead)`.
+    fn line(&self) -> String {
+        let mut line = self.name.clone();
+        if !self.titles.is_empty() {
+            line.push_str(&format!(", {}", self.titles.join(", ")));
+        }
+        if !self.roles.is_empty() {
+            line.push_str(&format!(" ({})", self.roles.join(", ")));
+        }
+        line
+    }
+}
+
+fn write_text(output: &mut String, unit: &Unit, depth: usize) {
+    let indent = "  ".repeat(depth);
+    output.push_str(&format!("{}{}\n", indent, unit.name));
+    for person in &unit.people {
+        output.push_str(&format!("{}  - {}\n", indent, person.line()));
+    }
+    for child in &unit.units {
+        write_text(output, child, depth + 1);
+    }
+}
+
+fn unit_json(unit: &Unit) -> Json {
+    let people = unit
+        .people
+        .iter()
+        .map(|x| json!({ "name": x.name, "titles": x.titles, "roles": x.roles }));
+    json!({
+        "name": unit.name,
+        "units": unit.units.iter().map(unit_json).collect::<Vec<_>>(),
+        "people": people.collect::<Vec<_>>(),
+    })
+}
+
+fn write_html(output: &mut String, unit: &Unit, class: &str) {
+    output.push_str(&format!(
+        "<li class=\"{}\"><span class=\"name\">{}</span>\n",
+        class,
+        escape_markup(&unit.name)
+    ));
+    if !unit.people.is_empty() || !unit.units.is_empty() {
+        output.push_str("<ul>\n");
+        for person in &unit.people {
+            output.push_str(&format!(
+                "<li class=\"person\"><span class=\"name\">{}</span>",
+                escape_markup(&person.name)
+            ));
+            for title in &person.titles {
+                output.push_str(&format!(
+                    " <span class=\"title\">{}</span>",
+                    escape_markup(title)
+                ));
+            }
+            for role in &person.roles {
+                output.push_str(&format!(
+                    " <span class=\"role\">{}</span>",
+                    escape_markup(role)
+                ));
+            }
+            output.push_str("</li>\n");
+        }
+        for child in &unit.units {
+            write_html(output, child, "unit");
+        }
+        output.push_str("</ul>\n");
+    }
+    output.push_str("</li>\n");
+}
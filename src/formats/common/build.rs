@@ -0,0 +1,29 @@
+//! Building properties from the parts other formats hold them in.
+
+use crate::{formats::FormatError, IntoOwned, Parse, Property};
+
+/// Build a property from its parts, the value must already be escaped
+/// as vcard text.
+pub(crate) fn build_property(
+    name: &str,
+    params: &[(String, String)],
+    value: &str,
+) -> Result<Property<'static>, FormatError> {
+    let mut line = name.to_string();
+    for (name, value) in params {
+        line.push(';');
+        line.push_str(name);
+        line.push('=');
+        line.push_str(value);
+    }
+    line.push(':');
+    line.push_str(value);
+    line.push('\n');
+    match Property::parse(&line) {
+        Ok((_, property)) => Ok(property.into_owned()),
+        Err(nom::Err::Error(err)) | Err(nom::Err::Failure(err)) => {
+            Err(FormatError::Property(err.display(&line)))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(FormatError::Property(line.trim_end().into())),
+    }
+}
@@ -0,0 +1,88 @@
+//! Dates and times between the basic ISO 8601 format of vcard and the
+//! extended one of most other formats.
+
+/// Convert a date and/or time from the basic ISO 8601 format used by vcard
+/// into the extended format used by jCard and most other formats.
+pub(crate) fn extended_date_and_or_time(ty: &str, value: &str) -> String {
+    if ty == "time" || ty == "utc-offset" {
+        return extended_time(value);
+    }
+    match value.find('T') {
+        Some(index) => format!(
+            "{}T{}",
+            extended_date(&value[..index]),
+            extended_time(&value[index + 1..])
+        ),
+        None => extended_date(value),
+    }
+}
+
+fn extended_date(date: &str) -> String {
+    let digits = date.trim_start_matches('-');
+    if digits.contains('-') || !digits.chars().all(|x| x.is_ascii_digit()) {
+        return date.into();
+    }
+    match (date.len() - digits.len(), digits.len()) {
+        (0, 8) => format!("{}-{}-{}", &digits[..4], &digits[4..6], &digits[6..]),
+        (2, 4) => format!("--{}-{}", &digits[..2], &digits[2..]),
+        _ => date.into(),
+    }
+}
+
+fn extended_time(time: &str) -> String {
+    if time.contains(':') {
+        return time.into();
+    }
+    let split = time
+        .char_indices()
+        .skip(1)
+        .find(|(_, x)| "Z+-".contains(*x))
+        .map(|(index, _)| index)
+        .unwrap_or(time.len());
+    let split = if time.starts_with(['+', 'Z']) {
+        0
+    } else {
+        split
+    };
+    let (local, zone) = time.split_at(split);
+    let digits = local.trim_start_matches('-');
+    let mut output = local[..local.len() - digits.len()].to_string();
+    output.push_str(&colon_pairs(digits));
+    match zone.chars().next() {
+        Some(sign) if sign == '+' || sign == '-' => {
+            output.push(sign);
+            output.push_str(&colon_pairs(&zone[1..]));
+        }
+        _ => output.push_str(zone),
+    }
+    output
+}
+
+fn colon_pairs(digits: &str) -> String {
+    if !digits.chars().all(|x| x.is_ascii_digit()) {
+        return digits.into();
+    }
+    digits
+        .as_bytes()
+        .chunks(2)
+        .map(|x| String::from_utf8_lossy(x).into_owned())
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Convert a date and/or time from the extended ISO 8601 format used by
+/// jCard and most other formats into the basic format used by vcard.
+pub(crate) fn basic_date_and_or_time(ty: &str, value: &str) -> String {
+    let (date, time) = match value.find('T') {
+        Some(index) => (&value[..index], Some(&value[index..])),
+        None if ty == "time" || ty == "utc-offset" => ("", Some(value)),
+        None => (value, None),
+    };
+    let digits = date.trim_start_matches('-');
+    let mut output = date[..date.len() - digits.len()].to_string();
+    output.push_str(&digits.replace('-', ""));
+    if let Some(time) = time {
+        output.push_str(&time.replace(':', ""));
+    }
+    output
+}
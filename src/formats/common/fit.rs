@@ -0,0 +1,30 @@
+//! Leaving items out until the rest fits a size limited format.
+
+/// Remove items, highest `priority` and last first, until `fits` accepts the
+/// rest, replacing an item by the smaller one `shrink` makes of it instead
+/// while it can. Returns the items left and the dropped ones, or `None` if
+/// only those of priority `0` are left and they still do not fit.
+pub(crate) fn drop_to_fit<T>(
+    mut items: Vec<T>,
+    priority: impl Fn(&T) -> u8,
+    mut shrink: impl FnMut(&T) -> Option<T>,
+    mut fits: impl FnMut(&[T]) -> bool,
+) -> Option<(Vec<T>, Vec<T>)> {
+    let mut dropped = vec![];
+    loop {
+        if fits(&items) {
+            return Some((items, dropped));
+        }
+        let index = items
+            .iter()
+            .enumerate()
+            .map(|(index, x)| (priority(x), index))
+            .filter(|(priority, _)| *priority > 0)
+            .max()
+            .map(|(_, index)| index)?;
+        match shrink(&items[index]) {
+            Some(smaller) => items[index] = smaller,
+            None => dropped.push(items.remove(index)),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Parameters and value types of the jCard and xCard representations.
+
+/// Quote a parameter value if it contains characters that would end it.
+pub(crate) fn quote_parameter_value(value: &str) -> String {
+    if value.contains(|x| ":;,".contains(x)) {
+        format!("\"{}\"", value)
+    } else {
+        value.into()
+    }
+}
+
+/// Remove the surrounding quotes of a parameter value.
+pub(crate) fn unquote_parameter_value(value: &str) -> &str {
+    if value.len() > 1 && value.starts_with('"') && value.ends_with('"') {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    }
+}
+
+/// The value type of a property when no `VALUE` parameter is given, `name`
+/// must be lowercase.
+pub(crate) fn default_value_type(name: &str) -> &'static str {
+    match name {
+        "source" | "photo" | "impp" | "geo" | "logo" | "member" | "related" | "sound" | "uid"
+        | "url" | "key" | "fburl" | "caladrurl" | "calurl" | "caladruri" | "caluri" => "uri",
+        "bday" | "anniversary" | "deathdate" => "date-and-or-time",
+        "rev" => "timestamp",
+        "lang" => "language-tag",
+        "kind" | "xml" | "fn" | "n" | "nickname" | "gender" | "adr" | "tel" | "email" | "tz"
+        | "title" | "role" | "org" | "categories" | "note" | "prodid" | "clientpidmap"
+        | "version" => "text",
+        _ => "unknown",
+    }
+}
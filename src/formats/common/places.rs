@@ -0,0 +1,28 @@
+//! The name and address of a vcard for the map formats.
+
+use crate::parse::{split_unescaped, unescape_text};
+use crate::{VCard, Value};
+
+/// The unescaped `FN` of a vcard.
+pub(crate) fn formatted_name(vcard: &VCard) -> Option<String> {
+    vcard
+        .iter()
+        .find(|x| matches!(x.value, Value::Fn(_)))
+        .map(|x| unescape_text(&x.value.to_string()))
+}
+
+/// The postal address of a vcard on one line, from the `ADR` with a `GEO`
+/// parameter or else the first one.
+pub(crate) fn address_line(vcard: &VCard) -> Option<String> {
+    let addresses = || vcard.iter().filter(|x| matches!(x.value, Value::Adr(_)));
+    let address = addresses()
+        .find(|x| x.params.geo().is_some())
+        .or_else(|| addresses().next())?;
+    let line = split_unescaped(&address.value.to_string(), ';')
+        .into_iter()
+        .map(unescape_text)
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(line).filter(|x| !x.is_empty())
+}
@@ -0,0 +1,13 @@
+//! The plain text of values, for formats without vcard escaping.
+
+use crate::parse::unescape_text;
+use crate::{TypeOrRaw, Value};
+
+/// The unescaped text of a value, the bare address for an `EMAIL` even when
+/// it is written as a `mailto:` URI.
+pub(crate) fn plain_text(value: &Value) -> String {
+    match value {
+        Value::Email(TypeOrRaw::Type(email)) => email.address(),
+        _ => unescape_text(&value.to_string()),
+    }
+}
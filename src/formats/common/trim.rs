@@ -0,0 +1,138 @@
+//! Trimming vcards to fit a size limited format.
+
+use super::fit::drop_to_fit;
+use crate::types::Image;
+use crate::{Property, TypeOrRaw, VCard, Value};
+
+/// How readily a size limited format leaves a property out, higher is
+/// dropped first and `0` never. Embedded binary data goes before anything
+/// else.
+pub(crate) fn drop_priority(property: &Property) -> u8 {
+    let embedded = property.params.0.iter().any(|x| {
+        x.name.eq_ignore_ascii_case("ENCODING")
+            && matches!(x.value.as_deref(), Some(v) if v.eq_ignore_ascii_case("b") || v.eq_ignore_ascii_case("base64"))
+    }) || property
+        .value
+        .to_string()
+        .get(..5)
+        .map(|x| x.eq_ignore_ascii_case("data:"))
+        == Some(true);
+    if embedded {
+        return 9;
+    }
+    match property.value {
+        Value::Begin | Value::End | Value::Version(_) | Value::Fn(_) | Value::N(_) => 0,
+        Value::Tel(_) | Value::Email(_) => 1,
+        Value::Organization(_) | Value::Title(_) | Value::Role(_) => 2,
+        Value::Adr(_) | Value::Url(_) => 3,
+        Value::Nickname(_)
+        | Value::Birthday(_)
+        | Value::Anniversary(_)
+        | Value::DeathDate(_)
+        | Value::Impp(_)
+        | Value::SocialProfile(_)
+        | Value::Gender(_)
+        | Value::Kind(_)
+        | Value::Lang(_)
+        | Value::Member(_)
+        | Value::Related(_) => 4,
+        Value::Geo(_)
+        | Value::Timezone(_)
+        | Value::Categories(_)
+        | Value::Uid(_)
+        | Value::Rev(_)
+        | Value::ProdID(_)
+        | Value::Source(_)
+        | Value::ClientPIDMap(_)
+        | Value::FbUrl(_)
+        | Value::CalAdrURL(_)
+        | Value::CalURL(_) => 5,
+        Value::Other(_, _) | Value::Xml(_) => 6,
+        Value::Note(_) => 7,
+        Value::Photo(_) | Value::Logo(_) | Value::Sound(_) | Value::Key(_) => 8,
+    }
+}
+
+/// Shrink and remove properties of a vcard by [`drop_priority`] with
+/// [`drop_to_fit`] until `fits` accepts it. Returns the trimmed vcard and
+/// the names of the dropped properties.
+pub(crate) fn trim_to_fit<'a>(
+    vcard: &VCard<'a>,
+    mut fits: impl FnMut(&VCard<'a>) -> bool,
+) -> Option<(VCard<'a>, Vec<String>)> {
+    let (properties, dropped) =
+        drop_to_fit(vcard.0.clone(), drop_priority, shrink_image, |properties| {
+            fits(&VCard(properties.to_vec()))
+        })?;
+    let dropped = dropped.iter().map(|x| x.value.name_raw().to_string());
+    Some((VCard(properties), dropped.collect()))
+}
+
+/// Embedded images are not shrunk below this many pixels on their longest
+/// side.
+const MIN_IMAGE_SIDE: u32 = 16;
+
+/// An embedded `PHOTO` or `LOGO` at half its width and height, as a JPEG.
+/// `None` for anything else, data that does not decode as an image or an
+/// image already at [`MIN_IMAGE_SIDE`].
+fn shrink_image<'a>(property: &Property<'a>) -> Option<Property<'a>> {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use image::codecs::jpeg::JpegEncoder;
+
+    let binary = property.params.0.iter().any(|x| {
+        x.name.eq_ignore_ascii_case("ENCODING")
+            && matches!(x.value.as_deref(), Some(v) if v.eq_ignore_ascii_case("b"))
+    });
+    let (embedded, data) = match &property.value {
+        Value::Photo(embedded) | Value::Logo(embedded) => match embedded {
+            TypeOrRaw::Type(Image::Data { encoding, data, .. })
+                if encoding.eq_ignore_ascii_case("base64") =>
+            {
+                (embedded, data.as_ref())
+            }
+            TypeOrRaw::Raw(data) if binary => (embedded, data.as_ref()),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let data: String = data.chars().filter(|x| !x.is_whitespace()).collect();
+    let decoded = image::load_from_memory(&STANDARD.decode(data).ok()?).ok()?;
+    if decoded.width().max(decoded.height()) <= MIN_IMAGE_SIDE {
+        return None;
+    }
+    let smaller = decoded
+        .thumbnail((decoded.width() / 2).max(1), (decoded.height() / 2).max(1))
+        .to_rgb8();
+    let mut jpeg = vec![];
+    JpegEncoder::new_with_quality(&mut jpeg, 75)
+        .encode(
+            &smaller,
+            smaller.width(),
+            smaller.height(),
+            image::ColorType::Rgb8,
+        )
+        .ok()?;
+    let data = STANDARD.encode(jpeg);
+    let embedded = match embedded {
+        TypeOrRaw::Type(_) => TypeOrRaw::Type(Image::Data {
+            ty: "image/jpeg".into(),
+            encoding: "base64".into(),
+            data: data.into(),
+        }),
+        TypeOrRaw::Raw(_) => TypeOrRaw::Raw(data.into()),
+    };
+    let mut property = property.clone();
+    property.value = match property.value {
+        Value::Logo(_) => Value::Logo(embedded),
+        _ => Value::Photo(embedded),
+    };
+    if binary {
+        for param in &mut property.params.0 {
+            if param.name.eq_ignore_ascii_case("TYPE") {
+                param.value = Some("JPEG".into());
+            }
+        }
+    }
+    Some(property)
+}
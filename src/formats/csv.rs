@@ -0,0 +1,600 @@
+//! CSV reading and writing with configurable columns.
+//!
+//! Every row holds one vcard, the [`Columns`] decide which property each
+//! column is written from and read into. [`Columns::google`] and
+//! [`Columns::outlook`] match the layouts used by Google Contacts and
+//! Outlook, custom spreadsheets can be described with [`Columns::column`].
+
+use std::collections::BTreeMap;
+
+use super::common::{build::build_property, text::plain_text};
+use super::FormatError;
+use crate::parse::{escape_text, split_unescaped, unescape_text};
+use crate::types::Date;
+use crate::{Property, TypeOrRaw, VCard, Value, Vcf};
+
+/// Selects one of the properties of a vcard with the same name.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+pub struct Selector {
+    /// `TYPE` values the property must have, they are set when reading.
+    pub types: Vec<String>,
+    /// `TYPE` values the property must not have.
+    pub excluded: Vec<String>,
+    /// Position among the properties having those types.
+    pub index: usize,
+}
+
+impl Selector {
+    /// The property at `index`, whatever its types.
+    pub fn nth(index: usize) -> Selector {
+        Selector {
+            types: vec![],
+            excluded: vec![],
+            index,
+        }
+    }
+
+    /// The first property having every one of `types`.
+    pub fn typed(types: &[&str]) -> Selector {
+        Selector {
+            types: types.iter().map(|x| x.to_string()).collect(),
+            excluded: vec![],
+            index: 0,
+        }
+    }
+
+    /// Skip the properties having any of `types`.
+    pub fn excluding(mut self, types: &[&str]) -> Selector {
+        self.excluded = types.iter().map(|x| x.to_string()).collect();
+        self
+    }
+}
+
+/// A component of an `ADR` property.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AddressPart {
+    PoBox,
+    Extended,
+    Street,
+    Locality,
+    Region,
+    Code,
+    Country,
+}
+
+/// What a column holds.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Field {
+    FormattedName,
+    FamilyName,
+    GivenName,
+    AdditionalNames,
+    HonorificPrefixes,
+    HonorificSuffixes,
+    Nickname,
+    /// The organization name, the first `ORG` component.
+    Organization,
+    /// The second `ORG` component.
+    Department,
+    Title,
+    Role,
+    Email(Selector),
+    /// The types of the email at that position, in the Google Contacts style
+    /// (`* Work ::: Home`, the star marking the preferred one).
+    EmailType(usize),
+    Tel(Selector),
+    /// The types of the telephone at that position, see [`Field::EmailType`].
+    TelType(usize),
+    Address(Selector, AddressPart),
+    /// The types of the address at that position, see [`Field::EmailType`].
+    AddressType(usize),
+    /// The url at that position.
+    Url(usize),
+    Birthday,
+    Note,
+    /// Every category, separated by `;`.
+    Categories,
+    /// The first property with this name, its value written unescaped.
+    Property(String),
+}
+
+/// The columns of a CSV file, in order.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Columns(pub Vec<(String, Field)>);
+
+impl Columns {
+    pub fn new() -> Columns {
+        Columns::default()
+    }
+
+    /// Add a column.
+    pub fn column<S: Into<String>>(mut self, header: S, field: Field) -> Columns {
+        self.0.push((header.into(), field));
+        self
+    }
+
+    /// The Google Contacts CSV layout.
+    pub fn google() -> Columns {
+        let mut columns = Columns::new()
+            .column("Name", Field::FormattedName)
+            .column("Given Name", Field::GivenName)
+            .column("Additional Name", Field::AdditionalNames)
+            .column("Family Name", Field::FamilyName)
+            .column("Nickname", Field::Nickname)
+            .column("Name Prefix", Field::HonorificPrefixes)
+            .column("Name Suffix", Field::HonorificSuffixes)
+            .column("Birthday", Field::Birthday)
+            .column("Notes", Field::Note);
+        for index in 0..2 {
+            let n = index + 1;
+            columns = columns
+                .column(format!("E-mail {} - Type", n), Field::EmailType(index))
+                .column(
+                    format!("E-mail {} - Value", n),
+                    Field::Email(Selector::nth(index)),
+                );
+        }
+        for index in 0..3 {
+            let n = index + 1;
+            columns = columns
+                .column(format!("Phone {} - Type", n), Field::TelType(index))
+                .column(
+                    format!("Phone {} - Value", n),
+                    Field::Tel(Selector::nth(index)),
+                );
+        }
+        let address = |part| Field::Address(Selector::nth(0), part);
+        columns
+            .column("Address 1 - Type", Field::AddressType(0))
+            .column("Address 1 - Street", address(AddressPart::Street))
+            .column("Address 1 - City", address(AddressPart::Locality))
+            .column("Address 1 - PO Box", address(AddressPart::PoBox))
+            .column("Address 1 - Region", address(AddressPart::Region))
+            .column("Address 1 - Postal Code", address(AddressPart::Code))
+            .column("Address 1 - Country", address(AddressPart::Country))
+            .column(
+                "Address 1 - Extended Address",
+                address(AddressPart::Extended),
+            )
+            .column("Organization 1 - Name", Field::Organization)
+            .column("Organization 1 - Title", Field::Title)
+            .column("Organization 1 - Department", Field::Department)
+            .column("Website 1 - Value", Field::Url(0))
+    }
+
+    /// The Outlook CSV layout.
+    pub fn outlook() -> Columns {
+        let mut columns = Columns::new()
+            .column("Title", Field::HonorificPrefixes)
+            .column("First Name", Field::GivenName)
+            .column("Middle Name", Field::AdditionalNames)
+            .column("Last Name", Field::FamilyName)
+            .column("Suffix", Field::HonorificSuffixes)
+            .column("Nickname", Field::Nickname)
+            .column("Company", Field::Organization)
+            .column("Department", Field::Department)
+            .column("Job Title", Field::Title);
+        for (prefix, ty) in &[("Business", "work"), ("Home", "home")] {
+            let address = |part| Field::Address(Selector::typed(&[ty]), part);
+            columns = columns
+                .column(format!("{} Street", prefix), address(AddressPart::Street))
+                .column(format!("{} City", prefix), address(AddressPart::Locality))
+                .column(format!("{} State", prefix), address(AddressPart::Region))
+                .column(
+                    format!("{} Postal Code", prefix),
+                    address(AddressPart::Code),
+                )
+                .column(
+                    format!("{} Country/Region", prefix),
+                    address(AddressPart::Country),
+                );
+        }
+        columns
+            .column(
+                "Business Fax",
+                Field::Tel(Selector::typed(&["work", "fax"])),
+            )
+            .column("Business Phone", Field::Tel(voice(&["work"])))
+            .column("Home Phone", Field::Tel(voice(&["home"])))
+            .column("Mobile Phone", Field::Tel(voice(&["cell"])))
+            .column(
+                "Other Phone",
+                Field::Tel(Selector::nth(0).excluding(&["work", "home", "cell", "fax", "pager"])),
+            )
+            .column("Birthday", Field::Birthday)
+            .column("Categories", Field::Categories)
+            .column("E-mail Address", Field::Email(Selector::nth(0)))
+            .column("E-mail 2 Address", Field::Email(Selector::nth(1)))
+            .column("E-mail 3 Address", Field::Email(Selector::nth(2)))
+            .column("Notes", Field::Note)
+            .column("Web Page", Field::Url(0))
+    }
+}
+
+/// A telephone with `types` that is not a fax or pager.
+fn voice(types: &[&str]) -> Selector {
+    Selector::typed(types).excluding(&["fax", "pager"])
+}
+
+/// Write every vcard as a CSV row.
+pub fn vcf_to_string(vcf: &Vcf, columns: &Columns) -> String {
+    let mut writer = ::csv::Writer::from_writer(vec![]);
+    let headers = columns.0.iter().map(|(header, _)| header.as_str());
+    // Writing into memory cannot fail.
+    writer.write_record(headers).unwrap();
+    for vcard in &vcf.0 {
+        let row = columns.0.iter().map(|(_, field)| write_field(vcard, field));
+        writer.write_record(row.collect::<Vec<_>>()).unwrap();
+    }
+    String::from_utf8(writer.into_inner().unwrap()).unwrap()
+}
+
+/// Read a vcard from every CSV row, columns are matched by header and the
+/// ones not listed in `columns` are ignored.
+pub fn vcf_from_str(input: &str, columns: &Columns) -> Result<Vcf<'static>, FormatError> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(input.as_bytes());
+    let fields = reader
+        .headers()
+        .map_err(csv_error)?
+        .iter()
+        .map(|header| {
+            columns
+                .0
+                .iter()
+                .find(|(name, _)| name.trim().eq_ignore_ascii_case(header.trim()))
+                .map(|(_, field)| field)
+        })
+        .collect::<Vec<_>>();
+    let mut vcards = vec![];
+    for record in reader.records() {
+        let record = record.map_err(csv_error)?;
+        let cells = fields
+            .iter()
+            .zip(record.iter())
+            .filter_map(|(field, cell)| Some(((*field)?, cell.trim())))
+            .filter(|(_, cell)| !cell.is_empty())
+            .collect::<Vec<_>>();
+        if !cells.is_empty() {
+            vcards.push(read_row(&cells)?);
+        }
+    }
+    Ok(Vcf(vcards))
+}
+
+fn csv_error(err: ::csv::Error) -> FormatError {
+    FormatError::Syntax(err.to_string())
+}
+
+fn address_index(part: AddressPart) -> usize {
+    match part {
+        AddressPart::PoBox => 0,
+        AddressPart::Extended => 1,
+        AddressPart::Street => 2,
+        AddressPart::Locality => 3,
+        AddressPart::Region => 4,
+        AddressPart::Code => 5,
+        AddressPart::Country => 6,
+    }
+}
+
+fn select<'v, 'a, F>(vcard: &'v VCard<'a>, selector: &Selector, f: F) -> Option<&'v Property<'a>>
+where
+    F: Fn(&Value) -> bool,
+{
+    vcard
+        .iter()
+        .filter(|x| f(&x.value))
+        .filter(|x| {
+            let types = x.params.types();
+            selector.types.iter().all(|ty| types.contains(ty))
+                && !selector.excluded.iter().any(|ty| types.contains(ty))
+        })
+        .nth(selector.index)
+}
+
+/// Unescaped component of a structured value.
+fn component(value: &Value, index: usize) -> String {
+    split_unescaped(&value.to_string(), ';')
+        .get(index)
+        .map(|x| {
+            split_unescaped(x, ',')
+                .into_iter()
+                .map(unescape_text)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .unwrap_or_default()
+}
+
+fn google_types(property: &Property) -> String {
+    let names = property
+        .params
+        .types()
+        .into_iter()
+        .filter(|x| x != "pref" && x != "voice" && x != "internet")
+        .map(|ty| match ty.as_str() {
+            "cell" => "Mobile".to_string(),
+            _ => {
+                let mut chars = ty.chars();
+                chars
+                    .next()
+                    .map(|x| x.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ::: ");
+    if property.params.preference() == Some(1) {
+        format!("* {}", names)
+    } else {
+        names
+    }
+}
+
+/// A date in the extended ISO 8601 format spreadsheets use, `1985-04-12` or
+/// `--04-12` without a year.
+fn extended_date(date: &Date) -> String {
+    match (date.year, date.month, date.day) {
+        (Some(year), Some(month), Some(day)) => format!("{:04}-{:02}-{:02}", year, month, day),
+        (None, Some(month), Some(day)) => format!("--{:02}-{:02}", month, day),
+        _ => date.to_string(),
+    }
+}
+
+fn write_field(vcard: &VCard, field: &Field) -> String {
+    let find = |f: &dyn Fn(&Value) -> bool| vcard.iter().find(|x| f(&x.value));
+    let text = |property: Option<&Property>| {
+        property.map(|x| plain_text(&x.value)).unwrap_or_default()
+    };
+    let name = |index| {
+        find(&|x| matches!(x, Value::N(_)))
+            .map(|x| component(&x.value, index))
+            .unwrap_or_default()
+    };
+    let is_email = |x: &Value| matches!(x, Value::Email(_));
+    let is_tel = |x: &Value| matches!(x, Value::Tel(_));
+    let is_adr = |x: &Value| matches!(x, Value::Adr(_));
+    match field {
+        Field::FormattedName => text(find(&|x| matches!(x, Value::Fn(_)))),
+        Field::FamilyName => name(0),
+        Field::GivenName => name(1),
+        Field::AdditionalNames => name(2),
+        Field::HonorificPrefixes => name(3),
+        Field::HonorificSuffixes => name(4),
+        Field::Nickname => match find(&|x| matches!(x, Value::Nickname(_))) {
+            Some(Property {
+                value: Value::Nickname(items),
+                ..
+            }) => items
+                .iter()
+                .map(|x| unescape_text(x))
+                .collect::<Vec<_>>()
+                .join(", "),
+            _ => String::new(),
+        },
+        Field::Organization | Field::Department => {
+            let index = if *field == Field::Organization { 0 } else { 1 };
+            find(&|x| matches!(x, Value::Organization(_)))
+                .map(|x| component(&x.value, index))
+                .unwrap_or_default()
+        }
+        Field::Title => text(find(&|x| matches!(x, Value::Title(_)))),
+        Field::Role => text(find(&|x| matches!(x, Value::Role(_)))),
+        Field::Email(selector) => text(select(vcard, selector, is_email)),
+        Field::Tel(selector) => text(select(vcard, selector, is_tel)),
+        Field::EmailType(index) => select(vcard, &Selector::nth(*index), is_email)
+            .map(google_types)
+            .unwrap_or_default(),
+        Field::TelType(index) => select(vcard, &Selector::nth(*index), is_tel)
+            .map(google_types)
+            .unwrap_or_default(),
+        Field::AddressType(index) => select(vcard, &Selector::nth(*index), is_adr)
+            .map(google_types)
+            .unwrap_or_default(),
+        Field::Address(selector, part) => select(vcard, selector, is_adr)
+            .map(|x| component(&x.value, address_index(*part)))
+            .unwrap_or_default(),
+        Field::Url(index) => vcard
+            .iter()
+            .filter(|x| matches!(x.value, Value::Url(_)))
+            .nth(*index)
+            .map(|x| x.value.to_string())
+            .unwrap_or_default(),
+        Field::Birthday => {
+            let birthday = find(&|x| matches!(x, Value::Birthday(_)));
+            let date = match birthday.map(|x| &x.value) {
+                Some(Value::Birthday(TypeOrRaw::Type(date))) => date.date(),
+                _ => None,
+            };
+            date.map(extended_date).unwrap_or_else(|| text(birthday))
+        }
+        Field::Note => text(find(&|x| matches!(x, Value::Note(_)))),
+        Field::Categories => match find(&|x| matches!(x, Value::Categories(_))) {
+            Some(Property {
+                value: Value::Categories(items),
+                ..
+            }) => items
+                .iter()
+                .map(|x| unescape_text(x))
+                .collect::<Vec<_>>()
+                .join(";"),
+            _ => String::new(),
+        },
+        Field::Property(name) => text(
+            vcard
+                .iter()
+                .find(|x| x.value.name_raw().eq_ignore_ascii_case(name)),
+        ),
+    }
+}
+
+/// Properties with several instances, keyed by their selector.
+#[derive(Default)]
+struct Entry {
+    value: Vec<String>,
+    types: Vec<String>,
+    preferred: bool,
+}
+
+impl Entry {
+    fn set_types(&mut self, cell: &str) {
+        let cell = match cell.strip_prefix('*') {
+            Some(cell) => {
+                self.preferred = true;
+                cell
+            }
+            None => cell,
+        };
+        for ty in cell.split(":::").map(|x| x.trim().to_lowercase()) {
+            let ty = match ty.as_str() {
+                "" | "other" => continue,
+                "mobile" => "cell".to_string(),
+                _ => ty,
+            };
+            if !self.types.contains(&ty) {
+                self.types.push(ty);
+            }
+        }
+    }
+
+    fn params(&self, selector: &Selector) -> Vec<(String, String)> {
+        let mut types = selector.types.clone();
+        types.extend(
+            self.types
+                .iter()
+                .filter(|x| !selector.types.contains(x))
+                .cloned(),
+        );
+        let mut params = vec![];
+        if !types.is_empty() {
+            params.push(("TYPE".to_string(), types.join(",")));
+        }
+        if self.preferred {
+            params.push(("PREF".to_string(), "1".to_string()));
+        }
+        params
+    }
+}
+
+fn entry<'m>(
+    entries: &'m mut BTreeMap<Selector, Entry>,
+    selector: &Selector,
+    size: usize,
+) -> &'m mut Entry {
+    let entry = entries.entry(selector.clone()).or_default();
+    if entry.value.len() < size {
+        entry.value.resize(size, String::new());
+    }
+    entry
+}
+
+fn read_row(cells: &[(&Field, &str)]) -> Result<VCard<'static>, FormatError> {
+    let mut name = vec![String::new(); 5];
+    let mut formatted = None;
+    let mut single = BTreeMap::new();
+    let mut org = vec![String::new(); 2];
+    let mut emails = BTreeMap::new();
+    let mut tels = BTreeMap::new();
+    let mut addresses = BTreeMap::new();
+    let mut urls = BTreeMap::new();
+    let mut others = vec![];
+    for (field, cell) in cells {
+        let escaped = escape_text(cell);
+        match field {
+            Field::FormattedName => formatted = Some(escaped),
+            Field::FamilyName => name[0] = escaped,
+            Field::GivenName => name[1] = escaped,
+            Field::AdditionalNames => name[2] = escaped,
+            Field::HonorificPrefixes => name[3] = escaped,
+            Field::HonorificSuffixes => name[4] = escaped,
+            Field::Organization => org[0] = escaped,
+            Field::Department => org[1] = escaped,
+            Field::Nickname => {
+                let items = cell.split(',').map(|x| escape_text(x.trim()));
+                single.insert("NICKNAME", items.collect::<Vec<_>>().join(","));
+            }
+            Field::Categories => {
+                let items = cell.split(';').map(|x| escape_text(x.trim()));
+                single.insert("CATEGORIES", items.collect::<Vec<_>>().join(","));
+            }
+            Field::Title => {
+                single.insert("TITLE", escaped);
+            }
+            Field::Role => {
+                single.insert("ROLE", escaped);
+            }
+            Field::Birthday => {
+                single.insert("BDAY", cell.to_string());
+            }
+            Field::Note => {
+                single.insert("NOTE", escaped);
+            }
+            Field::Email(selector) => entry(&mut emails, selector, 1).value[0] = escaped,
+            Field::Tel(selector) => entry(&mut tels, selector, 1).value[0] = escaped,
+            Field::Address(selector, part) => {
+                entry(&mut addresses, selector, 7).value[address_index(*part)] = escaped
+            }
+            Field::EmailType(index) => {
+                entry(&mut emails, &Selector::nth(*index), 1).set_types(cell)
+            }
+            Field::TelType(index) => entry(&mut tels, &Selector::nth(*index), 1).set_types(cell),
+            Field::AddressType(index) => {
+                entry(&mut addresses, &Selector::nth(*index), 7).set_types(cell)
+            }
+            Field::Url(index) => {
+                urls.insert(*index, cell.to_string());
+            }
+            Field::Property(name) => others.push((name.to_uppercase(), escaped)),
+        }
+    }
+    let mut properties = vec![Property::from(Value::Version("4.0".into()))];
+    let formatted = formatted.or_else(|| {
+        let parts = [&name[3], &name[1], &name[2], &name[0], &name[4]];
+        let full = parts.iter().filter(|x| !x.is_empty()).map(|x| x.as_str());
+        Some(full.collect::<Vec<_>>().join(" ")).filter(|x| !x.is_empty())
+    });
+    if let Some(formatted) = formatted.or_else(|| Some(org[0].clone()).filter(|x| !x.is_empty())) {
+        properties.push(build_property("FN", &[], &formatted)?);
+    }
+    if name.iter().any(|x| !x.is_empty()) {
+        properties.push(build_property("N", &[], &name.join(";"))?);
+    }
+    if let Some(nickname) = single.remove("NICKNAME") {
+        properties.push(build_property("NICKNAME", &[], &nickname)?);
+    }
+    if org.iter().any(|x| !x.is_empty()) {
+        let value = if org[1].is_empty() {
+            org[0].clone()
+        } else {
+            org.join(";")
+        };
+        properties.push(build_property("ORG", &[], &value)?);
+    }
+    for key in &["TITLE", "ROLE"] {
+        if let Some(value) = single.remove(key) {
+            properties.push(build_property(key, &[], &value)?);
+        }
+    }
+    for (name, entries) in [("EMAIL", emails), ("TEL", tels), ("ADR", addresses)] {
+        for (selector, entry) in entries {
+            if entry.value.iter().any(|x| !x.is_empty()) {
+                let params = entry.params(&selector);
+                properties.push(build_property(name, &params, &entry.value.join(";"))?);
+            }
+        }
+    }
+    for url in urls.values() {
+        properties.push(build_property("URL", &[], url)?);
+    }
+    for key in &["BDAY", "CATEGORIES", "NOTE"] {
+        if let Some(value) = single.remove(key) {
+            properties.push(build_property(key, &[], &value)?);
+        }
+    }
+    for (name, value) in others {
+        properties.push(build_property(&name, &[], &value)?);
+    }
+    Ok(VCard(properties))
+}
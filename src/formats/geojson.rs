@@ -0,0 +1,64 @@
+//! GeoJSON export of positioned contacts.
+//!
+//! Every vcard with a WGS-84 position, from `GEO` or the `GEO` parameter of
+//! an `ADR`, becomes a `Point` feature with its name and address as
+//! properties. Vcards without one are left out.
+
+use serde_json::{Map, Value as Json};
+
+use super::common::places::{address_line, formatted_name};
+use crate::types::GeoPosition;
+use crate::{VCard, Vcf};
+
+/// Convert a vcard into a feature collection of at most one feature.
+pub fn to_value(vcard: &VCard) -> Json {
+    collection(std::slice::from_ref(vcard))
+}
+
+/// Convert a vcard into a GeoJSON string.
+pub fn to_string(vcard: &VCard) -> String {
+    to_value(vcard).to_string()
+}
+
+/// Convert every positioned vcard into one feature collection.
+pub fn vcf_to_value(vcf: &Vcf) -> Json {
+    collection(&vcf.0)
+}
+
+/// Convert every positioned vcard into a GeoJSON string.
+pub fn vcf_to_string(vcf: &Vcf) -> String {
+    vcf_to_value(vcf).to_string()
+}
+
+fn collection(vcards: &[VCard]) -> Json {
+    let features = vcards.iter().filter_map(feature).collect();
+    let mut output = Map::new();
+    output.insert("type".into(), "FeatureCollection".into());
+    output.insert("features".into(), Json::Array(features));
+    Json::Object(output)
+}
+
+fn feature(vcard: &VCard) -> Option<Json> {
+    let position = vcard.position().filter(GeoPosition::is_wgs84)?;
+    let mut coordinates = vec![Json::from(position.longitude), Json::from(position.latitude)];
+    if let Some(altitude) = position.altitude {
+        coordinates.push(altitude.into());
+    }
+    let mut geometry = Map::new();
+    geometry.insert("type".into(), "Point".into());
+    geometry.insert("coordinates".into(), Json::Array(coordinates));
+
+    let mut properties = Map::new();
+    if let Some(name) = formatted_name(vcard) {
+        properties.insert("name".into(), name.into());
+    }
+    if let Some(address) = address_line(vcard) {
+        properties.insert("address".into(), address.into());
+    }
+
+    let mut feature = Map::new();
+    feature.insert("type".into(), "Feature".into());
+    feature.insert("geometry".into(), Json::Object(geometry));
+    feature.insert("properties".into(), Json::Object(properties));
+    Some(Json::Object(feature))
+}
@@ -0,0 +1,641 @@
+//! [h-card](https://microformats.org/wiki/h-card) rendering and parsing.
+//!
+//! Rendering produces a `<div class="h-card">` with one element per property,
+//! parsing follows the [microformats2 parsing](https://microformats.org/wiki/microformats2-parsing)
+//! rules and returns every h-card that is not nested in another h-card.
+
+use scraper::node::Node;
+use scraper::{ElementRef, Html};
+
+use super::common::{
+    build::build_property,
+    dates::{basic_date_and_or_time, extended_date_and_or_time},
+    text::plain_text,
+};
+use super::FormatError;
+use crate::parse::{escape_markup, escape_text, split_unescaped, unescape_text};
+use crate::{Property, TypeOrRaw, VCard, Value, Vcf};
+
+/// h-card properties for the `N` components, in order.
+const NAME: &[&str] = &[
+    "family-name",
+    "given-name",
+    "additional-name",
+    "honorific-prefix",
+    "honorific-suffix",
+];
+
+/// h-card properties for the `ADR` components, in order.
+const ADDRESS: &[&str] = &[
+    "post-office-box",
+    "extended-address",
+    "street-address",
+    "locality",
+    "region",
+    "postal-code",
+    "country-name",
+];
+
+/// Render a vcard as an h-card.
+pub fn to_string(vcard: &VCard) -> String {
+    let mut output = String::new();
+    write_vcard(&mut output, vcard);
+    output
+}
+
+/// Render every vcard as an h-card.
+pub fn vcf_to_string(vcf: &Vcf) -> String {
+    let mut output = String::new();
+    for vcard in &vcf.0 {
+        write_vcard(&mut output, vcard);
+    }
+    output
+}
+
+/// Read the first h-card of an HTML document.
+pub fn from_str(input: &str) -> Result<VCard<'static>, FormatError> {
+    vcf_from_str(input)?
+        .0
+        .into_iter()
+        .next()
+        .ok_or_else(|| FormatError::Syntax("no h-card found".into()))
+}
+
+/// Read every h-card of an HTML document.
+pub fn vcf_from_str(input: &str) -> Result<Vcf<'static>, FormatError> {
+    let document = Html::parse_document(input);
+    let mut items = vec![];
+    find_cards(document.root_element(), &mut items);
+    items
+        .iter()
+        .map(read_item)
+        .collect::<Result<Vec<_>, _>>()
+        .map(Vcf)
+}
+
+/// Whether a URI may be written as a link or image source. Anything else,
+/// such as a `javascript:` URI, is written as text.
+fn linkable(uri: &str) -> bool {
+    let (scheme, rest) = match uri.find(':') {
+        Some(index) => (uri[..index].to_ascii_lowercase(), &uri[index + 1..]),
+        None => return false,
+    };
+    match scheme.as_str() {
+        "http" | "https" | "mailto" | "tel" | "xmpp" | "sip" => true,
+        "data" => rest
+            .get(..6)
+            .is_some_and(|x| x.eq_ignore_ascii_case("image/")),
+        _ => false,
+    }
+}
+
+/// Unescaped components of a structured value.
+fn components(value: &Value) -> Vec<String> {
+    split_unescaped(&value.to_string(), ';')
+        .into_iter()
+        .map(unescape_text)
+        .collect()
+}
+
+fn write_vcard(output: &mut String, vcard: &VCard) {
+    output.push_str("<div class=\"h-card\">\n");
+    for property in vcard.iter() {
+        let value = &property.value;
+        let raw = plain_text(value);
+        let text = escape_markup(&raw);
+        let link = |class: &str| {
+            if linkable(&raw) {
+                format!("<a class=\"{}\" href=\"{}\">{}</a>", class, text, text)
+            } else {
+                format!("<span class=\"{}\">{}</span>", class, text)
+            }
+        };
+        let image = |class: &str| {
+            if linkable(&raw) {
+                format!("<img class=\"{}\" src=\"{}\" alt=\"\">", class, text)
+            } else {
+                format!("<span class=\"{}\">{}</span>", class, text)
+            }
+        };
+        let line = match value {
+            Value::Fn(_) => format!("<span class=\"p-name\">{}</span>", text),
+            Value::N(_) => NAME
+                .iter()
+                .zip(components(value))
+                .filter(|(_, part)| !part.is_empty())
+                .map(|(name, part)| {
+                    format!(
+                        "<data class=\"p-{}\" value=\"{}\"></data>",
+                        name,
+                        escape_markup(&part)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Value::Nickname(items) => items
+                .iter()
+                .map(|x| {
+                    let nickname = escape_markup(&unescape_text(x));
+                    format!("<span class=\"p-nickname\">{}</span>", nickname)
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Value::Organization(_) => {
+                let org = components(value).join(", ");
+                format!("<span class=\"p-org\">{}</span>", escape_markup(&org))
+            }
+            Value::Title(_) => format!("<span class=\"p-job-title\">{}</span>", text),
+            Value::Role(_) => format!("<span class=\"p-role\">{}</span>", text),
+            Value::Email(_) => {
+                format!("<a class=\"u-email\" href=\"mailto:{}\">{}</a>", text, text)
+            }
+            Value::Tel(_) => {
+                let number = text.trim_start_matches("tel:");
+                format!(
+                    "<a class=\"p-tel\" href=\"tel:{}\">{}</a>",
+                    number.replace(' ', ""),
+                    number
+                )
+            }
+            Value::Url(_) => link("u-url"),
+            Value::Uid(_) => format!("<data class=\"u-uid\" value=\"{}\"></data>", text),
+            Value::Photo(_) => image("u-photo"),
+            Value::Logo(_) => image("u-logo"),
+            Value::Impp(_) => link("u-impp"),
+            Value::SocialProfile(profile) => {
+                // Link handles such as `@alice@mastodon.social` to the
+                // profile page.
+                let uri = match profile {
+                    TypeOrRaw::Type(profile) => profile.uri(),
+                    TypeOrRaw::Raw(_) => None,
+                };
+                match uri.filter(|x| linkable(x)) {
+                    Some(uri) => format!(
+                        "<a class=\"u-url\" href=\"{}\">{}</a>",
+                        escape_markup(&uri),
+                        text
+                    ),
+                    None => link("u-url"),
+                }
+            }
+            Value::Adr(_) => {
+                let parts = ADDRESS
+                    .iter()
+                    .zip(components(value))
+                    .filter(|(_, part)| !part.is_empty())
+                    .map(|(name, part)| {
+                        format!("<span class=\"p-{}\">{}</span>", name, escape_markup(&part))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                format!("<div class=\"p-adr h-adr\">{}</div>", parts)
+            }
+            Value::Geo(geo) => {
+                let (latitude, longitude) = match geo {
+                    TypeOrRaw::Type(geo) => (geo.latitude.to_string(), geo.longitude.to_string()),
+                    TypeOrRaw::Raw(raw) => {
+                        let coordinates =
+                            raw.trim_start_matches("geo:").split(';').next().unwrap_or("");
+                        let mut parts = coordinates.split(',');
+                        match (parts.next(), parts.next()) {
+                            (Some(latitude), Some(longitude)) => {
+                                (latitude.trim().to_string(), longitude.trim().to_string())
+                            }
+                            _ => continue,
+                        }
+                    }
+                };
+                format!(
+                    "<span class=\"p-geo h-geo\"><data class=\"p-latitude\" value=\"{}\"></data>\
+                     <data class=\"p-longitude\" value=\"{}\"></data></span>",
+                    escape_markup(&latitude),
+                    escape_markup(&longitude)
+                )
+            }
+            Value::Birthday(_) | Value::Anniversary(_) => {
+                let name = if matches!(value, Value::Birthday(_)) {
+                    "bday"
+                } else {
+                    "anniversary"
+                };
+                let date = escape_markup(&extended_date_and_or_time(
+                    "date-and-or-time",
+                    &value.to_string(),
+                ));
+                format!(
+                    "<time class=\"dt-{}\" datetime=\"{}\">{}</time>",
+                    name, date, date
+                )
+            }
+            Value::Categories(items) => items
+                .iter()
+                .map(|x| {
+                    let category = escape_markup(&unescape_text(x));
+                    format!("<span class=\"p-category\">{}</span>", category)
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Value::Note(_) => format!("<p class=\"p-note\">{}</p>", text),
+            _ => continue,
+        };
+        if !line.is_empty() {
+            output.push_str(&line);
+            output.push('\n');
+        }
+    }
+    output.push_str("</div>\n");
+}
+
+/// A parsed microformats2 item.
+#[derive(Default, Clone)]
+struct Item {
+    properties: Vec<Prop>,
+}
+
+/// A property of an item, `nested` is set when it is itself an item.
+#[derive(Clone)]
+struct Prop {
+    prefix: &'static str,
+    name: String,
+    value: String,
+    nested: Option<Item>,
+}
+
+impl Item {
+    fn values<'i>(&'i self, name: &'i str) -> impl Iterator<Item = &'i Prop> {
+        self.properties.iter().filter(move |x| x.name == name)
+    }
+
+    fn first(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|x| x.name == name)
+            .map(|x| x.value.as_str())
+    }
+
+    fn push(&mut self, prefix: &'static str, name: &str, value: String, nested: Option<Item>) {
+        self.properties.push(Prop {
+            prefix,
+            name: name.into(),
+            value,
+            nested,
+        });
+    }
+
+    /// Whether a property may be implied, `prefixes` are the kinds of
+    /// property that prevent it.
+    fn can_imply(&self, name: &str, prefixes: &[&str]) -> bool {
+        self.first(name).is_none()
+            && !self
+                .properties
+                .iter()
+                .any(|x| x.nested.is_some() || prefixes.contains(&x.prefix))
+    }
+}
+
+fn classes(element: ElementRef<'_>) -> Vec<&str> {
+    element
+        .value()
+        .attr("class")
+        .map(|x| x.split_whitespace().collect())
+        .unwrap_or_default()
+}
+
+fn is_root_class(class: &str) -> bool {
+    class
+        .strip_prefix("h-")
+        .map(|x| {
+            !x.is_empty()
+                && x.split('-')
+                    .all(|x| !x.is_empty() && x.chars().all(|x| x.is_ascii_lowercase()))
+        })
+        .unwrap_or(false)
+}
+
+fn root_types(element: ElementRef) -> Vec<String> {
+    classes(element)
+        .into_iter()
+        .filter(|x| is_root_class(x))
+        .map(String::from)
+        .collect()
+}
+
+fn child_elements(element: ElementRef) -> impl Iterator<Item = ElementRef> {
+    element.children().filter_map(ElementRef::wrap)
+}
+
+/// Collect the h-cards that are not nested in another h-card.
+fn find_cards(element: ElementRef, items: &mut Vec<Item>) {
+    if root_types(element).iter().any(|x| x == "h-card") {
+        items.push(parse_item(element));
+        return;
+    }
+    for child in child_elements(element) {
+        find_cards(child, items);
+    }
+}
+
+fn parse_item(root: ElementRef) -> Item {
+    let mut item = Item::default();
+    for child in child_elements(root) {
+        parse_properties(child, &mut item);
+    }
+    imply_properties(root, &mut item);
+    item
+}
+
+const PREFIXES: &[&str] = &["p-", "u-", "dt-", "e-"];
+
+fn parse_properties(element: ElementRef, item: &mut Item) {
+    let classes = classes(element);
+    let properties = classes
+        .iter()
+        .filter_map(|class| {
+            PREFIXES
+                .iter()
+                .find(|prefix| class.starts_with(*prefix) && class.len() > prefix.len())
+                .map(|prefix| (*prefix, &class[prefix.len()..]))
+        })
+        .collect::<Vec<_>>();
+    if !root_types(element).is_empty() {
+        let nested = parse_item(element);
+        for (prefix, name) in properties {
+            let value = match prefix {
+                "u-" => nested.first("url").map(String::from),
+                "p-" => nested.first("name").map(String::from),
+                _ => None,
+            };
+            let value = value.unwrap_or_else(|| property_value(prefix, element));
+            item.push(prefix, name, value, Some(nested.clone()));
+        }
+        return;
+    }
+    for (prefix, name) in properties {
+        item.push(prefix, name, property_value(prefix, element), None);
+    }
+    for child in child_elements(element) {
+        parse_properties(child, item);
+    }
+}
+
+/// Text content where images are replaced by their `alt`, trimmed.
+fn text_content(element: ElementRef) -> String {
+    fn collect(element: ElementRef, output: &mut String) {
+        for node in element.children() {
+            match node.value() {
+                Node::Text(text) => output.push_str(text),
+                Node::Element(child) => match child.name() {
+                    "script" | "style" => {}
+                    "img" => output.push_str(child.attr("alt").unwrap_or("")),
+                    _ => {
+                        if let Some(child) = ElementRef::wrap(node) {
+                            collect(child, output);
+                        }
+                    }
+                },
+                _ => {}
+            }
+        }
+    }
+    let mut output = String::new();
+    collect(element, &mut output);
+    output.trim().to_string()
+}
+
+/// Values of the `value` and `value-title` class elements, if any.
+fn value_class(element: ElementRef) -> Option<String> {
+    fn collect(element: ElementRef, output: &mut Vec<String>) {
+        for child in child_elements(element) {
+            let classes = classes(child);
+            if classes.contains(&"value-title") {
+                output.push(child.value().attr("title").unwrap_or("").to_string());
+            } else if classes.contains(&"value") {
+                let value = child.value();
+                let attr = match value.name() {
+                    "img" | "area" => value.attr("alt"),
+                    "data" => value.attr("value"),
+                    "abbr" => value.attr("title"),
+                    _ => None,
+                };
+                output.push(
+                    attr.map(String::from)
+                        .unwrap_or_else(|| text_content(child)),
+                );
+            } else if root_types(child).is_empty() {
+                collect(child, output);
+            }
+        }
+    }
+    let mut output = vec![];
+    collect(element, &mut output);
+    if output.is_empty() {
+        None
+    } else {
+        Some(output.concat())
+    }
+}
+
+fn property_value(prefix: &str, element: ElementRef) -> String {
+    let value = element.value();
+    let attr = |names: &[(&str, &str)]| {
+        names
+            .iter()
+            .find(|(tag, _)| value.name() == *tag)
+            .and_then(|(_, attr)| value.attr(attr))
+            .map(String::from)
+    };
+    match prefix {
+        "u-" => attr(&[
+            ("a", "href"),
+            ("area", "href"),
+            ("link", "href"),
+            ("img", "src"),
+            ("audio", "src"),
+            ("video", "src"),
+            ("source", "src"),
+            ("iframe", "src"),
+            ("object", "data"),
+        ])
+        .or_else(|| value_class(element))
+        .or_else(|| attr(&[("abbr", "title"), ("data", "value"), ("input", "value")]))
+        .unwrap_or_else(|| text_content(element)),
+        "dt-" => value_class(element)
+            .or_else(|| {
+                attr(&[
+                    ("time", "datetime"),
+                    ("ins", "datetime"),
+                    ("del", "datetime"),
+                    ("abbr", "title"),
+                    ("data", "value"),
+                    ("input", "value"),
+                ])
+            })
+            .unwrap_or_else(|| text_content(element)),
+        "e-" => text_content(element),
+        _ => value_class(element)
+            .or_else(|| {
+                attr(&[
+                    ("abbr", "title"),
+                    ("link", "title"),
+                    ("data", "value"),
+                    ("input", "value"),
+                    ("img", "alt"),
+                    ("area", "alt"),
+                ])
+            })
+            .unwrap_or_else(|| text_content(element)),
+    }
+}
+
+/// The only child element with that tag, looking one level further down if
+/// there is a single child.
+fn only_child<'e>(element: ElementRef<'e>, tag: &str) -> Option<ElementRef<'e>> {
+    let children = child_elements(element).collect::<Vec<_>>();
+    let tagged = children
+        .iter()
+        .filter(|x| x.value().name() == tag)
+        .collect::<Vec<_>>();
+    match (tagged.as_slice(), children.as_slice()) {
+        ([child], _) if root_types(**child).is_empty() => Some(**child),
+        ([], [child]) if root_types(*child).is_empty() => only_child(*child, tag),
+        _ => None,
+    }
+}
+
+fn imply_properties(root: ElementRef, item: &mut Item) {
+    let value = root.value();
+    let (name, photo, url) = (
+        item.can_imply("name", &["p-", "e-"]),
+        item.can_imply("photo", &["u-"]),
+        item.can_imply("url", &["u-"]),
+    );
+    if name {
+        let name = match value.name() {
+            "img" | "area" => value.attr("alt").map(String::from),
+            "abbr" => value.attr("title").map(String::from),
+            _ => None,
+        }
+        .or_else(|| only_child(root, "img").and_then(|x| x.value().attr("alt").map(String::from)))
+        .unwrap_or_else(|| text_content(root));
+        item.push("p-", "name", name, None);
+    }
+    if photo {
+        let photo = match value.name() {
+            "img" => value.attr("src"),
+            "object" => value.attr("data"),
+            _ => only_child(root, "img").and_then(|x| x.value().attr("src")),
+        };
+        if let Some(photo) = photo {
+            item.push("u-", "photo", photo.into(), None);
+        }
+    }
+    if url {
+        let url = match value.name() {
+            "a" | "area" => value.attr("href"),
+            _ => only_child(root, "a").and_then(|x| x.value().attr("href")),
+        };
+        if let Some(url) = url {
+            item.push("u-", "url", url.into(), None);
+        }
+    }
+}
+
+fn read_item(item: &Item) -> Result<VCard<'static>, FormatError> {
+    let mut properties = vec![Property::from(Value::Version("4.0".into()))];
+    let mut push = |name: &str, params: &[(String, String)], value: &str| {
+        properties.push(build_property(name, params, value)?);
+        Ok::<_, FormatError>(())
+    };
+    let text = |name| item.values(name).map(|x| escape_text(&x.value));
+    let first = |name| text(name).next();
+    if let Some(name) = first("name") {
+        push("FN", &[], &name)?;
+    }
+    let name = NAME
+        .iter()
+        .map(|x| text(x).collect::<Vec<_>>().join(","))
+        .collect::<Vec<_>>();
+    if name.iter().any(|x| !x.is_empty()) {
+        push("N", &[], &name.join(";"))?;
+    }
+    let nicknames = text("nickname").collect::<Vec<_>>();
+    if !nicknames.is_empty() {
+        push("NICKNAME", &[], &nicknames.join(","))?;
+    }
+    for (property, name) in &[("org", "ORG"), ("job-title", "TITLE"), ("role", "ROLE")] {
+        for value in text(property) {
+            push(name, &[], &value)?;
+        }
+    }
+    for email in item.values("email") {
+        let email = email.value.trim_start_matches("mailto:");
+        push("EMAIL", &[], &escape_text(email))?;
+    }
+    for tel in item.values("tel") {
+        let tel = tel.value.trim_start_matches("tel:");
+        push("TEL", &[], &escape_text(tel))?;
+    }
+    let mut addresses = item
+        .values("adr")
+        .filter_map(|x| x.nested.as_ref())
+        .collect::<Vec<_>>();
+    if ADDRESS.iter().any(|x| item.first(x).is_some()) {
+        addresses.insert(0, item);
+    }
+    for address in addresses {
+        let parts = ADDRESS
+            .iter()
+            .map(|x| {
+                address
+                    .values(x)
+                    .map(|x| escape_text(&x.value))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>();
+        let params = match address.first("label") {
+            Some(label) => vec![("LABEL".to_string(), format!("\"{}\"", escape_text(label)))],
+            None => vec![],
+        };
+        push("ADR", &params, &parts.join(";"))?;
+    }
+    for (property, name) in &[
+        ("url", "URL"),
+        ("impp", "IMPP"),
+        ("photo", "PHOTO"),
+        ("logo", "LOGO"),
+    ] {
+        for property in item.values(property) {
+            push(name, &[], &property.value)?;
+        }
+    }
+    for geo in item.values("geo") {
+        let coordinates = match &geo.nested {
+            Some(geo) => geo.first("latitude").zip(geo.first("longitude")),
+            None => None,
+        };
+        if let Some((latitude, longitude)) = coordinates {
+            push("GEO", &[], &format!("geo:{},{}", latitude, longitude))?;
+        }
+    }
+    if let Some((latitude, longitude)) = item.first("latitude").zip(item.first("longitude")) {
+        push("GEO", &[], &format!("geo:{},{}", latitude, longitude))?;
+    }
+    for (property, name) in &[("bday", "BDAY"), ("anniversary", "ANNIVERSARY")] {
+        for date in item.values(property) {
+            let date = basic_date_and_or_time("date-and-or-time", date.value.trim());
+            push(name, &[], &date)?;
+        }
+    }
+    let categories = text("category").collect::<Vec<_>>();
+    if !categories.is_empty() {
+        push("CATEGORIES", &[], &categories.join(","))?;
+    }
+    if let Some(uid) = item.first("uid") {
+        push("UID", &[], uid)?;
+    }
+    for note in text("note") {
+        push("NOTE", &[], &note)?;
+    }
+    Ok(VCard(properties))
+}
@@ -0,0 +1,181 @@
+//! iCalendar export of birthdays and anniversaries.
+//!
+//! Every `BDAY` and `ANNIVERSARY` with a month and day becomes an all day
+//! `VEVENT` recurring yearly. Dates without a year start in 2000, and
+//! February 29 falls on the last day of February in common years. Event UIDs
+//! come from the card `UID`, or from its name and date if it has none, so
+//! exporting again updates the same events.
+
+use chrono::{Duration, NaiveDateTime, Utc};
+
+use crate::parse::{escape_text, unescape_text};
+use crate::{Occasion, VCard, Value, Vcf};
+
+/// Lines longer than this are folded.
+const LINE_LENGTH: usize = 75;
+
+/// First year of events for dates without one, a leap year so February 29
+/// is a valid start.
+const YEARLESS_START: i32 = 2000;
+
+/// Export options.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Options {
+    /// Written as the `DTSTAMP` of every event.
+    pub stamp: NaiveDateTime,
+    /// Add a display alarm this long before each event.
+    pub alarm: Option<Duration>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            stamp: Utc::now().naive_utc(),
+            alarm: None,
+        }
+    }
+}
+
+/// Export the birthdays and anniversaries of a vcard as a calendar.
+pub fn to_string(vcard: &VCard, options: &Options) -> String {
+    calendar(std::slice::from_ref(vcard), options)
+}
+
+/// Export the birthdays and anniversaries of every vcard as one calendar.
+pub fn vcf_to_string(vcf: &Vcf, options: &Options) -> String {
+    calendar(&vcf.0, options)
+}
+
+fn calendar(vcards: &[VCard], options: &Options) -> String {
+    let mut output = String::new();
+    write_line(&mut output, "BEGIN:VCALENDAR");
+    write_line(&mut output, "VERSION:2.0");
+    write_line(&mut output, "PRODID:-//rolodex//Birthdays//EN");
+    write_line(&mut output, "CALSCALE:GREGORIAN");
+    for vcard in vcards {
+        write_events(&mut output, vcard, options);
+    }
+    write_line(&mut output, "END:VCALENDAR");
+    output
+}
+
+fn write_events(output: &mut String, vcard: &VCard, options: &Options) {
+    let text = |value: &Value| unescape_text(&value.to_string());
+    let name = vcard
+        .iter()
+        .find(|x| matches!(x.value, Value::Fn(_)))
+        .map(|x| text(&x.value))
+        .unwrap_or_default();
+    let uid = vcard
+        .iter()
+        .find(|x| matches!(x.value, Value::Uid(_)))
+        .map(|x| text(&x.value));
+    let (mut birthdays, mut anniversaries) = (0, 0);
+    for property in vcard.iter() {
+        let (kind, count, summary) = match property.value {
+            Value::Birthday(_) => ("birthday", &mut birthdays, "Birthday"),
+            Value::Anniversary(_) => ("anniversary", &mut anniversaries, "Anniversary"),
+            _ => continue,
+        };
+        let occasion = match Occasion::from_property(property) {
+            Some(occasion) => occasion,
+            None => continue,
+        };
+        *count += 1;
+        let summary = if name.is_empty() {
+            summary.to_string()
+        } else {
+            format!("{}: {}", summary, name)
+        };
+        let mut event_uid = match &uid {
+            Some(uid) => format!("{}-{}", uid.trim_start_matches("urn:uuid:"), kind),
+            None => {
+                // The date the occasion falls on rather than the text of the
+                // value, so spelling it another way keeps the same UID.
+                let date = match occasion.year {
+                    Some(year) => format!("{:04}-{:02}-{:02}", year, occasion.month, occasion.day),
+                    None => format!("--{:02}-{:02}", occasion.month, occasion.day),
+                };
+                format!("{:016x}-{}", fnv1a(&format!("{}\n{}", name, date)), kind)
+            }
+        };
+        if *count > 1 {
+            event_uid.push_str(&format!("-{}", count));
+        }
+        let start = match occasion.in_year(occasion.year.unwrap_or(YEARLESS_START)) {
+            Some(start) => start,
+            None => continue,
+        };
+
+        write_line(output, "BEGIN:VEVENT");
+        write_line(output, &format!("UID:{}@rolodex", escape_text(&event_uid)));
+        write_line(
+            output,
+            &format!("DTSTAMP:{}", options.stamp.format("%Y%m%dT%H%M%SZ")),
+        );
+        write_line(
+            output,
+            &format!("DTSTART;VALUE=DATE:{}", start.format("%Y%m%d")),
+        );
+        if (occasion.month, occasion.day) == (2, 29) {
+            write_line(output, "RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=-1");
+        } else {
+            write_line(output, "RRULE:FREQ=YEARLY");
+        }
+        write_line(output, &format!("SUMMARY:{}", escape_text(&summary)));
+        write_line(output, "TRANSP:TRANSPARENT");
+        if let Some(alarm) = options.alarm {
+            write_line(output, "BEGIN:VALARM");
+            write_line(output, "ACTION:DISPLAY");
+            write_line(output, &format!("DESCRIPTION:{}", escape_text(&summary)));
+            write_line(output, &format!("TRIGGER:-{}", duration(alarm)));
+            write_line(output, "END:VALARM");
+        }
+        write_line(output, "END:VEVENT");
+    }
+}
+
+/// An iCalendar duration, always positive.
+fn duration(duration: Duration) -> String {
+    let seconds = duration.num_seconds().abs();
+    let (days, seconds) = (seconds / 86_400, seconds % 86_400);
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    let mut output = "P".to_string();
+    if days > 0 {
+        output.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || days == 0 {
+        output.push('T');
+        if hours > 0 {
+            output.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            output.push_str(&format!("{}M", minutes));
+        }
+        if seconds > 0 || (hours == 0 && minutes == 0) {
+            output.push_str(&format!("{}S", seconds));
+        }
+    }
+    output
+}
+
+/// 64 bit FNV-1a, for UIDs that stay the same between runs and releases.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, x| {
+        (hash ^ u64::from(x)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Write a line folded at 75 octets, ended by CRLF.
+fn write_line(output: &mut String, line: &str) {
+    let mut width = 0;
+    for x in line.chars() {
+        if width + x.len_utf8() > LINE_LENGTH {
+            output.push_str("\r\n ");
+            width = 1;
+        }
+        output.push(x);
+        width += x.len_utf8();
+    }
+    output.push_str("\r\n");
+}
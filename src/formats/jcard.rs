@@ -0,0 +1,290 @@
+//! [jCard](https://tools.ietf.org/html/rfc7095) reading and writing.
+//!
+//! A vcard is written as `["vcard", [["fn", {}, "text", "Forrest Gump"], ...]]`,
+//! the `VALUE` parameter is moved into the value type slot and text values
+//! are unescaped.
+
+use serde_json::{Map, Value as Json};
+
+use super::common::{
+    build::build_property,
+    dates::{basic_date_and_or_time, extended_date_and_or_time},
+    parameters::{default_value_type, quote_parameter_value, unquote_parameter_value},
+};
+use super::FormatError;
+use crate::parse::{escape_text, split_unescaped, unescape_text};
+use crate::{Parameter, Property, VCard, Value, Vcf};
+
+/// Properties whose value is made of `;` separated components.
+const STRUCTURED: &[&str] = &["n", "adr", "gender", "org", "clientpidmap"];
+
+/// Convert a vcard into a jCard value.
+pub fn to_value(vcard: &VCard) -> Json {
+    let mut properties = vec![];
+    if !vcard.iter().any(|x| matches!(x.value, Value::Version(_))) {
+        properties.push(Json::Array(vec![
+            "version".into(),
+            Map::new().into(),
+            "text".into(),
+            "4.0".into(),
+        ]));
+    }
+    for property in vcard.iter() {
+        if !matches!(property.value, Value::Begin | Value::End) {
+            properties.push(property_to_value(property));
+        }
+    }
+    Json::Array(vec!["vcard".into(), Json::Array(properties)])
+}
+
+/// Convert a vcard into a jCard string.
+pub fn to_string(vcard: &VCard) -> String {
+    to_value(vcard).to_string()
+}
+
+/// Convert every vcard into a jCard array.
+pub fn vcf_to_value(vcf: &Vcf) -> Json {
+    Json::Array(vcf.0.iter().map(to_value).collect())
+}
+
+/// Convert every vcard into a jCard array string.
+pub fn vcf_to_string(vcf: &Vcf) -> String {
+    vcf_to_value(vcf).to_string()
+}
+
+/// Read a vcard from a jCard value.
+pub fn from_value(value: &Json) -> Result<VCard<'static>, FormatError> {
+    let properties = match value.as_array().map(|x| x.as_slice()) {
+        Some([Json::String(tag), Json::Array(properties)]) if tag == "vcard" => properties,
+        _ => return Err(syntax("expected [\"vcard\", [...]]")),
+    };
+    properties
+        .iter()
+        .map(property_from_value)
+        .collect::<Result<Vec<_>, _>>()
+        .map(VCard)
+}
+
+/// Read a vcard from a jCard string.
+pub fn from_str(input: &str) -> Result<VCard<'static>, FormatError> {
+    from_value(&parse_json(input)?)
+}
+
+/// Read either a single jCard or an array of them.
+pub fn vcf_from_value(value: &Json) -> Result<Vcf<'static>, FormatError> {
+    match value.as_array().and_then(|x| x.first()) {
+        Some(Json::String(_)) => Ok(Vcf(vec![from_value(value)?])),
+        Some(_) => value
+            .as_array()
+            .unwrap_or(&vec![])
+            .iter()
+            .map(from_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Vcf),
+        None => Ok(Vcf(vec![])),
+    }
+}
+
+/// Read either a single jCard or an array of them from a string.
+pub fn vcf_from_str(input: &str) -> Result<Vcf<'static>, FormatError> {
+    vcf_from_value(&parse_json(input)?)
+}
+
+fn parse_json(input: &str) -> Result<Json, FormatError> {
+    serde_json::from_str(input).map_err(|err| FormatError::Syntax(err.to_string()))
+}
+
+fn syntax(msg: &str) -> FormatError {
+    FormatError::Syntax(msg.into())
+}
+
+pub(crate) fn property_to_value(property: &Property) -> Json {
+    let name = property.value.name_raw().to_lowercase();
+    let (params, ty) = parameters_to_value(property.group.as_deref(), &property.params.0);
+    let ty = ty.unwrap_or_else(|| default_value_type(&name).into());
+    let mut parts = vec![Json::String(name.clone()), params.into(), ty.clone().into()];
+    match &property.value {
+        Value::Nickname(items) | Value::Categories(items) => {
+            parts.extend(items.iter().map(|x| scalar_to_value(&ty, x)))
+        }
+        value if STRUCTURED.contains(&name.as_str()) => {
+            let raw = value.to_string();
+            let mut components: Vec<Json> = split_unescaped(&raw, ';')
+                .into_iter()
+                .map(|component| match split_unescaped(component, ',').as_slice() {
+                    [item] => unescape_text(item).into(),
+                    items => Json::Array(items.iter().map(|x| unescape_text(x).into()).collect()),
+                })
+                .collect();
+            if name != "n" && name != "adr" {
+                while components.len() > 1 && components.last() == Some(&Json::from("")) {
+                    components.pop();
+                }
+            }
+            if components.len() == 1 {
+                parts.push(components.remove(0));
+            } else {
+                parts.push(Json::Array(components));
+            }
+        }
+        value => parts.push(scalar_to_value(&ty, &value.to_string())),
+    }
+    Json::Array(parts)
+}
+
+/// Convert vcard parameters into a jCard parameter object, the `VALUE`
+/// parameter is returned separately.
+pub(crate) fn parameters_to_value(
+    group: Option<&str>,
+    parameters: &[Parameter],
+) -> (Map<String, Json>, Option<String>) {
+    let mut params = Map::new();
+    if let Some(group) = group {
+        params.insert("group".into(), group.into());
+    }
+    let mut ty = None;
+    for param in parameters {
+        let key = param.name.to_lowercase();
+        let value = param.value.as_deref().unwrap_or("");
+        if key == "value" {
+            ty = Some(value.to_lowercase());
+            continue;
+        }
+        let value = parameter_to_value(value);
+        match params.remove(&key) {
+            Some(Json::Array(mut items)) => {
+                items.extend(into_items(value));
+                params.insert(key, Json::Array(items));
+            }
+            Some(previous) => {
+                let mut items = vec![previous];
+                items.extend(into_items(value));
+                params.insert(key, Json::Array(items));
+            }
+            None => {
+                params.insert(key, value);
+            }
+        }
+    }
+    (params, ty)
+}
+
+/// Convert a jCard parameter object into the group and the vcard parameters.
+pub(crate) fn parameters_from_value(
+    params: &Map<String, Json>,
+) -> (Option<String>, Vec<(String, String)>) {
+    let mut group = None;
+    let mut parameters = vec![];
+    for (key, value) in params {
+        let value = match value {
+            Json::Array(items) => items
+                .iter()
+                .map(|x| quote_parameter_value(&escape_text(&json_to_string(x))))
+                .collect::<Vec<_>>()
+                .join(","),
+            value => quote_parameter_value(&escape_text(&json_to_string(value))),
+        };
+        if key.eq_ignore_ascii_case("group") {
+            group = Some(value);
+        } else {
+            parameters.push((key.to_uppercase(), value));
+        }
+    }
+    (group, parameters)
+}
+
+fn into_items(value: Json) -> Vec<Json> {
+    match value {
+        Json::Array(items) => items,
+        value => vec![value],
+    }
+}
+
+fn parameter_to_value(value: &str) -> Json {
+    let unquoted = unquote_parameter_value(value);
+    if unquoted.len() != value.len() {
+        return unescape_text(unquoted).into();
+    }
+    match split_unescaped(value, ',').as_slice() {
+        [item] => unescape_text(item).into(),
+        items => Json::Array(items.iter().map(|x| unescape_text(x).into()).collect()),
+    }
+}
+
+fn scalar_to_value(ty: &str, raw: &str) -> Json {
+    match ty {
+        "text" => unescape_text(raw).into(),
+        "date" | "time" | "date-time" | "date-and-or-time" | "timestamp" | "utc-offset" => {
+            extended_date_and_or_time(ty, raw).into()
+        }
+        "integer" => raw.parse::<i64>().map(Json::from).unwrap_or_else(|_| raw.into()),
+        "float" => raw.parse::<f64>().map(Json::from).unwrap_or_else(|_| raw.into()),
+        "boolean" => match raw.to_lowercase().as_str() {
+            "true" => true.into(),
+            "false" => false.into(),
+            _ => raw.into(),
+        },
+        _ => raw.into(),
+    }
+}
+
+pub(crate) fn property_from_value(value: &Json) -> Result<Property<'static>, FormatError> {
+    let parts = match value.as_array() {
+        Some(parts) if parts.len() >= 4 => parts,
+        _ => return Err(syntax("expected [name, {parameters}, type, value...]")),
+    };
+    let (name, params, ty) = match (&parts[0], &parts[1], &parts[2]) {
+        (Json::String(name), Json::Object(params), Json::String(ty)) => (name, params, ty),
+        _ => return Err(syntax("expected [name, {parameters}, type, value...]")),
+    };
+    let ty = ty.to_lowercase();
+    let default_ty = default_value_type(&name.to_lowercase());
+    let (group, mut parameters) = parameters_from_value(params);
+    let name = match group {
+        Some(group) => format!("{}.{}", group, name.to_uppercase()),
+        None => name.to_uppercase(),
+    };
+    if ty != "unknown" && ty != default_ty {
+        parameters.push(("VALUE".into(), ty.clone()));
+    }
+    let value = parts[3..]
+        .iter()
+        .map(|value| match value {
+            Json::Array(components) => components
+                .iter()
+                .map(|component| match component {
+                    Json::Array(items) => items
+                        .iter()
+                        .map(|x| escape_text(&json_to_string(x)))
+                        .collect::<Vec<_>>()
+                        .join(","),
+                    component => escape_text(&json_to_string(component)),
+                })
+                .collect::<Vec<_>>()
+                .join(";"),
+            value => scalar_from_value(&ty, value),
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    build_property(&name, &parameters, &value)
+}
+
+fn scalar_from_value(ty: &str, value: &Json) -> String {
+    let raw = json_to_string(value);
+    match ty {
+        "text" => escape_text(&raw),
+        "date" | "time" | "date-time" | "date-and-or-time" | "timestamp" | "utc-offset" => {
+            basic_date_and_or_time(ty, &raw)
+        }
+        "boolean" => raw.to_uppercase(),
+        _ => raw,
+    }
+}
+
+pub(crate) fn json_to_string(value: &Json) -> String {
+    match value {
+        Json::String(value) => value.clone(),
+        Json::Null => String::new(),
+        value => value.to_string(),
+    }
+}
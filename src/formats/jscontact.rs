@@ -0,0 +1,690 @@
+//! [JSContact](https://www.rfc-editor.org/rfc/rfc9553) conversion following
+//! the mapping rules of [RFC 9555](https://www.rfc-editor.org/rfc/rfc9555).
+//!
+//! Properties without a JSContact equivalent are kept in `vCardProps` and
+//! parameters without one in `vCardParams`, so converting a card back gives
+//! the same vcard.
+
+use serde_json::{Map, Value as Json};
+
+use super::common::{
+    build::build_property,
+    dates::{basic_date_and_or_time, extended_date_and_or_time},
+    parameters::{quote_parameter_value, unquote_parameter_value},
+    text::plain_text,
+};
+use super::jcard::{
+    json_to_string, parameters_from_value, parameters_to_value, property_from_value,
+    property_to_value,
+};
+use super::FormatError;
+use crate::parse::{escape_text, split_unescaped, unescape_text};
+use crate::types::DateAndOrTime;
+use crate::{IntoOwned, Parameter, Property, TypeOrRaw, VCard, Value};
+
+/// Name component kinds in the order of the `N` components.
+const NAME_COMPONENTS: &[&str] = &["surname", "given", "given2", "title", "credential"];
+
+/// Address component kinds in the order of the `ADR` components.
+const ADDRESS_COMPONENTS: &[&str] = &[
+    "postOfficeBox",
+    "apartment",
+    "name",
+    "locality",
+    "region",
+    "postcode",
+    "country",
+];
+
+/// `TYPE` values mapped to contexts.
+const CONTEXTS: &[(&str, &str)] = &[("work", "work"), ("home", "private")];
+
+/// `TEL` `TYPE` values mapped to phone features.
+const FEATURES: &[(&str, &str)] = &[
+    ("voice", "voice"),
+    ("fax", "fax"),
+    ("cell", "mobile"),
+    ("text", "text"),
+    ("video", "video"),
+    ("pager", "pager"),
+    ("textphone", "textphone"),
+];
+
+/// Convert a vcard into a JSContact Card.
+pub fn to_value(vcard: &VCard) -> Json {
+    let mut card = Map::new();
+    card.insert("@type".into(), "Card".into());
+    card.insert("version".into(), "1.0".into());
+    let mut unmapped = vec![];
+    for property in vcard.iter() {
+        if !write_property(&mut card, property) {
+            unmapped.push(property_to_value(property));
+        }
+    }
+    if !unmapped.is_empty() {
+        card.insert("vCardProps".into(), Json::Array(unmapped));
+    }
+    Json::Object(card)
+}
+
+/// Convert a vcard into a JSContact Card string.
+pub fn to_string(vcard: &VCard) -> String {
+    to_value(vcard).to_string()
+}
+
+/// Read a vcard from a JSContact Card.
+pub fn from_value(value: &Json) -> Result<VCard<'static>, FormatError> {
+    let card = match value {
+        Json::Object(card) if card.get("@type") == Some(&Json::from("Card")) => card,
+        _ => return Err(FormatError::Syntax("expected a Card object".into())),
+    };
+    let unmapped = match card.get("vCardProps") {
+        Some(Json::Array(props)) => props
+            .iter()
+            .map(property_from_value)
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(_) => return Err(FormatError::Syntax("vCardProps must be an array".into())),
+        None => vec![],
+    };
+    let mut properties = vec![];
+    if !unmapped.iter().any(|x| matches!(x.value, Value::Version(_))) {
+        properties.push(Property::from(Value::Version("4.0".into())));
+    }
+    read_card(card, &mut properties)?;
+    properties.extend(unmapped);
+    Ok(VCard(properties))
+}
+
+/// Read a vcard from a JSContact Card string.
+pub fn from_str(input: &str) -> Result<VCard<'static>, FormatError> {
+    let value = serde_json::from_str(input).map_err(|err| FormatError::Syntax(err.to_string()))?;
+    from_value(&value)
+}
+
+/// Parameters of a property that are consumed while mapping it.
+struct Params {
+    group: Option<String>,
+    items: Vec<Parameter<'static>>,
+}
+
+impl Params {
+    fn new(property: &Property) -> Params {
+        Params {
+            group: property.group.as_ref().map(|x| x.to_string()),
+            items: property.params.0.clone().into_owned(),
+        }
+    }
+
+    /// Remove a parameter, returning each of its values.
+    fn take(&mut self, name: &str) -> Vec<String> {
+        let mut values = vec![];
+        self.items.retain(|param| {
+            if !param.name.eq_ignore_ascii_case(name) {
+                return true;
+            }
+            let value = param.value.as_deref().unwrap_or("");
+            let unquoted = unquote_parameter_value(value);
+            if unquoted.len() != value.len() {
+                values.push(unquoted.to_string());
+            } else {
+                values.extend(value.split(',').map(String::from));
+            }
+            false
+        });
+        values
+    }
+
+    fn is_empty(&self) -> bool {
+        self.group.is_none() && self.items.is_empty()
+    }
+
+    /// Move the `TYPE` values found in `map` into a JSContact set.
+    fn take_types(&mut self, entry: &mut Map<String, Json>, key: &str, map: &[(&str, &str)]) {
+        let mut set = Map::new();
+        let mut rest = vec![];
+        for ty in self.take("TYPE") {
+            match map.iter().find(|(name, _)| name.eq_ignore_ascii_case(&ty)) {
+                Some((_, mapped)) => {
+                    set.insert((*mapped).into(), true.into());
+                }
+                None => rest.push(ty),
+            }
+        }
+        if !set.is_empty() {
+            entry.insert(key.into(), set.into());
+        }
+        if !rest.is_empty() {
+            self.items.push(Parameter {
+                name: "TYPE".into(),
+                value: Some(rest.join(",").into()),
+            });
+        }
+    }
+
+    fn take_pref(&mut self, entry: &mut Map<String, Json>) {
+        if let Some(pref) = self.take("PREF").first().and_then(|x| x.parse::<u64>().ok()) {
+            entry.insert("pref".into(), pref.into());
+        }
+    }
+
+    /// Keep whatever is left in `vCardParams`.
+    fn finish(self, mut entry: Map<String, Json>) -> Json {
+        let (params, ty) = parameters_to_value(self.group.as_deref(), &self.items);
+        let mut params = params;
+        if let Some(ty) = ty {
+            params.insert("value".into(), ty.into());
+        }
+        if !params.is_empty() {
+            entry.insert("vCardParams".into(), params.into());
+        }
+        Json::Object(entry)
+    }
+}
+
+fn object(items: Vec<(&str, Json)>) -> Map<String, Json> {
+    items.into_iter().map(|(key, value)| (key.into(), value)).collect()
+}
+
+fn insert_entry(card: &mut Map<String, Json>, collection: &str, prefix: &str, entry: Json) {
+    let entries = card
+        .entry(collection)
+        .or_insert_with(|| Json::Object(Map::new()));
+    if let Json::Object(entries) = entries {
+        let id = format!("{}{}", prefix, entries.len() + 1);
+        entries.insert(id, entry);
+    }
+}
+
+fn set_name_field(card: &mut Map<String, Json>, field: &str, value: Json) -> bool {
+    let name = card
+        .entry("name")
+        .or_insert_with(|| Json::Object(Map::new()));
+    match name {
+        Json::Object(name) if !name.contains_key(field) => {
+            name.insert(field.into(), value);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn set_field(card: &mut Map<String, Json>, field: &str, value: Json) -> bool {
+    if card.contains_key(field) {
+        return false;
+    }
+    card.insert(field.into(), value);
+    true
+}
+
+/// Split a structured value into its unescaped components and their items.
+fn components(value: &Value) -> Vec<Vec<String>> {
+    split_unescaped(&value.to_string(), ';')
+        .into_iter()
+        .map(|component| {
+            split_unescaped(component, ',')
+                .into_iter()
+                .filter(|x| !x.is_empty())
+                .map(unescape_text)
+                .collect()
+        })
+        .collect()
+}
+
+fn write_property(card: &mut Map<String, Json>, property: &Property) -> bool {
+    let mut params = Params::new(property);
+    let value = &property.value;
+    let text = || plain_text(value);
+    match value {
+        Value::Begin | Value::End => true,
+        Value::Version(version) => params.is_empty() && version == "4.0",
+        Value::Kind(kind) if params.is_empty() => set_field(card, "kind", kind.to_string().into()),
+        Value::Uid(uid) if params.is_empty() => set_field(card, "uid", uid.to_string().into()),
+        Value::ProdID(_) if params.is_empty() => set_field(card, "prodId", text().into()),
+        Value::Rev(rev) if params.is_empty() => {
+            let rev = rev.to_string();
+            set_field(card, "updated", extended_date_and_or_time("timestamp", &rev).into())
+        }
+        Value::Fn(_) if params.is_empty() => set_name_field(card, "full", text().into()),
+        Value::N(_) if params.is_empty() => {
+            let mut entries = vec![];
+            for (kind, items) in NAME_COMPONENTS.iter().zip(components(value)) {
+                for item in items {
+                    entries.push(Json::Object(object(vec![
+                        ("kind", (*kind).into()),
+                        ("value", item.into()),
+                    ])));
+                }
+            }
+            set_name_field(card, "components", entries.into())
+        }
+        Value::Categories(items) if params.is_empty() && !card.contains_key("keywords") => {
+            let keywords = items
+                .iter()
+                .map(|x| (unescape_text(x), Json::from(true)))
+                .collect::<Map<_, _>>();
+            set_field(card, "keywords", keywords.into())
+        }
+        Value::Nickname(items) => {
+            for item in items.iter() {
+                let entry = object(vec![("name", unescape_text(item).into())]);
+                let params = Params::new(property);
+                insert_entry(card, "nicknames", "k", params.finish(entry));
+            }
+            true
+        }
+        Value::Organization(_) => {
+            let mut parts = components(value).into_iter().map(|x| x.join(","));
+            let mut entry = object(vec![("name", parts.next().unwrap_or_default().into())]);
+            let units = parts
+                .map(|x| Json::Object(object(vec![("name", x.into())])))
+                .collect::<Vec<_>>();
+            if !units.is_empty() {
+                entry.insert("units".into(), units.into());
+            }
+            insert_entry(card, "organizations", "o", params.finish(entry));
+            true
+        }
+        Value::Title(_) | Value::Role(_) => {
+            let kind = if matches!(value, Value::Title(_)) { "title" } else { "role" };
+            let entry = object(vec![("name", text().into()), ("kind", kind.into())]);
+            insert_entry(card, "titles", "t", params.finish(entry));
+            true
+        }
+        Value::Email(_) => {
+            let mut entry = object(vec![("address", text().into())]);
+            params.take_types(&mut entry, "contexts", CONTEXTS);
+            params.take_pref(&mut entry);
+            insert_entry(card, "emails", "e", params.finish(entry));
+            true
+        }
+        Value::Tel(_) => {
+            let mut entry = object(vec![("number", text().into())]);
+            let value_types = params.take("VALUE");
+            if value_types.iter().any(|x| !x.eq_ignore_ascii_case("uri")) {
+                return false;
+            }
+            let mut contexts = Map::new();
+            params.take_types(&mut contexts, "contexts", CONTEXTS);
+            params.take_types(&mut entry, "features", FEATURES);
+            entry.extend(contexts);
+            params.take_pref(&mut entry);
+            insert_entry(card, "phones", "p", params.finish(entry));
+            true
+        }
+        Value::Adr(_) => {
+            let mut entries = vec![];
+            for (kind, items) in ADDRESS_COMPONENTS.iter().zip(components(value)) {
+                for item in items {
+                    entries.push(Json::Object(object(vec![
+                        ("kind", (*kind).into()),
+                        ("value", item.into()),
+                    ])));
+                }
+            }
+            let mut entry = object(vec![("components", entries.into())]);
+            if let Some(label) = params.take("LABEL").first() {
+                entry.insert("full".into(), unescape_text(label).into());
+            }
+            if let Some(geo) = params.take("GEO").first() {
+                entry.insert("coordinates".into(), geo.as_str().into());
+            }
+            if let Some(tz) = params.take("TZ").first() {
+                entry.insert("timeZone".into(), tz.as_str().into());
+            }
+            params.take_types(&mut entry, "contexts", CONTEXTS);
+            params.take_pref(&mut entry);
+            insert_entry(card, "addresses", "a", params.finish(entry));
+            true
+        }
+        Value::Impp(_) => {
+            let mut entry = object(vec![("uri", text().into()), ("vCardName", "impp".into())]);
+            if let Some(service) = params.take("SERVICE-TYPE").first() {
+                entry.insert("service".into(), service.as_str().into());
+            }
+            params.take_types(&mut entry, "contexts", CONTEXTS);
+            params.take_pref(&mut entry);
+            insert_entry(card, "onlineServices", "s", params.finish(entry));
+            true
+        }
+        Value::SocialProfile(_) | Value::Other(..)
+            if value.name_raw().eq_ignore_ascii_case("SOCIALPROFILE")
+                || value.name_raw().eq_ignore_ascii_case("X-SOCIALPROFILE") =>
+        {
+            let mut entry = object(vec![("uri", text().into())]);
+            if value.name_raw().eq_ignore_ascii_case("X-SOCIALPROFILE") {
+                entry.insert("vCardName".into(), "x-socialprofile".into());
+            }
+            if let Some(service) = params.take("SERVICE-TYPE").first() {
+                entry.insert("service".into(), service.as_str().into());
+            }
+            params.take_pref(&mut entry);
+            insert_entry(card, "onlineServices", "s", params.finish(entry));
+            true
+        }
+        Value::Url(_) => {
+            let mut entry = object(vec![("uri", value.to_string().into())]);
+            params.take_types(&mut entry, "contexts", CONTEXTS);
+            params.take_pref(&mut entry);
+            insert_entry(card, "links", "l", params.finish(entry));
+            true
+        }
+        Value::Photo(_) | Value::Logo(_) | Value::Sound(_) => {
+            let kind = match value {
+                Value::Photo(_) => "photo",
+                Value::Logo(_) => "logo",
+                _ => "sound",
+            };
+            let mut entry = object(vec![("kind", kind.into()), ("uri", value.to_string().into())]);
+            if let Some(media_type) = params.take("MEDIATYPE").first() {
+                entry.insert("mediaType".into(), media_type.as_str().into());
+            }
+            params.take_types(&mut entry, "contexts", CONTEXTS);
+            params.take_pref(&mut entry);
+            insert_entry(card, "media", "m", params.finish(entry));
+            true
+        }
+        Value::Birthday(date) | Value::Anniversary(date) | Value::DeathDate(date) => {
+            let date = match date {
+                TypeOrRaw::Type(date) => anniversary_date(date),
+                TypeOrRaw::Raw(_) => None,
+            };
+            let date = match date {
+                Some(date) => date,
+                None => return false,
+            };
+            let kind = match value {
+                Value::Birthday(_) => "birth",
+                Value::DeathDate(_) => "death",
+                _ => "wedding",
+            };
+            let entry = object(vec![("kind", kind.into()), ("date", date)]);
+            insert_entry(card, "anniversaries", "d", params.finish(entry));
+            true
+        }
+        Value::Note(_) => {
+            let entry = object(vec![("note", text().into())]);
+            insert_entry(card, "notes", "n", params.finish(entry));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Convert a vcard date into a JSContact `PartialDate` or `Timestamp`.
+fn anniversary_date(value: &DateAndOrTime) -> Option<Json> {
+    let date = match value {
+        DateAndOrTime::Date(date) => date,
+        DateAndOrTime::DateTime(..) => {
+            let utc = extended_date_and_or_time("timestamp", &value.to_string());
+            return Some(Json::Object(object(vec![
+                ("@type", "Timestamp".into()),
+                ("utc", utc.into()),
+            ])));
+        }
+        DateAndOrTime::Time(_) => return None,
+    };
+    let mut partial = object(vec![("@type", "PartialDate".into())]);
+    if let Some(year) = date.year {
+        partial.insert("year".into(), year.into());
+    }
+    if let Some(month) = date.month {
+        partial.insert("month".into(), month.into());
+    }
+    if let Some(day) = date.day {
+        partial.insert("day".into(), day.into());
+    }
+    Some(Json::Object(partial))
+}
+
+fn read_anniversary_date(date: &Map<String, Json>) -> Option<String> {
+    if let Some(utc) = date.get("utc").and_then(Json::as_str) {
+        return Some(basic_date_and_or_time("timestamp", utc));
+    }
+    let part = |key| date.get(key).and_then(Json::as_u64);
+    Some(match (part("year"), part("month"), part("day")) {
+        (Some(year), Some(month), Some(day)) => format!("{:04}{:02}{:02}", year, month, day),
+        (Some(year), Some(month), None) => format!("{:04}-{:02}", year, month),
+        (Some(year), None, None) => format!("{:04}", year),
+        (None, Some(month), Some(day)) => format!("--{:02}{:02}", month, day),
+        (None, Some(month), None) => format!("--{:02}", month),
+        (None, None, Some(day)) => format!("---{:02}", day),
+        _ => return None,
+    })
+}
+
+fn entries<'c>(card: &'c Map<String, Json>, collection: &str) -> Vec<&'c Map<String, Json>> {
+    match card.get(collection) {
+        Some(Json::Object(entries)) => entries.values().filter_map(Json::as_object).collect(),
+        _ => vec![],
+    }
+}
+
+fn string<'e>(entry: &'e Map<String, Json>, key: &str) -> &'e str {
+    entry.get(key).and_then(Json::as_str).unwrap_or("")
+}
+
+/// Rebuild the parameters of an entry, `types` are the mapped `TYPE` values.
+fn entry_parameters(
+    entry: &Map<String, Json>,
+    mut types: Vec<String>,
+    mut extra: Vec<(String, String)>,
+) -> (Option<String>, Vec<(String, String)>) {
+    let (group, params) = match entry.get("vCardParams") {
+        Some(Json::Object(params)) => parameters_from_value(params),
+        _ => (None, vec![]),
+    };
+    let mut output = vec![];
+    let mut rest = vec![];
+    for (key, value) in params {
+        if key == "TYPE" {
+            types.extend(value.split(',').map(String::from));
+        } else {
+            rest.push((key, value));
+        }
+    }
+    if !types.is_empty() {
+        output.push(("TYPE".to_string(), types.join(",")));
+    }
+    if let Some(pref) = entry.get("pref").and_then(Json::as_u64) {
+        output.push(("PREF".into(), pref.to_string()));
+    }
+    output.append(&mut extra);
+    output.extend(rest);
+    (group, output)
+}
+
+fn mapped_types(entry: &Map<String, Json>, key: &str, map: &[(&str, &str)]) -> Vec<String> {
+    match entry.get(key) {
+        Some(Json::Object(set)) => set
+            .iter()
+            .filter(|(_, value)| value.as_bool() == Some(true))
+            .filter_map(|(name, _)| map.iter().find(|(_, mapped)| mapped == name))
+            .map(|(ty, _)| ty.to_string())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn push(
+    properties: &mut Vec<Property<'static>>,
+    name: &str,
+    (group, params): (Option<String>, Vec<(String, String)>),
+    value: &str,
+) -> Result<(), FormatError> {
+    let name = match group {
+        Some(group) => format!("{}.{}", group, name),
+        None => name.into(),
+    };
+    properties.push(build_property(&name, &params, value)?);
+    Ok(())
+}
+
+fn read_card(
+    card: &Map<String, Json>,
+    properties: &mut Vec<Property<'static>>,
+) -> Result<(), FormatError> {
+    let none = || (None, vec![]);
+    if let Some(kind) = card.get("kind").and_then(Json::as_str) {
+        push(properties, "KIND", none(), kind)?;
+    }
+    if let Some(uid) = card.get("uid").and_then(Json::as_str) {
+        push(properties, "UID", none(), uid)?;
+    }
+    if let Some(Json::Object(name)) = card.get("name") {
+        if let Some(full) = name.get("full").and_then(Json::as_str) {
+            push(properties, "FN", none(), &escape_text(full))?;
+        }
+        if let Some(Json::Array(parts)) = name.get("components") {
+            let mut slots = vec![vec![]; NAME_COMPONENTS.len()];
+            for part in parts.iter().filter_map(Json::as_object) {
+                let index = match string(part, "kind") {
+                    "surname2" => Some(0),
+                    "generation" => Some(4),
+                    kind => NAME_COMPONENTS.iter().position(|x| *x == kind),
+                };
+                if let Some(index) = index {
+                    slots[index].push(escape_text(string(part, "value")));
+                }
+            }
+            let value = slots.iter().map(|x| x.join(",")).collect::<Vec<_>>().join(";");
+            push(properties, "N", none(), &value)?;
+        }
+    }
+    for entry in entries(card, "nicknames") {
+        let params = entry_parameters(entry, vec![], vec![]);
+        push(properties, "NICKNAME", params, &escape_text(string(entry, "name")))?;
+    }
+    for entry in entries(card, "organizations") {
+        let mut value = escape_text(string(entry, "name"));
+        if let Some(Json::Array(units)) = entry.get("units") {
+            for unit in units.iter().filter_map(Json::as_object) {
+                value.push(';');
+                value.push_str(&escape_text(string(unit, "name")));
+            }
+        }
+        let params = entry_parameters(entry, vec![], vec![]);
+        push(properties, "ORG", params, &value)?;
+    }
+    for entry in entries(card, "titles") {
+        let name = if string(entry, "kind") == "role" { "ROLE" } else { "TITLE" };
+        let params = entry_parameters(entry, vec![], vec![]);
+        push(properties, name, params, &escape_text(string(entry, "name")))?;
+    }
+    for entry in entries(card, "emails") {
+        let types = mapped_types(entry, "contexts", CONTEXTS);
+        let params = entry_parameters(entry, types, vec![]);
+        push(properties, "EMAIL", params, &escape_text(string(entry, "address")))?;
+    }
+    for entry in entries(card, "phones") {
+        let mut types = mapped_types(entry, "contexts", CONTEXTS);
+        types.extend(mapped_types(entry, "features", FEATURES));
+        let number = string(entry, "number");
+        let mut extra = vec![];
+        if number.starts_with("tel:") {
+            extra.push(("VALUE".to_string(), "uri".to_string()));
+        }
+        let params = entry_parameters(entry, types, vec![]);
+        let params = (params.0, params.1.into_iter().chain(extra).collect());
+        push(properties, "TEL", params, &escape_text(number))?;
+    }
+    for entry in entries(card, "addresses") {
+        let mut slots = vec![vec![]; ADDRESS_COMPONENTS.len()];
+        if let Some(Json::Array(parts)) = entry.get("components") {
+            for part in parts.iter().filter_map(Json::as_object) {
+                let index = match string(part, "kind") {
+                    "number" | "building" | "block" => Some(2),
+                    "room" | "floor" => Some(1),
+                    "district" | "subdistrict" => Some(3),
+                    kind => ADDRESS_COMPONENTS.iter().position(|x| *x == kind),
+                };
+                if let Some(index) = index {
+                    slots[index].push(escape_text(string(part, "value")));
+                }
+            }
+        }
+        let value = slots.iter().map(|x| x.join(",")).collect::<Vec<_>>().join(";");
+        let mut extra = vec![];
+        for (key, param) in [("full", "LABEL"), ("coordinates", "GEO"), ("timeZone", "TZ")] {
+            if let Some(text) = entry.get(key).and_then(Json::as_str) {
+                let text = if key == "full" { escape_text(text) } else { text.into() };
+                extra.push((param.to_string(), quote_parameter_value(&text)));
+            }
+        }
+        let types = mapped_types(entry, "contexts", CONTEXTS);
+        let params = entry_parameters(entry, types, extra);
+        push(properties, "ADR", params, &value)?;
+    }
+    for entry in entries(card, "onlineServices") {
+        let name = match entry.get("vCardName").and_then(Json::as_str) {
+            Some(name) => name.to_uppercase(),
+            None => "SOCIALPROFILE".into(),
+        };
+        let mut extra = vec![];
+        if let Some(service) = entry.get("service").and_then(Json::as_str) {
+            extra.push(("SERVICE-TYPE".to_string(), quote_parameter_value(service)));
+        }
+        let value = match entry.get("uri").and_then(Json::as_str) {
+            Some(uri) => uri.to_string(),
+            None => escape_text(string(entry, "user")),
+        };
+        let types = mapped_types(entry, "contexts", CONTEXTS);
+        let params = entry_parameters(entry, types, extra);
+        push(properties, &name, params, &value)?;
+    }
+    for entry in entries(card, "links") {
+        let types = mapped_types(entry, "contexts", CONTEXTS);
+        let params = entry_parameters(entry, types, vec![]);
+        push(properties, "URL", params, string(entry, "uri"))?;
+    }
+    for entry in entries(card, "media") {
+        let name = match string(entry, "kind") {
+            "logo" => "LOGO",
+            "sound" => "SOUND",
+            _ => "PHOTO",
+        };
+        let mut extra = vec![];
+        if let Some(media_type) = entry.get("mediaType").and_then(Json::as_str) {
+            extra.push(("MEDIATYPE".to_string(), quote_parameter_value(media_type)));
+        }
+        let types = mapped_types(entry, "contexts", CONTEXTS);
+        let params = entry_parameters(entry, types, extra);
+        push(properties, name, params, string(entry, "uri"))?;
+    }
+    for entry in entries(card, "anniversaries") {
+        let name = match string(entry, "kind") {
+            "birth" => "BDAY",
+            "wedding" => "ANNIVERSARY",
+            "death" => "DEATHDATE",
+            _ => continue,
+        };
+        let date = match entry.get("date").and_then(Json::as_object) {
+            Some(date) => read_anniversary_date(date),
+            None => None,
+        };
+        if let Some(date) = date {
+            let params = entry_parameters(entry, vec![], vec![]);
+            push(properties, name, params, &date)?;
+        }
+    }
+    if let Some(Json::Object(keywords)) = card.get("keywords") {
+        let value = keywords
+            .iter()
+            .filter(|(_, value)| value.as_bool() == Some(true))
+            .map(|(keyword, _)| escape_text(keyword))
+            .collect::<Vec<_>>()
+            .join(",");
+        push(properties, "CATEGORIES", none(), &value)?;
+    }
+    for entry in entries(card, "notes") {
+        let params = entry_parameters(entry, vec![], vec![]);
+        push(properties, "NOTE", params, &escape_text(string(entry, "note")))?;
+    }
+    if let Some(prod_id) = card.get("prodId").and_then(Json::as_str) {
+        push(properties, "PRODID", none(), &escape_text(prod_id))?;
+    }
+    if let Some(updated) = card.get("updated") {
+        let updated = basic_date_and_or_time("timestamp", &json_to_string(updated));
+        push(properties, "REV", none(), &updated)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,509 @@
+//! [schema.org](https://schema.org) JSON-LD export and import.
+//!
+//! The type comes from `KIND`, `org` and `group` become an `Organization`,
+//! `location` a `Place` and anything else a `Person`. The mapping is lossy,
+//! properties without a schema.org equivalent are dropped.
+
+use serde_json::{Map, Value as Json};
+
+use super::common::{
+    build::build_property,
+    dates::{basic_date_and_or_time, extended_date_and_or_time},
+    text::plain_text,
+};
+use super::FormatError;
+use crate::parse::{escape_text, split_unescaped, unescape_text};
+use crate::types::{GeoPosition, Kind, TelephoneType};
+use crate::{Property, TypeOrRaw, VCard, Value, Vcf};
+
+/// The `@context` of every exported object.
+pub const CONTEXT: &str = "https://schema.org";
+
+/// schema.org `PostalAddress` properties for the `ADR` components, the
+/// extended address is appended to the street address.
+const ADDRESS: &[(usize, &str)] = &[
+    (0, "postOfficeBoxNumber"),
+    (2, "streetAddress"),
+    (3, "addressLocality"),
+    (4, "addressRegion"),
+    (5, "postalCode"),
+    (6, "addressCountry"),
+];
+
+/// schema.org properties for the `N` components.
+const NAME: &[(usize, &str)] = &[
+    (0, "familyName"),
+    (1, "givenName"),
+    (2, "additionalName"),
+    (3, "honorificPrefix"),
+    (4, "honorificSuffix"),
+];
+
+/// Convert a vcard into a schema.org object.
+pub fn to_value(vcard: &VCard) -> Json {
+    let mut object = write_vcard(vcard);
+    let mut output = Map::new();
+    output.insert("@context".into(), CONTEXT.into());
+    output.append(&mut object);
+    Json::Object(output)
+}
+
+/// Convert a vcard into a schema.org JSON-LD string.
+pub fn to_string(vcard: &VCard) -> String {
+    to_value(vcard).to_string()
+}
+
+/// Convert every vcard into a schema.org `@graph`.
+pub fn vcf_to_value(vcf: &Vcf) -> Json {
+    let graph = vcf.0.iter().map(|x| Json::Object(write_vcard(x))).collect();
+    let mut output = Map::new();
+    output.insert("@context".into(), CONTEXT.into());
+    output.insert("@graph".into(), Json::Array(graph));
+    Json::Object(output)
+}
+
+/// Convert every vcard into a schema.org JSON-LD string.
+pub fn vcf_to_string(vcf: &Vcf) -> String {
+    vcf_to_value(vcf).to_string()
+}
+
+/// Read the first `Person`, `Organization` or `Place` of a JSON-LD value.
+pub fn from_value(value: &Json) -> Result<VCard<'static>, FormatError> {
+    vcf_from_value(value)?
+        .0
+        .into_iter()
+        .next()
+        .ok_or_else(|| FormatError::Syntax("no Person, Organization or Place found".into()))
+}
+
+/// Read the first `Person`, `Organization` or `Place` of a JSON-LD string.
+pub fn from_str(input: &str) -> Result<VCard<'static>, FormatError> {
+    from_value(&parse_json(input)?)
+}
+
+/// Read every `Person`, `Organization` or `Place` of a JSON-LD value, it
+/// may be a single object, an array or a `@graph`.
+pub fn vcf_from_value(value: &Json) -> Result<Vcf<'static>, FormatError> {
+    let mut objects = vec![];
+    collect_objects(value, &mut objects);
+    objects
+        .into_iter()
+        .map(read_object)
+        .collect::<Result<Vec<_>, _>>()
+        .map(Vcf)
+}
+
+/// Read every `Person`, `Organization` or `Place` of a JSON-LD string.
+pub fn vcf_from_str(input: &str) -> Result<Vcf<'static>, FormatError> {
+    vcf_from_value(&parse_json(input)?)
+}
+
+fn parse_json(input: &str) -> Result<Json, FormatError> {
+    serde_json::from_str(input).map_err(|err| FormatError::Syntax(err.to_string()))
+}
+
+/// Insert a value, turning the existing one into an array if needed.
+fn insert(object: &mut Map<String, Json>, key: &str, value: Json) {
+    match object.get_mut(key) {
+        Some(Json::Array(items)) => items.push(value),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Json::Array(vec![first, value]);
+        }
+        None => {
+            object.insert(key.into(), value);
+        }
+    }
+}
+
+/// Unescaped components of a structured value.
+fn components(value: &Value) -> Vec<String> {
+    split_unescaped(&value.to_string(), ';')
+        .into_iter()
+        .map(unescape_text)
+        .collect()
+}
+
+fn schema_type(vcard: &VCard) -> &'static str {
+    let kind = vcard.iter().find_map(|x| match &x.value {
+        Value::Kind(kind) => Some(kind),
+        _ => None,
+    });
+    match kind {
+        Some(TypeOrRaw::Type(Kind::Organization)) | Some(TypeOrRaw::Type(Kind::Group)) => {
+            "Organization"
+        }
+        Some(TypeOrRaw::Type(Kind::Location)) => "Place",
+        _ => "Person",
+    }
+}
+
+/// Latitude and longitude of a `GEO` value, either typed or a `geo:` uri.
+fn coordinates(value: &TypeOrRaw<GeoPosition>) -> Option<(f64, f64)> {
+    match value {
+        TypeOrRaw::Type(geo) => Some((geo.latitude, geo.longitude)),
+        TypeOrRaw::Raw(raw) => {
+            let raw = raw.trim_start_matches("geo:");
+            let mut parts = raw.split(';').next().unwrap_or("").split(',');
+            let latitude = parts.next()?.trim().parse().ok()?;
+            let longitude = parts.next()?.trim().parse().ok()?;
+            Some((latitude, longitude))
+        }
+    }
+}
+
+fn write_vcard(vcard: &VCard) -> Map<String, Json> {
+    let ty = schema_type(vcard);
+    let mut object = Map::new();
+    object.insert("@type".into(), ty.into());
+    let text = |value: &Value| Json::from(plain_text(value));
+    for property in vcard.iter() {
+        let value = &property.value;
+        match value {
+            Value::Fn(_) => insert(&mut object, "name", text(value)),
+            Value::N(_) if ty == "Person" => {
+                let parts = components(value);
+                for (index, key) in NAME {
+                    match parts.get(*index) {
+                        Some(part) if !part.is_empty() => {
+                            insert(&mut object, key, part.as_str().into())
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Value::Nickname(items) => {
+                for item in items.iter() {
+                    insert(&mut object, "alternateName", unescape_text(item).into());
+                }
+            }
+            Value::Organization(_) if ty == "Person" => {
+                let mut org = Map::new();
+                org.insert("@type".into(), "Organization".into());
+                let parts = components(value);
+                org.insert(
+                    "name".into(),
+                    parts.first().cloned().unwrap_or_default().into(),
+                );
+                insert(&mut object, "worksFor", Json::Object(org));
+            }
+            Value::Organization(_) => {
+                let parts = components(value);
+                let name = parts.first().cloned().unwrap_or_default();
+                insert(&mut object, "legalName", name.into());
+                for unit in parts.iter().skip(1) {
+                    let mut department = Map::new();
+                    department.insert("@type".into(), "Organization".into());
+                    department.insert("name".into(), unit.as_str().into());
+                    insert(&mut object, "department", Json::Object(department));
+                }
+            }
+            Value::Title(_) if ty == "Person" => insert(&mut object, "jobTitle", text(value)),
+            Value::Email(_) => insert(&mut object, "email", text(value)),
+            Value::Tel(_) => {
+                let types = property.params.telephone_types();
+                let key = if types.contains(&TelephoneType::Fax) {
+                    "faxNumber"
+                } else {
+                    "telephone"
+                };
+                let number = unescape_text(&value.to_string());
+                insert(&mut object, key, number.trim_start_matches("tel:").into());
+            }
+            Value::Adr(_) => {
+                let parts = components(value);
+                let mut address = Map::new();
+                address.insert("@type".into(), "PostalAddress".into());
+                for (index, key) in ADDRESS {
+                    let mut part = parts.get(*index).cloned().unwrap_or_default();
+                    if *index == 2 && parts.get(1).map(|x| !x.is_empty()) == Some(true) {
+                        part = [part.as_str(), parts[1].as_str()]
+                            .iter()
+                            .filter(|x| !x.is_empty())
+                            .cloned()
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                    }
+                    if !part.is_empty() {
+                        address.insert((*key).into(), part.into());
+                    }
+                }
+                insert(&mut object, "address", Json::Object(address));
+            }
+            Value::Geo(geo) => {
+                if let Some((latitude, longitude)) = coordinates(geo) {
+                    let mut geo = Map::new();
+                    geo.insert("@type".into(), "GeoCoordinates".into());
+                    geo.insert("latitude".into(), latitude.into());
+                    geo.insert("longitude".into(), longitude.into());
+                    insert(&mut object, "geo", Json::Object(geo));
+                }
+            }
+            Value::Url(_) => insert(&mut object, "sameAs", value.to_string().into()),
+            Value::SocialProfile(profile) => {
+                let uri = match profile {
+                    TypeOrRaw::Type(profile) => profile.uri(),
+                    TypeOrRaw::Raw(_) => None,
+                };
+                let uri = uri.unwrap_or_else(|| value.to_string());
+                insert(&mut object, "sameAs", uri.into())
+            }
+            Value::Other(name, _) if name.eq_ignore_ascii_case("X-SOCIALPROFILE") => {
+                insert(&mut object, "sameAs", value.to_string().into())
+            }
+            Value::Birthday(_) => {
+                let key = match ty {
+                    "Person" => "birthDate",
+                    "Organization" => "foundingDate",
+                    _ => continue,
+                };
+                let date = extended_date_and_or_time("date-and-or-time", &value.to_string());
+                insert(&mut object, key, date.into());
+            }
+            Value::Gender(_) if ty == "Person" => {
+                let gender = match value.to_string().split(';').next() {
+                    Some("M") => "https://schema.org/Male",
+                    Some("F") => "https://schema.org/Female",
+                    _ => continue,
+                };
+                insert(&mut object, "gender", gender.into());
+            }
+            Value::Note(_) => insert(&mut object, "description", text(value)),
+            Value::Photo(_) => insert(&mut object, "image", value.to_string().into()),
+            Value::Logo(_) if ty != "Person" => {
+                insert(&mut object, "logo", value.to_string().into())
+            }
+            Value::Uid(_) => insert(&mut object, "identifier", text(value)),
+            Value::Lang(_) if ty == "Person" => insert(&mut object, "knowsLanguage", text(value)),
+            Value::Member(_) if ty == "Organization" => {
+                let mut member = Map::new();
+                member.insert("@id".into(), value.to_string().into());
+                insert(&mut object, "member", Json::Object(member));
+            }
+            _ => {}
+        }
+    }
+    object
+}
+
+/// Find the objects with a supported `@type`, looking into arrays and
+/// `@graph`.
+fn collect_objects<'j>(value: &'j Json, objects: &mut Vec<&'j Map<String, Json>>) {
+    match value {
+        Json::Array(items) => {
+            for item in items {
+                collect_objects(item, objects);
+            }
+        }
+        Json::Object(object) => {
+            if let Some(graph) = object.get("@graph") {
+                collect_objects(graph, objects);
+            }
+            if object_type(object).is_some() {
+                objects.push(object);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn object_type(object: &Map<String, Json>) -> Option<&'static str> {
+    let types = match object.get("@type") {
+        Some(Json::String(ty)) => vec![ty.as_str()],
+        Some(Json::Array(types)) => types.iter().filter_map(Json::as_str).collect(),
+        _ => return None,
+    };
+    types.iter().find_map(|ty| {
+        match ty
+            .trim_start_matches("https://schema.org/")
+            .trim_start_matches("http://schema.org/")
+        {
+            "Person" => Some("Person"),
+            "Organization"
+            | "Corporation"
+            | "LocalBusiness"
+            | "NGO"
+            | "EducationalOrganization" => Some("Organization"),
+            "Place" => Some("Place"),
+            _ => None,
+        }
+    })
+}
+
+/// Every value of a key, a single value or an array of them.
+fn values<'j>(object: &'j Map<String, Json>, key: &str) -> Vec<&'j Json> {
+    match object.get(key) {
+        Some(Json::Array(items)) => items.iter().collect(),
+        Some(Json::Null) | None => vec![],
+        Some(value) => vec![value],
+    }
+}
+
+/// Every text value of a key, objects give their `key` entry.
+fn strings(object: &Map<String, Json>, key: &str, inner: &str) -> Vec<String> {
+    values(object, key)
+        .into_iter()
+        .filter_map(|x| match x {
+            Json::String(text) => Some(text.clone()),
+            Json::Number(number) => Some(number.to_string()),
+            Json::Object(object) => object.get(inner).and_then(Json::as_str).map(String::from),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Urls of an `ImageObject` or plain url values.
+fn media(object: &Map<String, Json>, key: &str) -> Vec<String> {
+    values(object, key)
+        .into_iter()
+        .filter_map(|x| match x {
+            Json::String(url) => Some(url.as_str()),
+            Json::Object(image) => image
+                .get("contentUrl")
+                .or_else(|| image.get("url"))
+                .and_then(Json::as_str),
+            _ => None,
+        })
+        .map(String::from)
+        .collect()
+}
+
+fn number(value: Option<&Json>) -> Option<f64> {
+    match value? {
+        Json::Number(number) => number.as_f64(),
+        Json::String(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn read_object(object: &Map<String, Json>) -> Result<VCard<'static>, FormatError> {
+    let ty = object_type(object).unwrap_or("Person");
+    let mut properties = vec![Property::from(Value::Version("4.0".into()))];
+    let mut push = |name: &str, params: &[(String, String)], value: &str| {
+        properties.push(build_property(name, params, value)?);
+        Ok::<_, FormatError>(())
+    };
+    match ty {
+        "Organization" => push("KIND", &[], "org")?,
+        "Place" => push("KIND", &[], "location")?,
+        _ => {}
+    }
+    let texts = |key| {
+        strings(object, key, "name")
+            .into_iter()
+            .map(|x| escape_text(&x))
+    };
+    for name in texts("name").take(1) {
+        push("FN", &[], &name)?;
+    }
+    let name = NAME
+        .iter()
+        .map(|(_, key)| texts(key).collect::<Vec<_>>().join(","))
+        .collect::<Vec<_>>();
+    if name.iter().any(|x| !x.is_empty()) {
+        push("N", &[], &name.join(";"))?;
+    }
+    let nicknames = texts("alternateName").collect::<Vec<_>>();
+    if !nicknames.is_empty() {
+        push("NICKNAME", &[], &nicknames.join(","))?;
+    }
+    for org in texts("worksFor") {
+        push("ORG", &[], &org)?;
+    }
+    for org in texts("legalName").take(1) {
+        let units = texts("department").collect::<Vec<_>>();
+        let value = std::iter::once(org)
+            .chain(units)
+            .collect::<Vec<_>>()
+            .join(";");
+        push("ORG", &[], &value)?;
+    }
+    for title in texts("jobTitle") {
+        push("TITLE", &[], &title)?;
+    }
+    for email in strings(object, "email", "email") {
+        push(
+            "EMAIL",
+            &[],
+            &escape_text(email.trim_start_matches("mailto:")),
+        )?;
+    }
+    for tel in texts("telephone") {
+        push("TEL", &[], &tel)?;
+    }
+    for fax in texts("faxNumber") {
+        push("TEL", &[("TYPE".into(), "fax".into())], &fax)?;
+    }
+    for address in values(object, "address") {
+        match address {
+            Json::Object(address) => {
+                let mut parts = vec![String::new(); 7];
+                for (index, key) in ADDRESS {
+                    parts[*index] = strings(address, key, "name")
+                        .into_iter()
+                        .next()
+                        .map(|x| escape_text(&x))
+                        .unwrap_or_default();
+                }
+                push("ADR", &[], &parts.join(";"))?;
+            }
+            Json::String(label) => {
+                let label = format!("\"{}\"", escape_text(label));
+                push("ADR", &[("LABEL".into(), label)], ";;;;;;")?;
+            }
+            _ => {}
+        }
+    }
+    for geo in values(object, "geo")
+        .into_iter()
+        .filter_map(Json::as_object)
+    {
+        let latitude = number(geo.get("latitude"));
+        let longitude = number(geo.get("longitude"));
+        if let Some((latitude, longitude)) = latitude.zip(longitude) {
+            push("GEO", &[], &format!("geo:{},{}", latitude, longitude))?;
+        }
+    }
+    for url in strings(object, "url", "url")
+        .into_iter()
+        .chain(strings(object, "sameAs", "url"))
+    {
+        push("URL", &[], &url)?;
+    }
+    for key in &["birthDate", "foundingDate"] {
+        for date in strings(object, key, "") {
+            push(
+                "BDAY",
+                &[],
+                &basic_date_and_or_time("date-and-or-time", &date),
+            )?;
+        }
+    }
+    for gender in strings(object, "gender", "name") {
+        let gender = gender.rsplit('/').next().unwrap_or("").to_lowercase();
+        match gender.as_str() {
+            "male" => push("GENDER", &[], "M")?,
+            "female" => push("GENDER", &[], "F")?,
+            _ => {}
+        }
+    }
+    for note in texts("description") {
+        push("NOTE", &[], &note)?;
+    }
+    for image in media(object, "image") {
+        push("PHOTO", &[], &image)?;
+    }
+    for logo in media(object, "logo") {
+        push("LOGO", &[], &logo)?;
+    }
+    for uid in texts("identifier").take(1) {
+        push("UID", &[], &uid)?;
+    }
+    for lang in strings(object, "knowsLanguage", "alternateName") {
+        push("LANG", &[], &lang)?;
+    }
+    for member in strings(object, "member", "@id") {
+        push("MEMBER", &[], &member)?;
+    }
+    Ok(VCard(properties))
+}
@@ -0,0 +1,56 @@
+//! KML export of positioned contacts.
+//!
+//! Every vcard with a WGS-84 position, from `GEO` or the `GEO` parameter of
+//! an `ADR`, becomes a `Placemark` with its name, address and a `Point`.
+//! Vcards without one are left out.
+
+use super::common::places::{address_line, formatted_name};
+use crate::parse::escape_markup;
+use crate::types::GeoPosition;
+use crate::{VCard, Vcf};
+
+/// The KML 2.2 namespace.
+pub const NAMESPACE: &str = "http://www.opengis.net/kml/2.2";
+
+/// Convert a vcard into a KML document of at most one placemark.
+pub fn to_string(vcard: &VCard) -> String {
+    document(std::slice::from_ref(vcard))
+}
+
+/// Convert every positioned vcard into one KML document.
+pub fn vcf_to_string(vcf: &Vcf) -> String {
+    document(&vcf.0)
+}
+
+fn document(vcards: &[VCard]) -> String {
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    output.push_str(&format!("<kml xmlns=\"{}\">\n<Document>\n", NAMESPACE));
+    for vcard in vcards {
+        write_placemark(&mut output, vcard);
+    }
+    output.push_str("</Document>\n</kml>\n");
+    output
+}
+
+fn write_placemark(output: &mut String, vcard: &VCard) {
+    let position = match vcard.position().filter(GeoPosition::is_wgs84) {
+        Some(position) => position,
+        None => return,
+    };
+    output.push_str("<Placemark>\n");
+    if let Some(name) = formatted_name(vcard) {
+        output.push_str(&format!("<name>{}</name>\n", escape_markup(&name)));
+    }
+    if let Some(address) = address_line(vcard) {
+        output.push_str(&format!("<address>{}</address>\n", escape_markup(&address)));
+    }
+    let mut coordinates = format!("{},{}", position.longitude, position.latitude);
+    if let Some(altitude) = position.altitude {
+        coordinates.push_str(&format!(",{}", altitude));
+    }
+    output.push_str(&format!(
+        "<Point><coordinates>{}</coordinates></Point>\n",
+        coordinates
+    ));
+    output.push_str("</Placemark>\n");
+}
@@ -0,0 +1,475 @@
+//! [LDIF](https://tools.ietf.org/html/rfc2849) reading and writing using the
+//! `inetOrgPerson` and `mozillaAbPersonAlpha` attributes, as exchanged by
+//! Thunderbird and LDAP directories.
+//!
+//! Only the properties with a matching attribute are converted, everything
+//! else is dropped.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use super::common::{build::build_property, text::plain_text};
+use super::FormatError;
+use crate::parse::{escape_text, split_unescaped, unescape_text};
+use crate::{Property, VCard, Value, Vcf};
+
+/// Lines longer than this are folded.
+const LINE_LENGTH: usize = 76;
+
+const OBJECT_CLASSES: &[&str] = &[
+    "top",
+    "person",
+    "organizationalPerson",
+    "inetOrgPerson",
+    "mozillaAbPersonAlpha",
+];
+
+/// Address attributes for the `ADR` components, home first then work.
+const HOME_ADDRESS: &[(usize, &str)] = &[
+    (2, "mozillaHomeStreet"),
+    (1, "mozillaHomeStreet2"),
+    (3, "mozillaHomeLocalityName"),
+    (4, "mozillaHomeState"),
+    (5, "mozillaHomePostalCode"),
+    (6, "mozillaHomeCountryName"),
+];
+const WORK_ADDRESS: &[(usize, &str)] = &[
+    (0, "postOfficeBox"),
+    (2, "street"),
+    (1, "mozillaWorkStreet2"),
+    (3, "l"),
+    (4, "st"),
+    (5, "postalCode"),
+    (6, "c"),
+];
+
+/// Telephone attributes and the `TYPE` they stand for.
+const PHONES: &[(&str, &str)] = &[
+    ("telephoneNumber", "work"),
+    ("homePhone", "home"),
+    ("mobile", "cell"),
+    ("facsimileTelephoneNumber", "fax"),
+    ("pager", "pager"),
+];
+
+/// Convert a vcard into an LDIF entry.
+pub fn to_string(vcard: &VCard) -> String {
+    let mut output = String::new();
+    write_entry(&mut output, vcard);
+    output
+}
+
+/// Convert every vcard into an LDIF file.
+pub fn vcf_to_string(vcf: &Vcf) -> String {
+    let mut output = String::new();
+    for (index, vcard) in vcf.0.iter().enumerate() {
+        if index > 0 {
+            output.push('\n');
+        }
+        write_entry(&mut output, vcard);
+    }
+    output
+}
+
+/// Read the first entry of an LDIF file.
+pub fn from_str(input: &str) -> Result<VCard<'static>, FormatError> {
+    vcf_from_str(input)?
+        .0
+        .into_iter()
+        .next()
+        .ok_or_else(|| FormatError::Syntax("no entry found".into()))
+}
+
+/// Read every entry of an LDIF file.
+pub fn vcf_from_str(input: &str) -> Result<Vcf<'static>, FormatError> {
+    parse_records(input)?
+        .iter()
+        .filter(|x| x.iter().any(|(name, _)| name == "dn"))
+        .map(|x| read_entry(x))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Vcf)
+}
+
+fn parameter<'p>(property: &'p Property, name: &str) -> Option<&'p str> {
+    property
+        .params
+        .0
+        .iter()
+        .find(|x| x.name.eq_ignore_ascii_case(name))
+        .and_then(|x| x.value.as_deref())
+        .map(|x| x.trim_matches('"'))
+}
+
+/// Unescaped components of a structured value.
+fn components(value: &Value) -> Vec<String> {
+    split_unescaped(&value.to_string(), ';')
+        .into_iter()
+        .map(unescape_text)
+        .collect()
+}
+
+/// Whether a value can be written without base64, see `SAFE-STRING`.
+fn is_safe(value: &str) -> bool {
+    !value.starts_with([' ', ':', '<'])
+        && !value.ends_with(' ')
+        && value
+            .chars()
+            .all(|x| x.is_ascii() && x != '\0' && x != '\n' && x != '\r')
+}
+
+fn write_attribute(output: &mut String, name: &str, value: &[u8]) {
+    let line = match std::str::from_utf8(value) {
+        Ok(text) if is_safe(text) => format!("{}: {}", name, text),
+        _ => format!("{}:: {}", name, STANDARD.encode(value)),
+    };
+    let mut width = 0;
+    for x in line.chars() {
+        if width + x.len_utf8() > LINE_LENGTH {
+            output.push_str("\n ");
+            width = 1;
+        }
+        output.push(x);
+        width += x.len_utf8();
+    }
+    output.push('\n');
+}
+
+fn write_entry(output: &mut String, vcard: &VCard) {
+    let mut attributes: Vec<(&str, Vec<u8>)> = vec![];
+    let mut add = |name, value: String| {
+        if !value.is_empty() {
+            attributes.push((name, value.into_bytes()));
+        }
+    };
+    let mut emails = vec![];
+    let mut home_address = None;
+    let mut work_address = None;
+    let mut photo = None;
+    for property in vcard.iter() {
+        let value = &property.value;
+        let types = property.params.types();
+        match value {
+            Value::Fn(_) => add("cn", unescape_text(&value.to_string())),
+            Value::N(_) => {
+                let parts = components(value);
+                add("givenName", parts.get(1).cloned().unwrap_or_default());
+                add("sn", parts.first().cloned().unwrap_or_default());
+            }
+            Value::Nickname(items) => {
+                if let Some(nickname) = items.iter().next() {
+                    add("mozillaNickname", unescape_text(nickname));
+                }
+            }
+            Value::Organization(_) => {
+                let parts = components(value);
+                add("o", parts.first().cloned().unwrap_or_default());
+                add("ou", parts.get(1).cloned().unwrap_or_default());
+            }
+            Value::Title(_) => add("title", unescape_text(&value.to_string())),
+            Value::Email(_) => {
+                let preferred = parameter(property, "PREF") == Some("1");
+                let address = plain_text(value);
+                if preferred {
+                    emails.insert(0, address);
+                } else {
+                    emails.push(address);
+                }
+            }
+            Value::Tel(_) => {
+                let name = PHONES
+                    .iter()
+                    .rev()
+                    .find(|(_, ty)| types.iter().any(|x| x == ty))
+                    .map(|(name, _)| *name)
+                    .unwrap_or("telephoneNumber");
+                let number = value.to_string();
+                add(name, number.trim_start_matches("tel:").to_string());
+            }
+            Value::Adr(_) if types.iter().any(|x| x == "home") => {
+                home_address = home_address.or(Some(property));
+            }
+            Value::Adr(_) => work_address = work_address.or(Some(property)),
+            Value::Url(_) if types.iter().any(|x| x == "home") => {
+                add("mozillaHomeUrl", value.to_string())
+            }
+            Value::Url(_) => add("workURL", value.to_string()),
+            Value::Birthday(_) => {
+                if let Some((year, month, day)) = date_parts(&value.to_string()) {
+                    add("birthyear", year.unwrap_or_default());
+                    add("birthmonth", month.unwrap_or_default());
+                    add("birthday", day.unwrap_or_default());
+                }
+            }
+            Value::Note(_) => add("description", unescape_text(&value.to_string())),
+            Value::Photo(_) => photo = photo.or(Some(value.to_string())),
+            Value::Rev(rev) => add("modifytimestamp", rev.to_string().replace('T', "")),
+            _ => {}
+        }
+    }
+    let mut emails = emails.into_iter();
+    if let Some(mail) = emails.next() {
+        add("mail", mail);
+    }
+    if let Some(mail) = emails.next() {
+        add("mozillaSecondEmail", mail);
+    }
+    for (property, names) in &[(home_address, HOME_ADDRESS), (work_address, WORK_ADDRESS)] {
+        if let Some(property) = property {
+            let parts = components(&property.value);
+            for (index, name) in names.iter() {
+                add(name, parts.get(*index).cloned().unwrap_or_default());
+            }
+        }
+    }
+    if let Some(property) = work_address {
+        let label = match parameter(property, "LABEL") {
+            Some(label) => unescape_text(label).replace('\n', "$"),
+            None => {
+                let parts = components(&property.value);
+                let lines = [2, 1, 3, 4, 5, 6].iter().filter_map(|x| parts.get(*x));
+                let lines = lines.filter(|x| !x.is_empty()).cloned().collect::<Vec<_>>();
+                lines.join("$")
+            }
+        };
+        add("postalAddress", label);
+    }
+    if let Some(data) = photo
+        .as_deref()
+        .and_then(|x| x.strip_prefix("data:image/jpeg;base64,"))
+    {
+        if let Ok(data) = STANDARD.decode(data) {
+            attributes.push(("jpegPhoto", data));
+        }
+    }
+
+    let cn = attributes.iter().find(|(name, _)| *name == "cn");
+    let mail = attributes.iter().find(|(name, _)| *name == "mail");
+    let dn = [("cn", cn), ("mail", mail)]
+        .iter()
+        .filter_map(|(name, x)| x.map(|(_, value)| (name, value)))
+        .map(|(name, value)| format!("{}={}", name, escape_dn(&String::from_utf8_lossy(value))))
+        .collect::<Vec<_>>()
+        .join(",");
+    write_attribute(output, "dn", dn.as_bytes());
+    for class in OBJECT_CLASSES {
+        write_attribute(output, "objectclass", class.as_bytes());
+    }
+    for (name, value) in &attributes {
+        write_attribute(output, name, value);
+    }
+}
+
+/// Escape a value used in a distinguished name, see RFC 4514.
+fn escape_dn(value: &str) -> String {
+    let mut output = String::new();
+    for (index, x) in value.chars().enumerate() {
+        let edge =
+            index == 0 && (x == ' ' || x == '#') || index + 1 == value.chars().count() && x == ' ';
+        if edge || ",+\"\\<>;=".contains(x) {
+            output.push('\\');
+        }
+        output.push(x);
+    }
+    output
+}
+
+/// Year, month and day of a vcard date, each as digits.
+fn date_parts(value: &str) -> Option<(Option<String>, Option<String>, Option<String>)> {
+    let value = value.split('T').next().unwrap_or("");
+    if let Some(day) = value.strip_prefix("---") {
+        return Some((None, None, Some(day.to_string())));
+    }
+    if let Some(rest) = value.strip_prefix("--") {
+        let rest = rest.replace('-', "");
+        return match rest.len() {
+            2 => Some((None, Some(rest), None)),
+            4 => Some((None, Some(rest[..2].into()), Some(rest[2..].into()))),
+            _ => None,
+        };
+    }
+    let digits = value.replace('-', "");
+    if !digits.chars().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    match digits.len() {
+        4 => Some((Some(digits), None, None)),
+        6 => Some((Some(digits[..4].into()), Some(digits[4..].into()), None)),
+        8 => Some((
+            Some(digits[..4].into()),
+            Some(digits[4..6].into()),
+            Some(digits[6..].into()),
+        )),
+        _ => None,
+    }
+}
+
+/// Attribute names, lowercase, and values of an entry.
+type Record = Vec<(String, Vec<u8>)>;
+
+/// Split the file into records.
+fn parse_records(input: &str) -> Result<Vec<Record>, FormatError> {
+    let mut lines: Vec<String> = vec![];
+    for line in input.lines() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.strip_prefix(' '), lines.last_mut()) {
+            (Some(rest), Some(last)) if !last.is_empty() => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    let mut records = vec![];
+    let mut record = vec![];
+    for line in lines {
+        if line.is_empty() {
+            if !record.is_empty() {
+                records.push(std::mem::take(&mut record));
+            }
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        let index = line
+            .find(':')
+            .ok_or_else(|| FormatError::Syntax(format!("missing ':' in \"{}\"", line)))?;
+        let name = line[..index].to_lowercase();
+        let rest = &line[index + 1..];
+        let value = if let Some(data) = rest.strip_prefix(':') {
+            STANDARD
+                .decode(data.trim())
+                .map_err(|err| FormatError::Syntax(err.to_string()))?
+        } else if rest.starts_with('<') {
+            return Err(FormatError::Syntax(format!(
+                "url values are not supported: \"{}\"",
+                line
+            )));
+        } else {
+            rest.trim_start_matches(' ').as_bytes().to_vec()
+        };
+        if name != "version" || !record.is_empty() {
+            record.push((name, value));
+        }
+    }
+    if !record.is_empty() {
+        records.push(record);
+    }
+    Ok(records)
+}
+
+fn values<'v>(
+    attributes: &'v [(String, Vec<u8>)],
+    name: &'v str,
+) -> impl Iterator<Item = String> + 'v {
+    attributes
+        .iter()
+        .filter(move |(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| String::from_utf8_lossy(value).into_owned())
+}
+
+fn read_entry(attributes: &[(String, Vec<u8>)]) -> Result<VCard<'static>, FormatError> {
+    let text = |name| values(attributes, name);
+    let first = |name: &str| values(attributes, name).next().unwrap_or_default();
+    let escaped = |name: &str| escape_text(&first(name));
+    let mut properties = vec![Property::from(Value::Version("4.0".into()))];
+    let mut push = |name: &str, params: &[(String, String)], value: &str| {
+        properties.push(build_property(name, params, value)?);
+        Ok::<_, FormatError>(())
+    };
+    let (given, family) = (escaped("givenName"), escaped("sn"));
+    let formatted = match first("cn") {
+        cn if !cn.is_empty() => escape_text(&cn),
+        _ => [given.as_str(), family.as_str()]
+            .iter()
+            .filter(|x| !x.is_empty())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" "),
+    };
+    if !formatted.is_empty() {
+        push("FN", &[], &formatted)?;
+    }
+    if !given.is_empty() || !family.is_empty() {
+        push("N", &[], &format!("{};{};;;", family, given))?;
+    }
+    let nickname = escaped("mozillaNickname");
+    if !nickname.is_empty() {
+        push("NICKNAME", &[], &nickname)?;
+    }
+    let (org, unit) = (escaped("o"), escaped("ou"));
+    if !org.is_empty() || !unit.is_empty() {
+        let value = if unit.is_empty() {
+            org
+        } else {
+            format!("{};{}", org, unit)
+        };
+        push("ORG", &[], &value)?;
+    }
+    let title = escaped("title");
+    if !title.is_empty() {
+        push("TITLE", &[], &title)?;
+    }
+    for (index, mail) in text("mail").chain(text("mozillaSecondEmail")).enumerate() {
+        let params = if index == 0 {
+            vec![("PREF".to_string(), "1".to_string())]
+        } else {
+            vec![]
+        };
+        push("EMAIL", &params, &escape_text(&mail))?;
+    }
+    for (name, ty) in PHONES {
+        for number in text(name) {
+            push(
+                "TEL",
+                &[("TYPE".into(), ty.to_string())],
+                &escape_text(&number),
+            )?;
+        }
+    }
+    let postal = first("postalAddress");
+    for (ty, names) in &[("home", HOME_ADDRESS), ("work", WORK_ADDRESS)] {
+        let mut parts = vec![String::new(); 7];
+        for (index, name) in names.iter() {
+            parts[*index] = escaped(name);
+        }
+        let mut params = vec![("TYPE".to_string(), ty.to_string())];
+        if *ty == "work" && !postal.is_empty() {
+            let label = escape_text(&postal.replace('$', "\n"));
+            params.push(("LABEL".into(), format!("\"{}\"", label)));
+        }
+        if parts.iter().any(|x| !x.is_empty()) || params.len() > 1 {
+            push("ADR", &params, &parts.join(";"))?;
+        }
+    }
+    for (name, ty) in &[
+        ("mozillaHomeUrl", "home"),
+        ("workURL", "work"),
+        ("mozillaWorkUrl", "work"),
+    ] {
+        for url in text(name).filter(|x| !x.is_empty()) {
+            push("URL", &[("TYPE".into(), ty.to_string())], &url)?;
+        }
+    }
+    let (year, month, day) = (first("birthyear"), first("birthmonth"), first("birthday"));
+    let birthday = match (year.is_empty(), month.is_empty(), day.is_empty()) {
+        (_, true, _) => year,
+        (true, false, true) => format!("--{:0>2}", month),
+        (false, false, true) => format!("{}-{:0>2}", year, month),
+        (true, false, false) => format!("--{:0>2}{:0>2}", month, day),
+        (false, false, false) => format!("{}{:0>2}{:0>2}", year, month, day),
+    };
+    if !birthday.is_empty() {
+        push("BDAY", &[], &birthday)?;
+    }
+    let note = escaped("description");
+    if !note.is_empty() {
+        push("NOTE", &[], &note)?;
+    }
+    if let Some((_, photo)) = attributes.iter().find(|(name, _)| name == "jpegphoto") {
+        let uri = format!("data:image/jpeg;base64,{}", STANDARD.encode(photo));
+        push("PHOTO", &[], &uri)?;
+    }
+    let rev = first("modifytimestamp");
+    if rev.len() >= 14 && rev.is_ascii() {
+        push("REV", &[], &format!("{}T{}", &rev[..8], &rev[8..]))?;
+    }
+    Ok(VCard(properties))
+}
@@ -0,0 +1,393 @@
+//! DoCoMo `MECARD:` and `BIZCARD:` reading and writing.
+//!
+//! Both are compact single line formats found in QR codes and NFC tags, for
+//! example `MECARD:N:Gump,Forrest;TEL:+1-111-555-1212;;`. [`encode`] picks the
+//! richest representation of a vcard that fits a size budget, dropping the
+//! least important properties if nothing fits whole.
+
+use super::common::{build::build_property, fit::drop_to_fit, text::plain_text};
+use super::FormatError;
+use crate::parse::{escape_text, split_unescaped, unescape_text};
+use crate::types::TelephoneType;
+use crate::{Property, VCard, Value};
+
+/// A representation chosen by [`encode`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Representation {
+    /// The vcard text itself.
+    VCard,
+    MeCard,
+    BizCard,
+}
+
+/// The result of [`encode`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Encoded {
+    pub representation: Representation,
+    pub text: String,
+    /// Names of the properties left out to fit the budget.
+    pub dropped: Vec<String>,
+}
+
+/// Encode a vcard in at most `budget` bytes, trying the vcard text, then
+/// `MECARD`, then `BIZCARD` and at last `MECARD` without its least
+/// important properties. Returns `None` if not even the name fits.
+pub fn encode(vcard: &VCard, budget: usize) -> Option<Encoded> {
+    let full = |representation, text: String| {
+        Some(Encoded {
+            representation,
+            text,
+            dropped: vec![],
+        })
+        .filter(|x| x.text.len() <= budget)
+    };
+    full(Representation::VCard, vcard.to_string())
+        .or_else(|| full(Representation::MeCard, to_string(vcard)))
+        .or_else(|| full(Representation::BizCard, bizcard_to_string(vcard)))
+        .or_else(|| {
+            let fits = |fields: &[Field]| render("MECARD:", fields).len() <= budget;
+            let (fields, dropped) =
+                drop_to_fit(mecard_fields(vcard), |x| x.priority, |_| None, fits)?;
+            Some(Encoded {
+                representation: Representation::MeCard,
+                text: render("MECARD:", &fields),
+                dropped: dropped.iter().map(|x| x.property.to_string()).collect(),
+            })
+        })
+}
+
+/// Convert a vcard into a `MECARD`.
+pub fn to_string(vcard: &VCard) -> String {
+    render("MECARD:", &mecard_fields(vcard))
+}
+
+/// Convert a vcard into a `BIZCARD`.
+pub fn bizcard_to_string(vcard: &VCard) -> String {
+    render("BIZCARD:", &bizcard_fields(vcard))
+}
+
+/// Read a `MECARD` or a `BIZCARD`.
+pub fn from_str(input: &str) -> Result<VCard<'static>, FormatError> {
+    let input = input.trim();
+    let prefix = |prefix: &str| {
+        input
+            .get(..prefix.len())
+            .filter(|x| x.eq_ignore_ascii_case(prefix))
+            .map(|_| &input[prefix.len()..])
+    };
+    if let Some(body) = prefix("MECARD:") {
+        read_mecard(&fields(body)?)
+    } else if let Some(body) = prefix("BIZCARD:") {
+        read_bizcard(&fields(body)?)
+    } else {
+        Err(FormatError::Syntax("expected MECARD: or BIZCARD:".into()))
+    }
+}
+
+/// A field of the output and how important the property it comes from is,
+/// `0` is never dropped.
+struct Field {
+    key: &'static str,
+    value: String,
+    property: &'static str,
+    priority: u8,
+}
+
+fn escape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for x in text.chars() {
+        if "\\;:,".contains(x) {
+            output.push('\\');
+        }
+        output.push(x);
+    }
+    output
+}
+
+fn unescape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(x) = chars.next() {
+        match x {
+            '\\' => output.extend(chars.next()),
+            _ => output.push(x),
+        }
+    }
+    output
+}
+
+fn render(prefix: &str, fields: &[Field]) -> String {
+    let mut output = prefix.to_string();
+    for field in fields {
+        output.push_str(field.key);
+        output.push(':');
+        output.push_str(&field.value);
+        output.push(';');
+    }
+    output.push(';');
+    output
+}
+
+/// Unescaped components of a structured value.
+fn components(value: &Value) -> Vec<String> {
+    split_unescaped(&value.to_string(), ';')
+        .into_iter()
+        .map(unescape_text)
+        .collect()
+}
+
+fn has_type(property: &Property, ty: TelephoneType) -> bool {
+    property.params.telephone_types().contains(&ty)
+}
+
+/// The family and given names, from `N` or else by splitting `FN`.
+fn names(vcard: &VCard) -> (String, String) {
+    let n = vcard.iter().find(|x| matches!(x.value, Value::N(_)));
+    if let Some(property) = n {
+        let parts = components(&property.value);
+        let part = |index: usize| parts.get(index).cloned().unwrap_or_default();
+        return (part(0), part(1));
+    }
+    let formatted = vcard.iter().find(|x| matches!(x.value, Value::Fn(_)));
+    let formatted = formatted
+        .map(|x| unescape_text(&x.value.to_string()))
+        .unwrap_or_default();
+    match formatted.rsplit_once(' ') {
+        Some((given, family)) => (family.to_string(), given.to_string()),
+        None => (formatted, String::new()),
+    }
+}
+
+fn mecard_fields(vcard: &VCard) -> Vec<Field> {
+    let mut fields = vec![];
+    let (family, given) = names(vcard);
+    let name = if given.is_empty() {
+        escape(&family)
+    } else {
+        format!("{},{}", escape(&family), escape(&given))
+    };
+    fields.push(Field {
+        key: "N",
+        value: name,
+        property: "N",
+        priority: 0,
+    });
+    let (mut tels, mut emails) = (0, 0);
+    for property in vcard.iter() {
+        let value = &property.value;
+        let text = escape(&plain_text(value));
+        let (key, property, priority) = match value {
+            Value::Tel(_) => {
+                tels += 1;
+                let key = if has_type(property, TelephoneType::Video) {
+                    "TEL-AV"
+                } else {
+                    "TEL"
+                };
+                (key, "TEL", if tels == 1 { 1 } else { 4 })
+            }
+            Value::Email(_) => {
+                emails += 1;
+                ("EMAIL", "EMAIL", if emails == 1 { 2 } else { 5 })
+            }
+            Value::Organization(_) => ("ORG", "ORG", 3),
+            Value::Url(_) => ("URL", "URL", 6),
+            Value::Adr(_) => {
+                let parts = components(value)
+                    .iter()
+                    .map(|x| escape(x))
+                    .collect::<Vec<_>>();
+                fields.push(Field {
+                    key: "ADR",
+                    value: parts.join(","),
+                    property: "ADR",
+                    priority: 7,
+                });
+                continue;
+            }
+            Value::Nickname(_) => ("NICKNAME", "NICKNAME", 8),
+            Value::Birthday(_) => {
+                let date = value.to_string().replace('-', "");
+                if date.len() != 8 || !date.chars().all(|x| x.is_ascii_digit()) {
+                    continue;
+                }
+                fields.push(Field {
+                    key: "BDAY",
+                    value: date,
+                    property: "BDAY",
+                    priority: 8,
+                });
+                continue;
+            }
+            Value::Note(_) => ("NOTE", "NOTE", 9),
+            _ => continue,
+        };
+        fields.push(Field {
+            key,
+            value: text,
+            property,
+            priority,
+        });
+    }
+    fields
+}
+
+fn bizcard_fields(vcard: &VCard) -> Vec<Field> {
+    let mut fields = vec![];
+    let (family, given) = names(vcard);
+    for (key, name) in &[("N", given), ("X", family)] {
+        if !name.is_empty() {
+            fields.push(Field {
+                key,
+                value: escape(name),
+                property: "N",
+                priority: 0,
+            });
+        }
+    }
+    for property in vcard.iter() {
+        let value = &property.value;
+        let text = escape(&plain_text(value));
+        let (key, name, text) = match value {
+            Value::Title(_) => ("T", "TITLE", text),
+            Value::Organization(_) => {
+                let name = components(value).into_iter().next().unwrap_or_default();
+                ("C", "ORG", escape(&name))
+            }
+            Value::Adr(_) => {
+                let parts = components(value);
+                let parts = parts.iter().filter(|x| !x.is_empty()).cloned();
+                ("A", "ADR", escape(&parts.collect::<Vec<_>>().join(", ")))
+            }
+            Value::Tel(_) if has_type(property, TelephoneType::Fax) => ("F", "TEL", text),
+            Value::Tel(_) if has_type(property, TelephoneType::Cell) => ("M", "TEL", text),
+            Value::Tel(_) => ("B", "TEL", text),
+            Value::Email(_) => ("E", "EMAIL", text),
+            _ => continue,
+        };
+        fields.push(Field {
+            key,
+            value: text,
+            property: name,
+            priority: 1,
+        });
+    }
+    fields
+}
+
+/// Split the body into keys, uppercase, and escaped values, stopping at the
+/// empty field that ends it.
+fn fields(body: &str) -> Result<Vec<(String, &str)>, FormatError> {
+    let mut output = vec![];
+    for field in split_unescaped(body, ';') {
+        if field.is_empty() {
+            break;
+        }
+        let mut parts = split_unescaped(field, ':').into_iter();
+        let key = parts.next().unwrap_or("");
+        let value = field
+            .get(key.len() + 1..)
+            .ok_or_else(|| FormatError::Syntax(format!("missing ':' in \"{}\"", field)))?;
+        output.push((key.trim().to_uppercase(), value));
+    }
+    Ok(output)
+}
+
+fn read_mecard(fields: &[(String, &str)]) -> Result<VCard<'static>, FormatError> {
+    let mut properties = vec![Property::from(Value::Version("4.0".into()))];
+    let mut push = |name: &str, params: &[(String, String)], value: &str| {
+        properties.push(build_property(name, params, value)?);
+        Ok::<_, FormatError>(())
+    };
+    for (key, value) in fields {
+        let text = escape_text(&unescape(value));
+        match key.as_str() {
+            "N" => {
+                let parts = split_unescaped(value, ',')
+                    .into_iter()
+                    .map(unescape)
+                    .collect::<Vec<_>>();
+                let family = parts.first().cloned().unwrap_or_default();
+                let given = parts.get(1).cloned().unwrap_or_default();
+                let formatted = [given.as_str(), family.as_str()]
+                    .iter()
+                    .filter(|x| !x.is_empty())
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                push("FN", &[], &escape_text(&formatted))?;
+                let name = format!("{};{};;;", escape_text(&family), escape_text(&given));
+                push("N", &[], &name)?;
+            }
+            "TEL" => push("TEL", &[], &text)?,
+            "TEL-AV" => push("TEL", &[("TYPE".into(), "video".into())], &text)?,
+            "EMAIL" => push("EMAIL", &[], &text)?,
+            "ORG" => push("ORG", &[], &text)?,
+            "URL" => push("URL", &[], &unescape(value))?,
+            "NICKNAME" => push("NICKNAME", &[], &text)?,
+            "BDAY" => push("BDAY", &[], &unescape(value))?,
+            "NOTE" => push("NOTE", &[], &text)?,
+            "ADR" => {
+                let parts = split_unescaped(value, ',');
+                let value = if parts.len() == 7 {
+                    parts
+                        .into_iter()
+                        .map(|x| escape_text(&unescape(x)))
+                        .collect::<Vec<_>>()
+                        .join(";")
+                } else {
+                    format!(";;{};;;;", text)
+                };
+                push("ADR", &[], &value)?;
+            }
+            _ => {}
+        }
+    }
+    Ok(VCard(properties))
+}
+
+fn read_bizcard(fields: &[(String, &str)]) -> Result<VCard<'static>, FormatError> {
+    let mut properties = vec![Property::from(Value::Version("4.0".into()))];
+    let mut push = |name: &str, params: &[(String, String)], value: &str| {
+        properties.push(build_property(name, params, value)?);
+        Ok::<_, FormatError>(())
+    };
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| unescape(value))
+            .unwrap_or_default()
+    };
+    let (given, family) = (field("N"), field("X"));
+    let formatted = [given.as_str(), family.as_str()]
+        .iter()
+        .filter(|x| !x.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if !formatted.is_empty() {
+        push("FN", &[], &escape_text(&formatted))?;
+        let name = format!("{};{};;;", escape_text(&family), escape_text(&given));
+        push("N", &[], &name)?;
+    }
+    for (key, value) in fields {
+        let text = escape_text(&unescape(value));
+        let ty = |ty: &str| vec![("TYPE".to_string(), ty.to_string())];
+        match key.as_str() {
+            "T" => push("TITLE", &[], &text)?,
+            "C" => push("ORG", &[], &text)?,
+            "A" => push(
+                "ADR",
+                &[("LABEL".into(), format!("\"{}\"", text))],
+                ";;;;;;",
+            )?,
+            "B" => push("TEL", &ty("work"), &text)?,
+            "M" => push("TEL", &ty("cell"), &text)?,
+            "F" => push("TEL", &ty("fax"), &text)?,
+            "E" => push("EMAIL", &[], &text)?,
+            _ => {}
+        }
+    }
+    Ok(VCard(properties))
+}
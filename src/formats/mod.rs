@@ -0,0 +1,107 @@
+//! Conversions between vcards and other contact formats.
+//!
+//! Each format lives behind a crate feature of the same name.
+
+use std::error::Error;
+use std::fmt;
+
+/// Helpers shared by the formats, each compiled with the formats using it.
+mod common {
+    #[cfg(any(
+        feature = "jcard",
+        feature = "xcard",
+        feature = "csv",
+        feature = "ldif",
+        feature = "hcard",
+        feature = "jsonld",
+        feature = "mecard"
+    ))]
+    pub(crate) mod build;
+
+    #[cfg(any(
+        feature = "jscontact",
+        feature = "csv",
+        feature = "ldif",
+        feature = "hcard",
+        feature = "jsonld",
+        feature = "mecard"
+    ))]
+    pub(crate) mod text;
+
+    #[cfg(any(feature = "jcard", feature = "xcard"))]
+    pub(crate) mod parameters;
+
+    #[cfg(any(feature = "jcard", feature = "hcard", feature = "jsonld"))]
+    pub(crate) mod dates;
+
+    #[cfg(any(feature = "qr", feature = "ndef", feature = "mecard"))]
+    pub(crate) mod fit;
+
+    #[cfg(any(feature = "qr", feature = "ndef"))]
+    pub(crate) mod trim;
+
+    #[cfg(any(feature = "geojson", feature = "kml"))]
+    pub(crate) mod places;
+}
+
+#[cfg(feature = "jcard")]
+pub mod jcard;
+
+#[cfg(feature = "xcard")]
+pub mod xcard;
+
+#[cfg(feature = "jscontact")]
+pub mod jscontact;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "ldif")]
+pub mod ldif;
+
+#[cfg(feature = "hcard")]
+pub mod hcard;
+
+#[cfg(feature = "jsonld")]
+pub mod jsonld;
+
+#[cfg(feature = "mecard")]
+pub mod mecard;
+
+#[cfg(feature = "qr")]
+pub mod qr;
+
+#[cfg(feature = "ndef")]
+pub mod ndef;
+
+#[cfg(feature = "icalendar")]
+pub mod icalendar;
+
+#[cfg(feature = "geojson")]
+pub mod geojson;
+
+#[cfg(feature = "kml")]
+pub mod kml;
+
+/// Error returned when reading a vcard from another format fails.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FormatError {
+    /// The input is not valid for the format being read.
+    Syntax(String),
+    /// A property built from the input could not be parsed as vcard.
+    Property(String),
+    /// The vcard does not fit in the space the format has.
+    TooLarge,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatError::Syntax(msg) => write!(f, "Syntax error: {}", msg),
+            FormatError::Property(msg) => write!(f, "Invalid property: {}", msg),
+            FormatError::TooLarge => write!(f, "The vcard does not fit"),
+        }
+    }
+}
+
+impl Error for FormatError {}
@@ -0,0 +1,177 @@
+//! NFC Data Exchange Format messages holding vcards.
+//!
+//! A vcard is written as a single MIME media record of type `text/vcard`.
+//! Reading accepts any message, collecting the vcards of its `text/vcard`,
+//! `text/x-vcard` and `text/directory` records and joining chunked ones.
+
+use super::common::trim::trim_to_fit;
+use super::FormatError;
+use crate::{IntoOwned, Parse, VCard, Vcf};
+
+/// The MIME type of written records.
+pub const MIME_TYPE: &str = "text/vcard";
+
+/// Largest message that fits on an NTAG213 tag, after the TLV framing.
+pub const NTAG213: usize = 141;
+/// Largest message that fits on an NTAG215 tag, after the TLV framing.
+pub const NTAG215: usize = 499;
+/// Largest message that fits on an NTAG216 tag, after the TLV framing.
+pub const NTAG216: usize = 883;
+
+const MESSAGE_BEGIN: u8 = 0x80;
+const MESSAGE_END: u8 = 0x40;
+const CHUNK: u8 = 0x20;
+const SHORT_RECORD: u8 = 0x10;
+const ID_LENGTH: u8 = 0x08;
+const TNF: u8 = 0x07;
+
+const TNF_MIME_MEDIA: u8 = 0x02;
+const TNF_UNCHANGED: u8 = 0x06;
+
+/// The result of [`encode`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Encoded {
+    /// The NDEF message.
+    pub message: Vec<u8>,
+    /// Names of the properties left out to fit the budget.
+    pub dropped: Vec<String>,
+}
+
+/// Write a vcard as an NDEF message of one `text/vcard` record.
+pub fn to_bytes(vcard: &VCard) -> Vec<u8> {
+    record(format!("{}\r\n", vcard).as_bytes())
+}
+
+/// Write a vcard as an NDEF message of at most `budget` bytes, scaling down
+/// embedded images and leaving out its least important properties if the
+/// whole card does not fit. Fails if it does not fit with only its name left.
+pub fn encode(vcard: &VCard, budget: usize) -> Result<Encoded, FormatError> {
+    let (vcard, dropped) =
+        trim_to_fit(vcard, |vcard| to_bytes(vcard).len() <= budget).ok_or(FormatError::TooLarge)?;
+    Ok(Encoded {
+        message: to_bytes(&vcard),
+        dropped,
+    })
+}
+
+/// Read the vcards of an NDEF message.
+pub fn from_bytes(input: &[u8]) -> Result<Vcf<'static>, FormatError> {
+    let mut vcards = vec![];
+    let mut chunks: Option<(bool, Vec<u8>)> = None;
+    let mut rest = input;
+    let mut end = false;
+    while !end {
+        let (
+            Record {
+                header,
+                ty,
+                payload,
+            },
+            remains,
+        ) = read_record(rest)?;
+        rest = remains;
+        end = header & MESSAGE_END != 0;
+        let (vcard, payload) = match chunks.take() {
+            Some((vcard, mut data)) => {
+                if header & TNF != TNF_UNCHANGED || !ty.is_empty() {
+                    return Err(FormatError::Syntax("unfinished chunked record".into()));
+                }
+                data.extend_from_slice(payload);
+                (vcard, data)
+            }
+            None => {
+                let is_vcard = header & TNF == TNF_MIME_MEDIA
+                    && std::str::from_utf8(ty).map(is_vcard_type) == Ok(true);
+                (is_vcard, payload.to_vec())
+            }
+        };
+        if header & CHUNK != 0 {
+            chunks = Some((vcard, payload));
+        } else if vcard {
+            let text = String::from_utf8(payload)
+                .map_err(|_| FormatError::Syntax("vcard is not UTF-8".into()))?;
+            // The parser wants bare line feeds and a final line ending.
+            let mut text = text.replace("\r\n", "\n");
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+            let (_, vcf) = Vcf::parse(&text)
+                .map_err(|_| FormatError::Syntax("record does not hold a vcard".into()))?;
+            vcards.extend(vcf.into_owned().0);
+        }
+    }
+    if chunks.is_some() {
+        return Err(FormatError::Syntax("unfinished chunked record".into()));
+    }
+    Ok(Vcf(vcards))
+}
+
+fn is_vcard_type(ty: &str) -> bool {
+    ["text/vcard", "text/x-vcard", "text/directory"]
+        .iter()
+        .any(|x| ty.eq_ignore_ascii_case(x))
+}
+
+/// A message of one MIME media record.
+fn record(payload: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(payload.len() + MIME_TYPE.len() + 6);
+    let mut header = MESSAGE_BEGIN | MESSAGE_END | TNF_MIME_MEDIA;
+    if payload.len() < 256 {
+        header |= SHORT_RECORD;
+    }
+    output.push(header);
+    output.push(MIME_TYPE.len() as u8);
+    if payload.len() < 256 {
+        output.push(payload.len() as u8);
+    } else {
+        output.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    }
+    output.extend_from_slice(MIME_TYPE.as_bytes());
+    output.extend_from_slice(payload);
+    output
+}
+
+struct Record<'a> {
+    header: u8,
+    ty: &'a [u8],
+    payload: &'a [u8],
+}
+
+/// Split the first record off a message, returning it and the rest of the
+/// message.
+fn read_record(input: &[u8]) -> Result<(Record<'_>, &[u8]), FormatError> {
+    let truncated = || FormatError::Syntax("truncated record".into());
+    let mut rest = input;
+    let mut take = |count: usize| {
+        if rest.len() < count {
+            return Err(truncated());
+        }
+        let (taken, remains) = rest.split_at(count);
+        rest = remains;
+        Ok(taken)
+    };
+    let header = take(1)?[0];
+    let type_length = take(1)?[0] as usize;
+    let payload_length = if header & SHORT_RECORD != 0 {
+        take(1)?[0] as usize
+    } else {
+        let bytes = take(4)?;
+        u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize
+    };
+    let id_length = if header & ID_LENGTH != 0 {
+        take(1)?[0] as usize
+    } else {
+        0
+    };
+    let ty = take(type_length)?;
+    take(id_length)?;
+    let payload = take(payload_length)?;
+    Ok((
+        Record {
+            header,
+            ty,
+            payload,
+        },
+        rest,
+    ))
+}
@@ -0,0 +1,101 @@
+//! QR codes holding a vcard.
+//!
+//! [`encode`] puts the vcard text in a QR code with the requested error
+//! correction level. Cards too large for the code lose their least important
+//! properties first, embedded `PHOTO`, `LOGO`, `SOUND` and `KEY` data before
+//! anything else, and the names of those left out are reported. Embedded
+//! `PHOTO` and `LOGO` images are scaled down as far as they usefully go
+//! before they are left out.
+
+use qrcode::render::{svg, unicode};
+use qrcode::{Color, QrCode};
+
+use super::common::trim::trim_to_fit;
+use super::FormatError;
+use crate::VCard;
+
+pub use qrcode::EcLevel;
+
+/// Width of the light border around the code, in modules.
+const QUIET_ZONE: u32 = 4;
+
+/// A vcard encoded as a QR code.
+pub struct Encoded {
+    code: QrCode,
+    /// The vcard text held by the code.
+    pub text: String,
+    /// Names of the properties left out to fit the code.
+    pub dropped: Vec<String>,
+}
+
+/// Encode a vcard as a QR code with the given error correction level.
+/// Fails if the vcard does not fit with only its name left.
+pub fn encode(vcard: &VCard, level: EcLevel) -> Result<Encoded, FormatError> {
+    let mut code = None;
+    let (vcard, dropped) = trim_to_fit(vcard, |vcard| {
+        code = QrCode::with_error_correction_level(vcard.to_string(), level).ok();
+        code.is_some()
+    })
+    .ok_or(FormatError::TooLarge)?;
+    Ok(Encoded {
+        code: code.ok_or(FormatError::TooLarge)?,
+        text: vcard.to_string(),
+        dropped,
+    })
+}
+
+impl Encoded {
+    /// Number of modules along each side, without the quiet zone.
+    pub fn width(&self) -> usize {
+        self.code.width()
+    }
+
+    /// Render as an SVG image, each module `module_size` pixels wide.
+    pub fn to_svg(&self, module_size: u32) -> String {
+        self.code
+            .render::<svg::Color>()
+            .module_dimensions(module_size, module_size)
+            .build()
+    }
+
+    /// Render as a grayscale PNG image, each module `module_size` pixels
+    /// wide.
+    pub fn to_png(&self, module_size: u32) -> Vec<u8> {
+        let modules = self.code.width() as u32;
+        let size = (modules + 2 * QUIET_ZONE) * module_size;
+        let colors = self.code.to_colors();
+        let mut pixels = Vec::with_capacity((size * size) as usize);
+        for y in 0..size {
+            for x in 0..size {
+                let module = |v: u32| (v / module_size).checked_sub(QUIET_ZONE);
+                let dark = match (module(x), module(y)) {
+                    (Some(x), Some(y)) if x < modules && y < modules => {
+                        colors[(y * modules + x) as usize] == Color::Dark
+                    }
+                    _ => false,
+                };
+                pixels.push(if dark { 0 } else { 255 });
+            }
+        }
+        let mut output = vec![];
+        let mut encoder = png::Encoder::new(&mut output, size, size);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&pixels))
+            .expect("writing to a vector does not fail");
+        output
+    }
+
+    /// Render with Unicode block characters for a terminal, two rows of
+    /// modules per line. Dark modules are drawn as spaces so the code reads
+    /// on a dark background.
+    pub fn to_terminal(&self) -> String {
+        self.code
+            .render::<unicode::Dense1x2>()
+            .dark_color(unicode::Dense1x2::Light)
+            .light_color(unicode::Dense1x2::Dark)
+            .build()
+    }
+}
@@ -0,0 +1,385 @@
+//! [xCard](https://tools.ietf.org/html/rfc6351) reading and writing.
+//!
+//! Properties are written as elements of the `urn:ietf:params:xml:ns:vcard-4.0`
+//! namespace, grouped properties are wrapped in `<group name="...">` and
+//! elements from any other namespace are kept as `XML` properties.
+
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::ResolveResult;
+use quick_xml::NsReader;
+
+use super::common::{
+    build::build_property,
+    parameters::{default_value_type, quote_parameter_value, unquote_parameter_value},
+};
+use super::FormatError;
+use crate::parse::{escape_text, split_unescaped, unescape_text};
+use crate::{Property, VCard, Value, Vcf};
+
+/// Namespace of the xCard elements.
+pub const NAMESPACE: &str = "urn:ietf:params:xml:ns:vcard-4.0";
+
+/// Convert a vcard into an xCard document.
+pub fn to_string(vcard: &VCard) -> String {
+    document(std::slice::from_ref(vcard))
+}
+
+/// Convert every vcard into a single xCard document.
+pub fn vcf_to_string(vcf: &Vcf) -> String {
+    document(&vcf.0)
+}
+
+/// Read the first vcard of an xCard document.
+pub fn from_str(input: &str) -> Result<VCard<'static>, FormatError> {
+    vcf_from_str(input)?
+        .0
+        .into_iter()
+        .next()
+        .ok_or_else(|| FormatError::Syntax("no <vcard> element found".into()))
+}
+
+/// Read every vcard of an xCard document.
+pub fn vcf_from_str(input: &str) -> Result<Vcf<'static>, FormatError> {
+    let root = parse_document(input)?;
+    if !root.is_vcard("vcards") {
+        return Err(FormatError::Syntax("expected a <vcards> root element".into()));
+    }
+    root.children
+        .iter()
+        .filter(|x| x.is_vcard("vcard"))
+        .map(read_vcard)
+        .collect::<Result<Vec<_>, _>>()
+        .map(Vcf)
+}
+
+/// Names of the components of structured properties, in order.
+fn components(name: &str) -> Option<&'static [&'static str]> {
+    match name {
+        "n" => Some(&["surname", "given", "additional", "prefix", "suffix"]),
+        "adr" => Some(&["pobox", "ext", "street", "locality", "region", "code", "country"]),
+        "gender" => Some(&["sex", "identity"]),
+        "clientpidmap" => Some(&["sourceid", "uri"]),
+        _ => None,
+    }
+}
+
+fn parameter_type(name: &str) -> &'static str {
+    match name {
+        "pref" => "integer",
+        "geo" => "uri",
+        "language" => "language-tag",
+        _ => "text",
+    }
+}
+
+fn document(vcards: &[VCard]) -> String {
+    let mut output = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<vcards xmlns=\"{}\">",
+        NAMESPACE
+    );
+    for vcard in vcards {
+        write_vcard(&mut output, vcard);
+    }
+    output.push_str("</vcards>\n");
+    output
+}
+
+fn write_vcard(output: &mut String, vcard: &VCard) {
+    output.push_str("<vcard>");
+    let mut group: Option<&str> = None;
+    for property in vcard.iter() {
+        if matches!(property.value, Value::Begin | Value::End | Value::Version(_)) {
+            continue;
+        }
+        let next = property.group.as_deref();
+        if next != group {
+            if group.is_some() {
+                output.push_str("</group>");
+            }
+            if let Some(name) = next {
+                output.push_str(&format!("<group name=\"{}\">", escape(name)));
+            }
+            group = next;
+        }
+        write_property(output, property);
+    }
+    if group.is_some() {
+        output.push_str("</group>");
+    }
+    output.push_str("</vcard>");
+}
+
+fn write_property(output: &mut String, property: &Property) {
+    if let Value::Xml(xml) = &property.value {
+        output.push_str(&unescape_text(xml));
+        return;
+    }
+    let name = property.value.name_raw().to_lowercase();
+    let mut ty = default_value_type(&name).to_string();
+    let mut params = String::new();
+    for param in &property.params.0 {
+        let key = param.name.to_lowercase();
+        let value = param.value.as_deref().unwrap_or("");
+        if key == "value" {
+            ty = value.to_lowercase();
+            continue;
+        }
+        let unquoted = unquote_parameter_value(value);
+        let items = if unquoted.len() != value.len() {
+            vec![unquoted]
+        } else {
+            value.split(',').collect()
+        };
+        params.push_str(&format!("<{}>", key));
+        for item in items {
+            write_element(&mut params, parameter_type(&key), item);
+        }
+        params.push_str(&format!("</{}>", key));
+    }
+    output.push_str(&format!("<{}>", name));
+    if !params.is_empty() {
+        output.push_str(&format!("<parameters>{}</parameters>", params));
+    }
+    match &property.value {
+        Value::Nickname(items) | Value::Categories(items) => {
+            for item in items.iter() {
+                write_element(output, &ty, &unescape_text(item));
+            }
+        }
+        value if name == "org" => {
+            for component in split_unescaped(&value.to_string(), ';') {
+                write_element(output, "text", &unescape_text(component));
+            }
+        }
+        value => match components(&name) {
+            Some(names) => {
+                let raw = value.to_string();
+                for (name, component) in names.iter().zip(split_unescaped(&raw, ';')) {
+                    let items = split_unescaped(component, ',');
+                    if items.iter().all(|x| x.is_empty()) {
+                        output.push_str(&format!("<{}/>", name));
+                    }
+                    for item in items.into_iter().filter(|x| !x.is_empty()) {
+                        write_element(output, name, &unescape_text(item));
+                    }
+                }
+            }
+            None if ty == "text" => write_element(output, &ty, &unescape_text(&value.to_string())),
+            None => write_element(output, &ty, &value.to_string()),
+        },
+    }
+    output.push_str(&format!("</{}>", name));
+}
+
+fn write_element(output: &mut String, name: &str, text: &str) {
+    output.push_str(&format!("<{}>{}</{}>", name, escape(text), name));
+}
+
+fn read_vcard(element: &Element) -> Result<VCard<'static>, FormatError> {
+    let mut properties = vec![];
+    for child in &element.children {
+        read_property(child, None, &mut properties)?;
+    }
+    if !properties.iter().any(|x| matches!(x.value, Value::Version(_))) {
+        properties.insert(0, Property::from(Value::Version("4.0".into())));
+    }
+    Ok(VCard(properties))
+}
+
+fn read_property(
+    element: &Element,
+    group: Option<&str>,
+    properties: &mut Vec<Property<'static>>,
+) -> Result<(), FormatError> {
+    if element.namespace.as_deref() != Some(NAMESPACE) {
+        properties.push(build_property("XML", &[], &escape_text(&element.raw))?);
+        return Ok(());
+    }
+    if element.name == "group" {
+        let name = element.attribute("name");
+        for child in &element.children {
+            read_property(child, name, properties)?;
+        }
+        return Ok(());
+    }
+    let lowercase = element.name.as_str();
+    let mut params = vec![];
+    let mut values = vec![];
+    for child in &element.children {
+        if child.name != "parameters" {
+            values.push(child);
+            continue;
+        }
+        for param in &child.children {
+            let value = param
+                .children
+                .iter()
+                .map(|x| quote_parameter_value(&x.text))
+                .collect::<Vec<_>>()
+                .join(",");
+            params.push((param.name.to_uppercase(), value));
+        }
+    }
+    let value = if let Some(names) = components(lowercase) {
+        names
+            .iter()
+            .map(|name| {
+                values
+                    .iter()
+                    .filter(|x| x.name == *name)
+                    .map(|x| escape_text(&x.text))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>()
+            .join(";")
+    } else if lowercase == "org" {
+        values.iter().map(|x| escape_text(&x.text)).collect::<Vec<_>>().join(";")
+    } else {
+        let ty = values.first().map(|x| x.name.as_str()).unwrap_or("unknown");
+        if ty != "unknown" && ty != default_value_type(lowercase) {
+            params.push(("VALUE".into(), ty.into()));
+        }
+        values
+            .iter()
+            .map(|x| if x.name == "text" { escape_text(&x.text) } else { x.text.clone() })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    let name = match group {
+        Some(group) => format!("{}.{}", group, lowercase.to_uppercase()),
+        None => lowercase.to_uppercase(),
+    };
+    properties.push(build_property(&name, &params, &value)?);
+    Ok(())
+}
+
+/// The subset of an XML element needed to read xCard.
+struct Element {
+    namespace: Option<String>,
+    prefix: Option<String>,
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+    raw: String,
+}
+
+impl Element {
+    fn is_vcard(&self, name: &str) -> bool {
+        self.namespace.as_deref() == Some(NAMESPACE) && self.name == name
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn set_raw(&mut self, raw: &str) {
+        let raw = raw.trim();
+        self.raw = match (&self.namespace, raw.contains("xmlns")) {
+            (Some(namespace), false) => {
+                let declaration = match &self.prefix {
+                    Some(prefix) => format!(" xmlns:{}=\"{}\"", prefix, escape(namespace.as_str())),
+                    None => format!(" xmlns=\"{}\"", escape(namespace.as_str())),
+                };
+                let index = raw
+                    .find(|x: char| x.is_whitespace() || x == '>' || x == '/')
+                    .unwrap_or(raw.len());
+                format!("{}{}{}", &raw[..index], declaration, &raw[index..])
+            }
+            _ => raw.into(),
+        };
+    }
+}
+
+fn xml_error<E: std::fmt::Display>(err: E) -> FormatError {
+    FormatError::Syntax(err.to_string())
+}
+
+fn parse_document(input: &str) -> Result<Element, FormatError> {
+    let mut reader = NsReader::from_str(input);
+    reader.config_mut().trim_text(true);
+    let mut stack: Vec<(usize, Element)> = vec![];
+    loop {
+        let start = reader.buffer_position() as usize;
+        let (namespace, event) = reader.read_resolved_event().map_err(xml_error)?;
+        let namespace = match namespace {
+            ResolveResult::Bound(namespace) => {
+                Some(String::from_utf8_lossy(namespace.as_ref()).into_owned())
+            }
+            _ => None,
+        };
+        let end = reader.buffer_position() as usize;
+        let finished = match event {
+            Event::Start(start_tag) => {
+                stack.push((start, read_element(namespace, &start_tag)?));
+                None
+            }
+            Event::Empty(start_tag) => {
+                let mut element = read_element(namespace, &start_tag)?;
+                element.set_raw(&input[start..end]);
+                close(&mut stack, element)
+            }
+            Event::End(_) => {
+                let (start, mut element) = stack
+                    .pop()
+                    .ok_or_else(|| FormatError::Syntax("unexpected closing tag".into()))?;
+                element.set_raw(&input[start..end]);
+                close(&mut stack, element)
+            }
+            Event::Text(text) => {
+                if let Some((_, element)) = stack.last_mut() {
+                    element.text.push_str(&text.unescape().map_err(xml_error)?);
+                }
+                None
+            }
+            Event::CData(data) => {
+                if let Some((_, element)) = stack.last_mut() {
+                    element.text.push_str(&String::from_utf8_lossy(&data.into_inner()));
+                }
+                None
+            }
+            Event::Eof => return Err(FormatError::Syntax("unexpected end of document".into())),
+            _ => None,
+        };
+        if let Some(root) = finished {
+            return Ok(root);
+        }
+    }
+}
+
+fn read_element(namespace: Option<String>, tag: &BytesStart) -> Result<Element, FormatError> {
+    let name = tag.name();
+    let mut attributes = vec![];
+    for attribute in tag.attributes() {
+        let attribute = attribute.map_err(xml_error)?;
+        let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned();
+        attributes.push((key, attribute.unescape_value().map_err(xml_error)?.into_owned()));
+    }
+    Ok(Element {
+        namespace,
+        prefix: name
+            .prefix()
+            .map(|x| String::from_utf8_lossy(x.as_ref()).into_owned()),
+        name: String::from_utf8_lossy(name.local_name().as_ref()).into_owned(),
+        attributes,
+        children: vec![],
+        text: String::new(),
+        raw: String::new(),
+    })
+}
+
+/// Attach a finished element to its parent, returning it if it is the root.
+fn close(stack: &mut [(usize, Element)], element: Element) -> Option<Element> {
+    match stack.last_mut() {
+        Some((_, parent)) => {
+            parent.children.push(element);
+            None
+        }
+        None => Some(element),
+    }
+}
@@ -3,7 +3,7 @@
 //! rolodex represents vcard as a vector of properties.
 
 pub mod value;
-pub use self::value::{AtLeastOne, PropertyValue, TypeOrRaw, Value};
+pub use self::value::{AtLeastOne, IntoOwned, PropertyValue, TypeOrRaw, Value};
 
 pub mod types;
 
@@ -11,12 +11,30 @@ pub mod property;
 pub use self::property::{Parameter, Parameters, Property};
 
 mod vcard;
-pub use self::vcard::VCard;
+pub use self::vcard::{Revise, VCard};
 
 mod vcf;
 pub use self::vcf::Vcf;
 
+mod occasion;
+pub use self::occasion::{Occasion, OccasionKind, Upcoming};
+
+mod proximity;
+pub use self::proximity::Nearby;
+
+#[cfg(feature = "phone")]
+mod phone;
+#[cfg(feature = "phone")]
+pub use self::phone::PhoneNumber;
+
+#[cfg(feature = "directory")]
+mod directory;
+#[cfg(feature = "directory")]
+pub use self::directory::{Directory, Member, Unit};
+
 pub mod parse;
 pub use self::parse::{Parse, ParseError};
 
+pub mod formats;
+
 pub const DATE_FORMAT: &str = "%Y%m%d";
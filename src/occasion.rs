@@ -0,0 +1,119 @@
+use chrono::{Datelike, NaiveDate};
+
+use std::convert::TryFrom;
+
+use crate::types::DateAndOrTime;
+use crate::{Parse, Property, TypeOrRaw, VCard, Value, Vcf};
+
+/// Which property an [`Occasion`] comes from.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OccasionKind {
+    Birthday,
+    Anniversary,
+}
+
+/// The recurring date of a `BDAY` or `ANNIVERSARY` with a month and day.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Occasion {
+    pub kind: OccasionKind,
+    pub year: Option<i32>,
+    pub month: u32,
+    pub day: u32,
+}
+
+/// An occasion falling within the range asked for by [`Vcf::upcoming`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Upcoming<'v, 'a> {
+    pub vcard: &'v VCard<'a>,
+    pub occasion: Occasion,
+    /// The day it is celebrated.
+    pub date: NaiveDate,
+    /// Years since the original date, if it has a year.
+    pub age: Option<u32>,
+}
+
+impl Occasion {
+    /// Read the occasion of a `BDAY` or `ANNIVERSARY` property, `None` for
+    /// other properties and for dates without a month and day.
+    pub fn from_property(property: &Property) -> Option<Occasion> {
+        let (kind, value) = match &property.value {
+            Value::Birthday(value) => (OccasionKind::Birthday, value),
+            Value::Anniversary(value) => (OccasionKind::Anniversary, value),
+            _ => return None,
+        };
+        let value = match value {
+            TypeOrRaw::Type(value) => *value,
+            TypeOrRaw::Raw(raw) => match DateAndOrTime::parse(raw) {
+                Ok(("", value)) => value,
+                _ => return None,
+            },
+        };
+        let date = value.date()?;
+        Some(Occasion {
+            kind,
+            year: date.year,
+            month: date.month?,
+            day: date.day?,
+        })
+    }
+
+    /// The day the occasion is celebrated in `year`, February 29 moves to
+    /// February 28 in common years.
+    pub fn in_year(&self, year: i32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, self.month, self.day).or_else(|| {
+            Some((self.month, self.day))
+                .filter(|x| *x == (2, 29))
+                .and_then(|_| NaiveDate::from_ymd_opt(year, 2, 28))
+        })
+    }
+
+    /// The first day on or after `date` the occasion is celebrated.
+    pub fn next(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let year = date.year().max(self.year.unwrap_or(i32::MIN));
+        self.in_year(year)
+            .filter(|x| *x >= date)
+            .or_else(|| self.in_year(year + 1))
+    }
+
+    /// Whole years from the original date to `date`, `None` if it has no
+    /// year or `date` comes before it.
+    pub fn age(&self, date: NaiveDate) -> Option<u32> {
+        let mut age = date.year() - self.year?;
+        if date < self.in_year(date.year())? {
+            age -= 1;
+        }
+        u32::try_from(age).ok()
+    }
+}
+
+impl<'a> VCard<'a> {
+    /// The birthdays and anniversaries of the vcard.
+    pub fn occasions(&self) -> Vec<Occasion> {
+        self.iter().filter_map(Occasion::from_property).collect()
+    }
+}
+
+impl<'a> Vcf<'a> {
+    /// The birthdays and anniversaries celebrated from `from` to `days`
+    /// days after it inclusive, in date order.
+    pub fn upcoming(&self, from: NaiveDate, days: u32) -> Vec<Upcoming<'_, 'a>> {
+        let until = from + chrono::Duration::days(days.into());
+        let mut upcoming = vec![];
+        for vcard in &self.0 {
+            for occasion in vcard.occasions() {
+                if let Some(date) = occasion.next(from).filter(|x| *x <= until) {
+                    upcoming.push(Upcoming {
+                        vcard,
+                        occasion,
+                        date,
+                        age: occasion.age(date),
+                    });
+                }
+            }
+        }
+        upcoming.sort_by_key(|x| x.date);
+        upcoming
+    }
+}
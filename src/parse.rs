@@ -1,7 +1,7 @@
 use nom::{
     do_parse,
     error::{VerboseError, VerboseErrorKind},
-    named, separated_list1, tag, take_till1, take_while1, IResult,
+    named, take_till1, take_while1, IResult,
 };
 
 use std::borrow::Cow;
@@ -10,7 +10,7 @@ use std::fmt::Debug;
 use crate::{AtLeastOne, PropertyValue, TypeOrRaw};
 
 pub trait Parse<'a>: Sized {
-    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError>;
+    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError<'a>>;
 }
 
 /// This trait represents something the parser knows how to manage.
@@ -43,7 +43,7 @@ impl<'a> ParseError<'a> {
 }
 
 impl<'a> Parse<'a> for chrono::NaiveDate {
-    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError<'a>> {
         match chrono::NaiveDate::parse_from_str(input, crate::DATE_FORMAT) {
             Ok(date) => Ok(("", date)),
             Err(_) => {
@@ -57,7 +57,7 @@ impl<'a> Parse<'a> for chrono::NaiveDate {
 }
 
 impl<'a> Parse<'a> for std::borrow::Cow<'a, str> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError<'a>> {
         Ok(("", input.into()))
     }
 }
@@ -82,7 +82,7 @@ pub fn parse_typed_value<'de, T>(
 where
     T: Parsable<'de>,
 {
-    match T::parse(&input) {
+    match T::parse(input) {
         Ok((remains, item)) => Ok((remains, TypeOrRaw::Type(item))),
         Err(_) => parse_value(input).map(|(a, b)| (a, TypeOrRaw::Raw(b.to_string().into()))),
     }
@@ -91,50 +91,106 @@ where
 pub fn parse_multiple_value(
     input: &str,
 ) -> IResult<&str, AtLeastOne<Cow<'_, str>>, VerboseError<&str>> {
-    match parse_comma_seperated_value(input) {
-        Err(err) => Err(err),
-        Ok((remains, mut list)) => {
-            if list.len() == 1 {
-                Ok((remains, AtLeastOne::Single(list.remove(0))))
-            } else {
-                Ok((remains, AtLeastOne::Multiple(list)))
-            }
+    let (remains, value) = take_until_unescaped_newline(input)?;
+    let mut list = split_unescaped(value, ',');
+    if list.len() == 1 {
+        Ok((remains, AtLeastOne::Single(list.remove(0).into())))
+    } else {
+        Ok((remains, AtLeastOne::Multiple(list.into_iter().map(Cow::from).collect())))
+    }
+}
+
+/// Split a raw value on every occurrence of `separator` that is not escaped
+/// with a backslash.
+pub fn split_unescaped(input: &str, separator: char) -> Vec<&str> {
+    let mut items = vec![];
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, chr) in input.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if chr == '\\' {
+            escaped = true;
+        } else if chr == separator {
+            items.push(&input[start..index]);
+            start = index + chr.len_utf8();
         }
     }
+    items.push(&input[start..]);
+    items
 }
 
-named!(parse_comma_seperated_value<&str, Vec<Cow<str>>, VerboseError<&str>>, do_parse!(
-    data: separated_list1!(tag!(","), parse_until_comma) >>
-    (data)
-));
+/// Escape text so it can be written as a vcard value.
+pub fn escape_text(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for chr in input.chars() {
+        match chr {
+            '\\' => output.push_str("\\\\"),
+            ',' => output.push_str("\\,"),
+            ';' => output.push_str("\\;"),
+            '\n' => output.push_str("\\n"),
+            '\r' => {}
+            chr => output.push(chr),
+        }
+    }
+    output
+}
 
-fn parse_until_comma<'de>(input: &'de str) -> IResult<&'de str, Cow<str>, VerboseError<&'de str>> {
-    let (remains, data) = nom::bytes::complete::take_until(",")(input)?;
-    Ok((remains, data.into()))
+/// Resolve the backslash escapes of a raw vcard value.
+pub fn unescape_text(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(chr) = chars.next() {
+        if chr == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => output.push('\n'),
+                Some(chr) => output.push(chr),
+                None => output.push('\\'),
+            }
+        } else {
+            output.push(chr);
+        }
+    }
+    output
 }
 
-named!(pub(crate) parse_value<&str, Cow<str>, VerboseError<&str>>, do_parse!(
+/// Escape text for HTML or XML content and attribute values.
+#[cfg(any(feature = "hcard", feature = "kml", feature = "directory"))]
+pub(crate) fn escape_markup(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for chr in input.chars() {
+        match chr {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            chr => output.push(chr),
+        }
+    }
+    output
+}
+
+named!(pub(crate) parse_value<&str, Cow<'_, str>, VerboseError<&str>>, do_parse!(
     value: take_until_unescaped_newline >>
-    (value)
+    (value.into())
 ));
 
-fn take_until_unescaped_newline(input: &str) -> IResult<&str, Cow<'_, str>, VerboseError<&str>> {
+fn take_until_unescaped_newline(input: &str) -> IResult<&str, &str, VerboseError<&str>> {
     let mut escaped = false;
-    for (index, chr) in input.chars().enumerate() {
+    for (index, chr) in input.char_indices() {
         if chr == '\\' {
-            escaped = true;
-        } else if chr == '\n' {
-            if !escaped {
-                return Ok((&input[index..], input[..index].into()));
-            }
+            escaped = !escaped;
+        } else if chr == '\n' && !escaped {
+            let end = if input[..index].ends_with('\r') { index - 1 } else { index };
+            return Ok((&input[end..], &input[..end]));
         } else {
             escaped = false;
         }
     }
-    Err(nom::Err::Incomplete(nom::Needed::Unknown))
+    Ok(("", input))
 }
 
-named!(pub(crate) _parse_name<&str, Cow<str>, VerboseError<&str>>, do_parse!(
+named!(pub(crate) _parse_name<&str, Cow<'_, str>, VerboseError<&str>>, do_parse!(
     value: take_till1!(|x| x == ':' || x == '=' || x == ';') >>
     (value.into())
 ));
@@ -144,7 +200,7 @@ pub(crate) fn parse_name(input: &str) -> IResult<&str, Cow<'_, str>, VerboseErro
     match data {
         Cow::Borrowed("END") => Err(nom::Err::Error(VerboseError { errors: vec![(input, VerboseErrorKind::Context("Found END:VCARD tag"))]})),
         Cow::Borrowed("BEGIN") => Err(nom::Err::Error(VerboseError { errors: vec![(input, VerboseErrorKind::Context("Found BEGIN:VCARD tag"))]})),
-        data => Ok((input, data.into()))
+        data => Ok((input, data))
     }
 }
 
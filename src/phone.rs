@@ -0,0 +1,186 @@
+//! Phone number normalisation over `TEL` values.
+//!
+//! Numbers are read with the libphonenumber metadata, national numbers in
+//! a default region taken from the card's `ADR` country or given by the
+//! caller, so `312-555-1515`, `(312) 555-1515` and `+1 312 555 1515` all
+//! become `+13125551515`.
+
+use isocountry::CountryCode;
+use phonenumber::country::Id;
+use phonenumber::metadata::DATABASE;
+use phonenumber::Mode;
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::Telephone;
+use crate::{Property, TypeOrRaw, VCard, Value};
+
+/// A number read from a `TEL` value into its country code and national
+/// number.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct PhoneNumber {
+    number: phonenumber::PhoneNumber,
+    /// The extension of the `TEL`, which is not part of the number.
+    pub extension: Option<String>,
+}
+
+impl PhoneNumber {
+    /// Read `text` as a number, taking national numbers to be in `region`,
+    /// an ISO 3166 code or English country name such as `US`, `USA` or
+    /// `United States`. An unknown region is treated as none, so only
+    /// international numbers are read.
+    pub fn parse(text: &str, region: Option<&str>) -> Option<PhoneNumber> {
+        let region = region.and_then(region_id);
+        let number = phonenumber::parse(region, text).ok()?;
+        Some(PhoneNumber { number, extension: None })
+    }
+
+    /// Read the number of a `TEL` value, as [`PhoneNumber::parse`].
+    pub fn from_telephone(telephone: &Telephone, region: Option<&str>) -> Option<PhoneNumber> {
+        let mut number = PhoneNumber::parse(&telephone.digits(), region)?;
+        number.extension = telephone.extension.as_ref().map(|x| x.to_string());
+        Some(number)
+    }
+
+    /// The number in E.164 form, such as `+13125551515`.
+    pub fn e164(&self) -> String {
+        self.number.format().mode(Mode::E164).to_string()
+    }
+
+    /// The number as dialled from abroad, such as `+1 312-555-1515`.
+    pub fn international(&self) -> String {
+        self.number.format().mode(Mode::International).to_string()
+    }
+
+    /// The number as dialled within its country, such as `(312) 555-1515`.
+    pub fn national(&self) -> String {
+        self.number.format().mode(Mode::National).to_string()
+    }
+
+    /// The country calling code, such as 1 for the NANP countries.
+    pub fn country_code(&self) -> u16 {
+        self.number.code().value()
+    }
+
+    /// The ISO 3166 alpha-2 code of the region the number belongs to.
+    pub fn region(&self) -> Option<String> {
+        self.number.country().id().map(|x| x.as_ref().to_string())
+    }
+
+    /// Whether the number has a length some number of its country has.
+    pub fn is_possible(&self) -> bool {
+        let length = self.number.national().to_string().len() as u16;
+        DATABASE
+            .by_code(&self.country_code())
+            .into_iter()
+            .flatten()
+            .any(|x| {
+                // The lengths are kept per kind of number, not in general.
+                let descriptors = x.descriptors();
+                let kinds = [
+                    Some(descriptors.general()),
+                    descriptors.fixed_line(),
+                    descriptors.mobile(),
+                    descriptors.toll_free(),
+                    descriptors.premium_rate(),
+                    descriptors.shared_cost(),
+                    descriptors.personal_number(),
+                    descriptors.voip(),
+                    descriptors.pager(),
+                    descriptors.uan(),
+                    descriptors.voicemail(),
+                ];
+                kinds
+                    .iter()
+                    .flatten()
+                    .any(|x| x.possible_length().contains(&length))
+            })
+    }
+
+    /// Whether the number matches the patterns of numbers in use in its
+    /// region.
+    pub fn is_valid(&self) -> bool {
+        self.number.is_valid()
+    }
+}
+
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.extension {
+            Some(extension) => write!(f, "{} x{}", self.e164(), extension),
+            None => write!(f, "{}", self.e164()),
+        }
+    }
+}
+
+/// The phonenumber region of an ISO 3166 alpha-2 or alpha-3 code or an
+/// English country name, ignoring case.
+fn region_id(region: &str) -> Option<Id> {
+    let region = region.trim();
+    let code = CountryCode::for_alpha2_caseless(region)
+        .or_else(|_| CountryCode::for_alpha3_caseless(region))
+        .ok()
+        .or_else(|| country_by_name(region))?;
+    Id::from_str(code.alpha2()).ok()
+}
+
+/// Names in common use that differ from the ISO 3166 ones.
+const COUNTRY_ALIASES: &[(&str, CountryCode)] = &[
+    ("uk", CountryCode::GBR),
+    ("united kingdom", CountryCode::GBR),
+    ("great britain", CountryCode::GBR),
+    ("england", CountryCode::GBR),
+    ("scotland", CountryCode::GBR),
+    ("wales", CountryCode::GBR),
+    ("united states", CountryCode::USA),
+    ("america", CountryCode::USA),
+    ("russia", CountryCode::RUS),
+    ("south korea", CountryCode::KOR),
+    ("vietnam", CountryCode::VNM),
+];
+
+fn country_by_name(name: &str) -> Option<CountryCode> {
+    let name = name.trim_end_matches('.').to_lowercase();
+    let alias = COUNTRY_ALIASES.iter().find(|(alias, _)| *alias == name);
+    alias.map(|(_, code)| *code).or_else(|| {
+        CountryCode::iter()
+            .find(|x| x.name().to_lowercase() == name)
+            .copied()
+    })
+}
+
+impl<'a> VCard<'a> {
+    /// The ISO 3166 alpha-2 code of the country of the most preferred `ADR`
+    /// naming a known country.
+    pub fn phone_region(&self) -> Option<String> {
+        let mut addresses: Vec<_> = self
+            .iter()
+            .filter(|x| matches!(x.value, Value::Adr(_)))
+            .collect();
+        addresses.sort_by_key(|x| x.params.preference().unwrap_or(u8::MAX));
+        addresses.into_iter().find_map(|x| match &x.value {
+            Value::Adr(TypeOrRaw::Type(address)) => {
+                let id = region_id(address.country.as_deref()?)?;
+                Some(id.as_ref().to_string())
+            }
+            _ => None,
+        })
+    }
+
+    /// Every `TEL` with its number read in the card's
+    /// [`phone_region`](VCard::phone_region), or in `region` when the card
+    /// has none. Numbers that can not be read are left out.
+    pub fn phone_numbers(&self, region: Option<&str>) -> Vec<(&Property<'a>, PhoneNumber)> {
+        let card_region = self.phone_region();
+        let region = card_region.as_deref().or(region);
+        self.iter()
+            .filter_map(|x| match &x.value {
+                Value::Tel(TypeOrRaw::Type(telephone)) => {
+                    Some((x, PhoneNumber::from_telephone(telephone, region)?))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -1,9 +1,12 @@
-use nom::{do_parse, error::VerboseError, named, opt, preceded, tag, take_till, Err, IResult};
+use nom::{
+    alt, delimited, do_parse, error::VerboseError, named, opt, preceded, recognize, tag, take_till,
+    Err, IResult,
+};
 
 use std::borrow::Cow;
 use std::fmt;
 
-use crate::{parse::parse_name, Parse, ParseError};
+use crate::{parse::parse_name, IntoOwned, Parse, ParseError};
 
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -28,8 +31,19 @@ impl<'a> fmt::Display for Parameter<'a> {
     }
 }
 
+impl<'a> IntoOwned for Parameter<'a> {
+    type Owned = Parameter<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Parameter {
+            name: IntoOwned::into_owned(self.name),
+            value: self.value.into_owned(),
+        }
+    }
+}
+
 impl<'a> Parse<'a> for Parameter<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError<'a>> {
         match parse_parameter(input) {
             Ok(item) => Ok(item),
             Err(Err::Failure(err)) => Err(Err::Failure(ParseError(err))),
@@ -39,13 +53,19 @@ impl<'a> Parse<'a> for Parameter<'a> {
     }
 }
 
-named!(pub parse_parameter<&str, Parameter, VerboseError<&str>>, do_parse!(
+named!(pub parse_parameter<&str, Parameter<'_>, VerboseError<&str>>, do_parse!(
     name: parse_name >>
     value: opt!(preceded!(tag!("="), parse_parameter_value)) >>
     (Parameter { name, value })
 ));
 
-named!(parse_parameter_value<&str, Cow<str>, VerboseError<&str>>, do_parse!(
-    value: take_till!(|x| ":;".contains(x)) >>
+named!(parse_parameter_value<&str, Cow<'_, str>, VerboseError<&str>>, do_parse!(
+    value: alt!(parse_quoted_parameter_value | take_till!(|x| ":;".contains(x))) >>
     (value.into())
 ));
+
+named!(parse_quoted_parameter_value<&str, &str, VerboseError<&str>>, recognize!(delimited!(
+    tag!("\""),
+    take_till!(|x| x == '"'),
+    tag!("\"")
+)));
@@ -3,7 +3,9 @@ use nom::{do_parse, error::VerboseError, named, opt, separated_list0, tag, IResu
 
 use std::fmt;
 
-use crate::{Parameter, Parse, ParseError};
+use crate::parse::{split_unescaped, unescape_text};
+use crate::types::{GeoPosition, Service, TelephoneType, TimeZone};
+use crate::{IntoOwned, Parameter, Parse, ParseError};
 
 #[derive(Debug, PartialEq, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -22,8 +24,113 @@ impl<'a> fmt::Display for Parameters<'a> {
     }
 }
 
+impl<'a> Parameters<'a> {
+    /// The value of the `TZ` parameter.
+    pub fn timezone(&self) -> Option<TimeZone<'_>> {
+        TimeZone::from_text(self.unquoted("TZ")?)
+    }
+
+    /// The value of the `GEO` parameter.
+    pub fn geo(&self) -> Option<GeoPosition> {
+        GeoPosition::from_text(self.unquoted("GEO")?)
+    }
+
+    /// The `TYPE` values in lowercase, including the bare type names of
+    /// 2.1 such as `TEL;WORK;FAX`.
+    pub fn types(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .flat_map(|x| match &x.value {
+                Some(value) if x.name.eq_ignore_ascii_case("TYPE") => {
+                    value.trim_matches('"').split(',').collect()
+                }
+                None => vec![x.name.as_ref()],
+                Some(_) => vec![],
+            })
+            .map(|x| x.to_lowercase())
+            .collect()
+    }
+
+    /// The preference from 1, most preferred, to 100. Taken from `PREF`
+    /// or, as in 3.0, a `pref` type.
+    pub fn preference(&self) -> Option<u8> {
+        match self.unquoted("PREF").and_then(|x| x.parse().ok()) {
+            Some(pref) => Some(pref).filter(|x| (1..=100).contains(x)),
+            None => self.types().iter().any(|x| x == "pref").then_some(1),
+        }
+    }
+
+    /// What a `TEL` is for, `voice` if no type says.
+    pub fn telephone_types(&self) -> Vec<TelephoneType> {
+        let types = self
+            .types()
+            .iter()
+            .filter_map(|x| TelephoneType::from_name(x))
+            .collect::<Vec<_>>();
+        if types.is_empty() {
+            vec![TelephoneType::Voice]
+        } else {
+            types
+        }
+    }
+
+    /// The `SORT-AS` values, one for each component of an `N` or `ORG`.
+    pub fn sort_as(&self) -> Vec<String> {
+        match self.unquoted("SORT-AS") {
+            Some(value) => split_unescaped(value, ',')
+                .into_iter()
+                .map(unescape_text)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// The service of an `IMPP` or social profile, from `SERVICE-TYPE`,
+    /// Apple's `X-SERVICE-TYPE` or a `TYPE` naming a known service.
+    pub fn service_type(&self) -> Option<String> {
+        let named = self
+            .unquoted("SERVICE-TYPE")
+            .or_else(|| self.unquoted("X-SERVICE-TYPE"));
+        match named {
+            Some(name) => Some(name.to_string()),
+            None => self
+                .types()
+                .into_iter()
+                .find(|x| !matches!(Service::from_name(x), Service::Other(_))),
+        }
+    }
+
+    /// The username of a social profile, from `USERNAME` or Apple's
+    /// `X-USER`.
+    pub fn username(&self) -> Option<&str> {
+        self.unquoted("USERNAME").or_else(|| self.unquoted("X-USER"))
+    }
+
+    /// The value of the named parameter without its surrounding quotes.
+    fn unquoted(&self, name: &str) -> Option<&str> {
+        let value = self
+            .0
+            .iter()
+            .find(|x| x.name.eq_ignore_ascii_case(name))?
+            .value
+            .as_deref()?;
+        match value.strip_prefix('"').and_then(|x| x.strip_suffix('"')) {
+            Some(unquoted) => Some(unquoted),
+            None => Some(value),
+        }
+    }
+}
+
+impl<'a> IntoOwned for Parameters<'a> {
+    type Owned = Parameters<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Parameters(self.0.into_owned())
+    }
+}
+
 impl<'a> Parse<'a> for Parameters<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError<'a>> {
         match parse_parameters(input) {
             Ok((remains, item)) => Ok((remains, Parameters(item))),
             Err(nom::Err::Failure(err)) => Err(nom::Err::Failure(ParseError(err))),
@@ -33,7 +140,7 @@ impl<'a> Parse<'a> for Parameters<'a> {
     }
 }
 
-named!(pub parse_parameters<&str, Vec<Parameter>, VerboseError<&str>>, do_parse!(
+named!(pub parse_parameters<&str, Vec<Parameter<'_>>, VerboseError<&str>>, do_parse!(
     opt!(tag!(";")) >>
     data: separated_list0!(tag!(";"), parse_parameter) >>
     (data)
@@ -1,11 +1,13 @@
 use nom::error::VerboseError;
-use nom::{alt, do_parse, named, tag, tag_no_case, opt};
+use nom::{alt, do_parse, named, one_of, opt, peek, tag, tag_no_case, take_while1, terminated};
+
+use std::borrow::Cow;
 
 use super::{parse_parameters, Property};
 use crate::parse::{parse_multiple_value, parse_name, parse_typed_value, parse_value};
 use crate::{Parameters, Value};
 
-named!(pub parse_property<&str, Property, VerboseError<&str>>, alt!(
+named!(pub parse_property<&str, Property<'_>, VerboseError<&str>>, alt!(
 //    parse_begin |
 //    parse_end |
     parse_logo |
@@ -18,11 +20,13 @@ named!(pub parse_property<&str, Property, VerboseError<&str>>, alt!(
     parse_photo |
     parse_birthday |
     parse_anniversary |
+    parse_deathdate |
     parse_gender |
     parse_address |
     parse_tel |
     parse_email |
     parse_impp |
+    parse_socialprofile |
     parse_lang |
     parse_timezone |
     parse_geo |
@@ -42,48 +46,52 @@ named!(pub parse_property<&str, Property, VerboseError<&str>>, alt!(
     parse_version |
     parse_key |
     parse_fburl |
-    parse_caladrurl |
-    parse_calurl |
+    parse_caladruri |
+    parse_caluri |
     parse_other
 ));
 
-named!(pub parse_begin<&str, Property, VerboseError<&str>>, do_parse!(
+named!(pub parse_begin<&str, Property<'_>, VerboseError<&str>>, do_parse!(
     tag_no_case!("BEGIN") >>
     tag!(":") >>
     tag_no_case!("VCARD") >>
     opt!(tag!("\r")) >>
     opt!(tag!("\n")) >>
-    (Property { params: Parameters(vec![]), value: Value::Begin })
+    (Property { group: None, params: Parameters(vec![]), value: Value::Begin })
 ));
 
-named!(pub parse_end<&str, Property, VerboseError<&str>>, do_parse!(
+named!(pub parse_end<&str, Property<'_>, VerboseError<&str>>, do_parse!(
     tag_no_case!("END") >>
     tag!(":") >>
     tag_no_case!("VCARD") >>
     opt!(tag!("\r")) >>
     opt!(tag!("\n")) >>
-    (Property { params: Parameters(vec![]), value: Value::End })
+    (Property { group: None, params: Parameters(vec![]), value: Value::End })
 ));
 
 macro_rules! impl_simple_prop_parser {
     ($label:ident, $txt:expr, $variant:ident) => {
-        named!($label<&str, Property, VerboseError<&str>>, do_parse!(
+        named!($label<&str, Property<'_>, VerboseError<&str>>, do_parse!(
+            group: opt!(parse_group) >>
             tag_no_case!($txt) >>
+            peek!(one_of!(";:")) >>
             params: parse_parameters >>
             tag!(":") >>
             value: parse_value >>
             opt!(tag!("\r")) >>
-            (Property { params: Parameters(params), value: Value::$variant(value) })
+            (Property { group, params: Parameters(params), value: Value::$variant(value) })
         ));
     };
     ($label:ident, $txt:expr, $variant:ident, $value_func:ident) => {
-        named!($label<&str, Property, VerboseError<&str>>, do_parse!(
+        named!($label<&str, Property<'_>, VerboseError<&str>>, do_parse!(
+            group: opt!(parse_group) >>
             tag_no_case!($txt) >>
+            peek!(one_of!(";:")) >>
             params: parse_parameters >>
             tag!(":") >>
             value: $value_func >>
             opt!(tag!("\r")) >>
-            (Property { params: Parameters(params), value: Value::$variant(value) })
+            (Property { group, params: Parameters(params), value: Value::$variant(value) })
         ));
     };
 }
@@ -103,19 +111,26 @@ impl_simple_prop_parser!(
     Anniversary,
     parse_typed_value
 );
+impl_simple_prop_parser!(parse_deathdate, "DEATHDATE", DeathDate, parse_typed_value);
 impl_simple_prop_parser!(parse_gender, "GENDER", Gender, parse_typed_value);
 impl_simple_prop_parser!(parse_address, "ADR", Adr, parse_typed_value);
-impl_simple_prop_parser!(parse_tel, "TEL", Tel);
+impl_simple_prop_parser!(parse_tel, "TEL", Tel, parse_typed_value);
 impl_simple_prop_parser!(parse_email, "EMAIL", Email, parse_typed_value);
-impl_simple_prop_parser!(parse_impp, "IMPP", Impp);
+impl_simple_prop_parser!(parse_impp, "IMPP", Impp, parse_typed_value);
+impl_simple_prop_parser!(
+    parse_socialprofile,
+    "SOCIALPROFILE",
+    SocialProfile,
+    parse_typed_value
+);
 impl_simple_prop_parser!(parse_lang, "LANG", Lang);
-impl_simple_prop_parser!(parse_timezone, "TZ", Timezone);
+impl_simple_prop_parser!(parse_timezone, "TZ", Timezone, parse_typed_value);
 impl_simple_prop_parser!(parse_title, "TITLE", Title);
 impl_simple_prop_parser!(parse_role, "ROLE", Role);
 impl_simple_prop_parser!(parse_logo, "LOGO", Logo, parse_typed_value);
-impl_simple_prop_parser!(parse_organization, "ORG", Organization);
-impl_simple_prop_parser!(parse_member, "MEMBER", Member);
-impl_simple_prop_parser!(parse_related, "RELATED", Related);
+impl_simple_prop_parser!(parse_organization, "ORG", Organization, parse_typed_value);
+impl_simple_prop_parser!(parse_member, "MEMBER", Member, parse_typed_value);
+impl_simple_prop_parser!(parse_related, "RELATED", Related, parse_typed_value);
 impl_simple_prop_parser!(
     parse_categories,
     "CATEGORIES",
@@ -124,21 +139,27 @@ impl_simple_prop_parser!(
 );
 impl_simple_prop_parser!(parse_note, "NOTE", Note);
 impl_simple_prop_parser!(parse_prodid, "PRODID", ProdID);
-impl_simple_prop_parser!(parse_rev, "REV", Rev);
+impl_simple_prop_parser!(parse_rev, "REV", Rev, parse_typed_value);
 impl_simple_prop_parser!(parse_sound, "SOUND", Sound);
 impl_simple_prop_parser!(parse_uid, "UID", Uid);
 impl_simple_prop_parser!(parse_clientpidmap, "CLIENTPIDMAP", ClientPIDMap);
 impl_simple_prop_parser!(parse_url, "URL", Url, parse_typed_value);
 impl_simple_prop_parser!(parse_version, "VERSION", Version);
-impl_simple_prop_parser!(parse_key, "KEY", Key);
-impl_simple_prop_parser!(parse_fburl, "FBURL", FbUrl);
-impl_simple_prop_parser!(parse_calurl, "CALURL", CalURL);
-impl_simple_prop_parser!(parse_caladrurl, "CALADRURL", CalAdrURL);
+impl_simple_prop_parser!(parse_key, "KEY", Key, parse_typed_value);
+impl_simple_prop_parser!(parse_fburl, "FBURL", FbUrl, parse_typed_value);
+impl_simple_prop_parser!(parse_caluri, "CALURI", CalURL, parse_typed_value);
+impl_simple_prop_parser!(parse_caladruri, "CALADRURI", CalAdrURL, parse_typed_value);
+
+named!(parse_group<&str, Cow<'_, str>, VerboseError<&str>>, do_parse!(
+    group: terminated!(take_while1!(|x: char| x.is_alphanumeric() || x == '-'), tag!(".")) >>
+    (group.into())
+));
 
-named!(parse_other<&str, Property, VerboseError<&str>>, do_parse!(
+named!(parse_other<&str, Property<'_>, VerboseError<&str>>, do_parse!(
+    group: opt!(parse_group) >>
     name: parse_name >>
     params: parse_parameters >>
     tag!(":") >>
     value: parse_value >>
-    ( Property { params: Parameters(params), value: Value::Other(name, value) })
+    ( Property { group, params: Parameters(params), value: Value::Other(name, value) })
 ));
@@ -0,0 +1,67 @@
+use crate::types::{BoundingBox, GeoPosition};
+use crate::{TypeOrRaw, VCard, Value, Vcf};
+
+/// A vcard found by [`Vcf::within`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Nearby<'v, 'a> {
+    pub vcard: &'v VCard<'a>,
+    pub position: GeoPosition,
+    /// Meters from the center of the search.
+    pub distance: f64,
+}
+
+impl<'a> VCard<'a> {
+    /// The position of the contact, from `GEO` or else from the `GEO`
+    /// parameter of an `ADR`.
+    pub fn position(&self) -> Option<GeoPosition> {
+        self.positions().next()
+    }
+
+    /// The first position of the contact in WGS-84, the system distances
+    /// and bounding boxes are measured in.
+    fn wgs84_position(&self) -> Option<GeoPosition> {
+        self.positions().find(GeoPosition::is_wgs84)
+    }
+
+    /// Every position of the contact, the `GEO` properties first.
+    fn positions(&self) -> impl Iterator<Item = GeoPosition> + '_ {
+        let properties = self.iter().filter_map(|x| match &x.value {
+            Value::Geo(TypeOrRaw::Type(position)) => Some(position.clone()),
+            _ => None,
+        });
+        let addresses = self
+            .iter()
+            .filter(|x| matches!(x.value, Value::Adr(_)))
+            .filter_map(|x| x.params.geo());
+        properties.chain(addresses)
+    }
+}
+
+impl<'a> Vcf<'a> {
+    /// The vcards positioned at most `meters` from `center`, nearest first.
+    pub fn within(&self, center: &GeoPosition, meters: f64) -> Vec<Nearby<'_, 'a>> {
+        let mut nearby = vec![];
+        for vcard in &self.0 {
+            if let Some(position) = vcard.wgs84_position() {
+                let distance = center.distance(&position);
+                if distance <= meters {
+                    nearby.push(Nearby {
+                        vcard,
+                        position,
+                        distance,
+                    });
+                }
+            }
+        }
+        nearby.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        nearby
+    }
+
+    /// The vcards positioned inside `bounds`, in file order.
+    pub fn in_box(&self, bounds: &BoundingBox) -> Vec<&VCard<'a>> {
+        self.0
+            .iter()
+            .filter(|x| x.wgs84_position().is_some_and(|x| bounds.contains(&x)))
+            .collect()
+    }
+}
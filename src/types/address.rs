@@ -3,7 +3,7 @@ use nom::{do_parse, named, opt, tag, IResult};
 use std::borrow::Cow;
 use std::fmt;
 
-use crate::{Parse, ParseError};
+use crate::{IntoOwned, Parse, ParseError};
 
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -41,8 +41,24 @@ impl<'a> fmt::Display for Address<'a> {
     }
 }
 
+impl<'a> IntoOwned for Address<'a> {
+    type Owned = Address<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Address {
+            po_box: self.po_box.into_owned(),
+            extended: self.extended.into_owned(),
+            street: self.street.into_owned(),
+            locality: self.locality.into_owned(),
+            region: self.region.into_owned(),
+            code: self.code.into_owned(),
+            country: self.country.into_owned(),
+        }
+    }
+}
+
 impl<'a> Parse<'a> for Address<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Address<'a>, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Address<'a>, ParseError<'a>> {
         parse_formatted_address(input).map_err(|c| match c {
             nom::Err::Error(err) => nom::Err::Error(crate::parse::to_parse_error(input, err)),
             nom::Err::Failure(err) => nom::Err::Failure(crate::parse::to_parse_error(input, err)),
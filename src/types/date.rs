@@ -0,0 +1,294 @@
+use chrono::Datelike;
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
+
+use std::fmt;
+
+use crate::parse::{parse_value, Parse, ParseError};
+use crate::IntoOwned;
+
+/// A calendar date, any part of it may be left out as in `--0415` or
+/// `---12`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+pub struct Date {
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub year: Option<i32>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub month: Option<u32>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub day: Option<u32>,
+}
+
+/// A time of day, any part of it may be left out as in `-2200` or `--00`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+pub struct Time {
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub hour: Option<u32>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub minute: Option<u32>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub second: Option<u32>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub zone: Option<Zone>,
+}
+
+/// The time zone of a [`Time`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Zone {
+    /// Written as `Z`.
+    Utc,
+    /// Minutes east of UTC.
+    Offset(i32),
+}
+
+/// The `date-and-or-time` value type of RFC 6350 section 4.3.4, read from
+/// the basic and extended ISO 8601 formats and written in the basic one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DateAndOrTime {
+    Date(Date),
+    DateTime(Date, Time),
+    /// Written with a leading `T`.
+    Time(Time),
+}
+
+impl Date {
+    /// The date as a `chrono::NaiveDate` if it has a year, month and day.
+    pub fn to_naive_date(&self) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(self.year?, self.month?, self.day?)
+    }
+}
+
+impl DateAndOrTime {
+    pub fn date(&self) -> Option<&Date> {
+        match self {
+            DateAndOrTime::Date(date) | DateAndOrTime::DateTime(date, _) => Some(date),
+            DateAndOrTime::Time(_) => None,
+        }
+    }
+
+    pub fn time(&self) -> Option<&Time> {
+        match self {
+            DateAndOrTime::Time(time) | DateAndOrTime::DateTime(_, time) => Some(time),
+            DateAndOrTime::Date(_) => None,
+        }
+    }
+}
+
+impl From<chrono::NaiveDate> for DateAndOrTime {
+    fn from(date: chrono::NaiveDate) -> DateAndOrTime {
+        DateAndOrTime::Date(Date {
+            year: Some(date.year()),
+            month: Some(date.month()),
+            day: Some(date.day()),
+        })
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.year, self.month, self.day) {
+            (Some(year), Some(month), None) => return write!(f, "{:04}-{:02}", year, month),
+            (Some(year), _, _) => write!(f, "{:04}", year)?,
+            (None, _, _) => write!(f, "--")?,
+        }
+        match (self.month, self.day) {
+            (Some(month), _) => write!(f, "{:02}", month)?,
+            (None, Some(_)) => write!(f, "-")?,
+            (None, None) => {}
+        }
+        match self.day {
+            Some(day) => write!(f, "{:02}", day),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.hour {
+            Some(hour) => write!(f, "{:02}", hour)?,
+            None => write!(f, "-")?,
+        }
+        match (self.minute, self.second) {
+            (Some(minute), _) => write!(f, "{:02}", minute)?,
+            (None, Some(_)) if self.hour.is_none() => write!(f, "-")?,
+            _ => {}
+        }
+        if let Some(second) = self.second {
+            write!(f, "{:02}", second)?;
+        }
+        match self.zone {
+            Some(zone) => write!(f, "{}", zone),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Zone::Utc => write!(f, "Z"),
+            Zone::Offset(minutes) => {
+                let sign = if *minutes < 0 { '-' } else { '+' };
+                let minutes = minutes.abs();
+                write!(f, "{}{:02}{:02}", sign, minutes / 60, minutes % 60)
+            }
+        }
+    }
+}
+
+impl fmt::Display for DateAndOrTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DateAndOrTime::Date(date) => write!(f, "{}", date),
+            DateAndOrTime::DateTime(date, time) => write!(f, "{}T{}", date, time),
+            DateAndOrTime::Time(time) => write!(f, "T{}", time),
+        }
+    }
+}
+
+impl IntoOwned for DateAndOrTime {
+    type Owned = DateAndOrTime;
+
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+}
+
+impl<'a> Parse<'a> for DateAndOrTime {
+    fn parse(input: &'a str) -> IResult<&'a str, DateAndOrTime, ParseError<'a>> {
+        let (remains, value) = parse_value(input).map_err(|c| c.map(ParseError))?;
+        match parse_date_and_or_time(&value) {
+            Some(value) => Ok((remains, value)),
+            None => {
+                let error = VerboseError {
+                    errors: vec![(
+                        input,
+                        VerboseErrorKind::Context("Failed to parse date-and-or-time"),
+                    )],
+                };
+                Err(nom::Err::Error(ParseError(error)))
+            }
+        }
+    }
+}
+
+fn parse_date_and_or_time(value: &str) -> Option<DateAndOrTime> {
+    if !value.is_ascii() {
+        return None;
+    }
+    match value.find('T') {
+        Some(0) => Some(DateAndOrTime::Time(parse_time(&value[1..])?)),
+        Some(index) => Some(DateAndOrTime::DateTime(
+            parse_date(&value[..index])?,
+            parse_time(&value[index + 1..])?,
+        )),
+        None => Some(DateAndOrTime::Date(parse_date(value)?)),
+    }
+}
+
+/// Parse a run of digits, none of them optional.
+fn number(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.chars().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn parse_date(value: &str) -> Option<Date> {
+    let (year, month, day) = if let Some(day) = value.strip_prefix("---") {
+        (None, None, Some(day))
+    } else if let Some(rest) = value.strip_prefix("--") {
+        match rest.len() {
+            2 => (None, Some(rest), None),
+            4 => (None, Some(&rest[..2]), Some(&rest[2..])),
+            5 if &rest[2..3] == "-" => (None, Some(&rest[..2]), Some(&rest[3..])),
+            _ => return None,
+        }
+    } else {
+        match value.len() {
+            4 => (Some(value), None, None),
+            7 if &value[4..5] == "-" => (Some(&value[..4]), Some(&value[5..]), None),
+            8 => (Some(&value[..4]), Some(&value[4..6]), Some(&value[6..])),
+            10 if &value[4..5] == "-" && &value[7..8] == "-" => {
+                (Some(&value[..4]), Some(&value[5..7]), Some(&value[8..]))
+            }
+            _ => return None,
+        }
+    };
+    let two_digits = |x: &str| Some(x).filter(|x| x.len() == 2).and_then(number);
+    let date = Date {
+        year: match year {
+            Some(year) => Some(number(year)? as i32),
+            None => None,
+        },
+        month: match month {
+            Some(month) => Some(two_digits(month).filter(|x| (1..=12).contains(x))?),
+            None => None,
+        },
+        day: match day {
+            Some(day) => Some(two_digits(day).filter(|x| (1..=31).contains(x))?),
+            None => None,
+        },
+    };
+    if let (Some(month), Some(day)) = (date.month, date.day) {
+        // 2000 is a leap year, so February 29 passes without a year.
+        chrono::NaiveDate::from_ymd_opt(date.year.unwrap_or(2000), month, day)?;
+    }
+    Some(date)
+}
+
+fn parse_time(value: &str) -> Option<Time> {
+    let dashes = value.len() - value.trim_start_matches('-').len();
+    let rest = &value[dashes..];
+    let (local, zone) = match rest.find(|x| "Z+-".contains(x)) {
+        Some(index) => (&rest[..index], Some(parse_zone(&rest[index..])?)),
+        None => (rest, None),
+    };
+    let digits = local.replace(':', "");
+    let count = digits.len() / 2;
+    if digits.len() % 2 != 0 || count == 0 || dashes + count > 3 {
+        return None;
+    }
+    let mut fields = [None; 3];
+    for (index, pair) in digits.as_bytes().chunks(2).enumerate() {
+        fields[dashes + index] = Some(number(std::str::from_utf8(pair).ok()?)?);
+    }
+    let [hour, minute, second] = fields;
+    if hour > Some(23) || minute > Some(59) || second > Some(60) {
+        return None;
+    }
+    Some(Time {
+        hour,
+        minute,
+        second,
+        zone,
+    })
+}
+
+pub(super) fn parse_zone(value: &str) -> Option<Zone> {
+    if value == "Z" {
+        return Some(Zone::Utc);
+    }
+    let sign = match value.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits = value[1..].replace(':', "");
+    let (hours, minutes) = match digits.len() {
+        2 => (number(&digits)?, 0),
+        4 => (number(&digits[..2])?, number(&digits[2..])?),
+        _ => return None,
+    };
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(Zone::Offset(sign * (hours * 60 + minutes) as i32))
+}
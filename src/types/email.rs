@@ -1,40 +1,205 @@
-use nom::character::is_alphanumeric;
-use nom::{do_parse, named, tag, take_until, take_while, IResult};
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
 
 use std::borrow::Cow;
 use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
 
-use crate::{Parse, ParseError};
+use super::uri::percent_decode;
+use crate::parse::{parse_value, Parse, ParseError};
+use crate::IntoOwned;
 
+/// An `EMAIL` value, an address as in RFC 5322 such as `jane@example.com`
+/// or `"jane doe"@example.com`, or a `mailto:` URI holding one.
+///
+/// Internationalised domains are kept in lowercase ASCII with their labels
+/// in punycode, see [`Email::unicode_domain`].
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 pub struct Email<'a> {
+    /// The local part as written, quotes included.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
     pub user: Cow<'a, str>,
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
     pub domain: Cow<'a, str>,
+    /// Written as a `mailto:` URI, otherwise as a bare address.
+    #[cfg_attr(feature = "typed-builder", builder(default))]
+    pub uri: bool,
+}
+
+/// Characters of an atom besides letters and digits, RFC 5322 3.2.3.
+const ATEXT: &str = "!#$%&'*+-/=?^_`{|}~";
+
+impl<'a> Email<'a> {
+    /// Read an address from the whole of `text`, as a `mailto:` URI or as
+    /// a bare address.
+    pub fn from_text(text: &'a str) -> Option<Email<'a>> {
+        match text.get(..7) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("mailto:") => {
+                // Header fields such as `?subject=` make it more than an
+                // address.
+                let address = Some(&text[7..]).filter(|x| !x.contains(['?', '#']))?;
+                let mut email = match percent_decode(address)? {
+                    Cow::Borrowed(address) => parse_address(address)?,
+                    Cow::Owned(address) => parse_address(&address)?.into_owned(),
+                };
+                email.uri = true;
+                Some(email)
+            }
+            _ => parse_address(text),
+        }
+    }
+
+    /// The address without the `mailto:` scheme.
+    pub fn address(&self) -> String {
+        format!("{}@{}", self.user, self.domain)
+    }
+
+    /// The domain with its punycode labels decoded, as shown to people.
+    pub fn unicode_domain(&self) -> String {
+        idna::domain_to_unicode(&self.domain).0
+    }
+}
+
+fn parse_address(text: &str) -> Option<Email<'_>> {
+    let at = text.rfind('@')?;
+    let (user, domain) = (&text[..at], &text[at + 1..]);
+    if !valid_local(user) {
+        return None;
+    }
+    Some(Email {
+        user: user.into(),
+        domain: normalise_domain(domain)?,
+        uri: false,
+    })
+}
+
+/// Whether `local` is a dot-atom or quoted string of at most 64 octets,
+/// non-ASCII characters allowed as in RFC 6532.
+fn valid_local(local: &str) -> bool {
+    if local.is_empty() || local.len() > 64 {
+        return false;
+    }
+    match local.strip_prefix('"') {
+        Some(quoted) => valid_quoted(quoted),
+        None => local
+            .split('.')
+            .all(|atom| !atom.is_empty() && atom.chars().all(is_atext)),
+    }
+}
+
+fn is_atext(x: char) -> bool {
+    x.is_ascii_alphanumeric() || ATEXT.contains(x) || (!x.is_ascii() && !x.is_control())
+}
+
+/// Whether `quoted`, following an opening quote, is the rest of a quoted
+/// string with nothing after its closing quote.
+fn valid_quoted(quoted: &str) -> bool {
+    let mut chars = quoted.chars();
+    while let Some(x) = chars.next() {
+        match x {
+            '"' => return chars.as_str().is_empty(),
+            '\\' => match chars.next() {
+                Some(x) if x == ' ' || x == '\t' || x.is_ascii_graphic() => {}
+                _ => return false,
+            },
+            x if x == ' ' || x.is_ascii_graphic() || (!x.is_ascii() && !x.is_control()) => {}
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// The domain in lowercase ASCII with non-ASCII labels in punycode, or a
+/// domain literal such as `[192.0.2.1]` or `[IPv6:2001:db8::1]`.
+fn normalise_domain(domain: &str) -> Option<Cow<'_, str>> {
+    if let Some(literal) = domain.strip_prefix('[').and_then(|x| x.strip_suffix(']')) {
+        let valid = match literal.get(..5) {
+            Some(tag) if tag.eq_ignore_ascii_case("ipv6:") => {
+                literal[5..].parse::<Ipv6Addr>().is_ok()
+            }
+            _ => literal.parse::<Ipv4Addr>().is_ok(),
+        };
+        return Some(domain.into()).filter(|_| valid);
+    }
+    // UTS 46 mapping, which also lowercases and folds full-width forms.
+    let normal = idna::domain_to_ascii(domain).ok()?;
+    if !normal.split('.').all(valid_label) {
+        return None;
+    }
+    match normal.len() {
+        0..=253 if normal == domain => Some(domain.into()),
+        0..=253 => Some(normal.into()),
+        _ => None,
+    }
+}
+
+/// Whether `label` is a hostname label of RFC 1123.
+fn valid_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= 63
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label
+            .bytes()
+            .all(|x| x.is_ascii_alphanumeric() || x == b'-')
+}
+
+/// Percent-encode the characters of an address a `mailto:` URI can not
+/// hold as they are.
+fn mailto_encode(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for x in text.chars() {
+        if x.is_ascii_alphanumeric() || "-._~!$'()*+,;=:@".contains(x) {
+            output.push(x);
+        } else {
+            let mut bytes = [0; 4];
+            for byte in x.encode_utf8(&mut bytes).bytes() {
+                output.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    output
 }
 
 impl<'a> fmt::Display for Email<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}@{}", self.user, self.domain)
+        if self.uri {
+            write!(f, "mailto:{}", mailto_encode(&self.address()))
+        } else {
+            write!(f, "{}@{}", self.user, self.domain)
+        }
     }
 }
 
-impl<'a> Parse<'a> for Email<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Email, ParseError> {
-        parse_email(input).map_err(|c| match c {
-            nom::Err::Error(err) => nom::Err::Error(crate::parse::to_parse_error(input, err)),
-            nom::Err::Failure(err) => nom::Err::Failure(crate::parse::to_parse_error(input, err)),
-            nom::Err::Incomplete(n) => nom::Err::Incomplete(n),
-        })
+impl<'a> IntoOwned for Email<'a> {
+    type Owned = Email<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Email {
+            user: IntoOwned::into_owned(self.user),
+            domain: IntoOwned::into_owned(self.domain),
+            uri: self.uri,
+        }
     }
 }
 
-named!(parse_email<&str, Email>, do_parse!(
-    user: take_until!("@") >>
-    tag!("@") >>
-    domain: take_while!(|x| is_alphanumeric(x as u8) || "_-.".contains(x)) >>
-    (Email { user: user.into(), domain: domain.into() })
-));
+impl<'a> Parse<'a> for Email<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, Email<'a>, ParseError<'a>> {
+        let (remains, value) = parse_value(input).map_err(|c| c.map(ParseError))?;
+        let value = match value {
+            Cow::Borrowed(value) => Email::from_text(value),
+            Cow::Owned(_) => None,
+        };
+        match value {
+            Some(value) => Ok((remains, value)),
+            None => {
+                let error = VerboseError {
+                    errors: vec![(input, VerboseErrorKind::Context("Failed to parse email"))],
+                };
+                Err(nom::Err::Error(ParseError(error)))
+            }
+        }
+    }
+}
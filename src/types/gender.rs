@@ -4,6 +4,7 @@ use std::borrow::Cow;
 use std::fmt;
 
 use crate::parse::{parse_value, Parse, ParseError};
+use crate::IntoOwned;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -28,7 +29,7 @@ impl fmt::Display for Sex {
 }
 
 impl<'a> Parse<'a> for Sex {
-    fn parse(input: &'a str) -> IResult<&'a str, Sex, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Sex, ParseError<'a>> {
         parse_sex(input).map_err(|c| match c {
             nom::Err::Error(err) => nom::Err::Error(err.into()),
             nom::Err::Failure(err) => nom::Err::Failure(err.into()),
@@ -60,13 +61,24 @@ impl<'a> fmt::Display for Gender<'a> {
         let sex = self
             .sex
             .map(|x| format!("{}", x))
-            .unwrap_or_else(String::new);
+            .unwrap_or_default();
         write!(f, "{};{}", sex, self.identity)
     }
 }
 
+impl<'a> IntoOwned for Gender<'a> {
+    type Owned = Gender<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Gender {
+            sex: self.sex,
+            identity: IntoOwned::into_owned(self.identity),
+        }
+    }
+}
+
 impl<'a> Parse<'a> for Gender<'a> {
-    fn parse(input: &'a str) -> IResult<&str, Gender<'a>, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Gender<'a>, ParseError<'a>> {
         parse_gender(input).map_err(|c| match c {
             nom::Err::Error(err) => nom::Err::Error(err.into()),
             nom::Err::Failure(err) => nom::Err::Failure(err.into()),
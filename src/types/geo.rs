@@ -1,32 +1,215 @@
-use nom::{do_parse, named, parse_to, tag, IResult};
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
 
 use std::fmt;
 
-use crate::{Parse, ParseError};
+use crate::parse::parse_value;
+use crate::{IntoOwned, Parse, ParseError};
 
+/// Mean radius of the earth in meters, used for distances.
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// A `GEO` property or parameter value, read from an RFC 5870 `geo:` URI or
+/// the 3.0 `lat;lon` form and written back in the same form.
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct GeoPosition(pub f64, pub f64);
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+pub struct GeoPosition {
+    pub latitude: f64,
+    pub longitude: f64,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub altitude: Option<f64>,
+    /// The coordinate reference system, WGS-84 when left out.
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option, into), default))]
+    pub crs: Option<String>,
+    /// The `u` parameter, in meters.
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub uncertainty: Option<f64>,
+    /// Any other URI parameters, in order.
+    #[cfg_attr(feature = "typed-builder", builder(default))]
+    pub parameters: Vec<(String, String)>,
+    /// Written as a `geo:` URI, otherwise in the 3.0 `lat;lon` form.
+    #[cfg_attr(feature = "typed-builder", builder(default = true))]
+    pub uri: bool,
+}
+
+impl GeoPosition {
+    /// A position written as a `geo:` URI.
+    pub fn new(latitude: f64, longitude: f64) -> GeoPosition {
+        GeoPosition {
+            latitude,
+            longitude,
+            altitude: None,
+            crs: None,
+            uncertainty: None,
+            parameters: vec![],
+            uri: true,
+        }
+    }
+
+    /// Read a position from the text of a value or parameter. A bare
+    /// `lat,lon` is taken as a `geo:` URI without its scheme.
+    pub fn from_text(text: &str) -> Option<GeoPosition> {
+        let scheme = text.get(..4).filter(|x| x.eq_ignore_ascii_case("geo:"));
+        let uri = match scheme {
+            Some(_) => &text[4..],
+            None => {
+                let mut parts = text.split(';');
+                if let (Some(latitude), Some(longitude), None) =
+                    (parts.next(), parts.next(), parts.next())
+                {
+                    if !latitude.contains(',') {
+                        let mut position = GeoPosition::new(number(latitude)?, number(longitude)?);
+                        position.uri = false;
+                        return Some(position).filter(GeoPosition::in_range);
+                    }
+                }
+                text
+            }
+        };
+
+        let mut parts = uri.split(';');
+        let mut coordinates = parts.next()?.split(',');
+        let mut position = GeoPosition::new(
+            number(coordinates.next()?)?,
+            number(coordinates.next()?)?,
+        );
+        position.altitude = match coordinates.next() {
+            Some(altitude) => Some(number(altitude)?),
+            None => None,
+        };
+        if coordinates.next().is_some() {
+            return None;
+        }
+        for (index, parameter) in parts.enumerate() {
+            let (name, value) = match parameter.find('=') {
+                Some(at) => (&parameter[..at], &parameter[at + 1..]),
+                None => (parameter, ""),
+            };
+            if name.is_empty() {
+                return None;
+            }
+            if name.eq_ignore_ascii_case("crs") && index == 0 {
+                position.crs = Some(value.to_string()).filter(|x| !x.is_empty());
+                position.crs.as_ref()?;
+            } else if name.eq_ignore_ascii_case("u") && position.uncertainty.is_none() {
+                position.uncertainty = Some(number(value)?).filter(|x| *x >= 0.0);
+                position.uncertainty?;
+            } else {
+                position.parameters.push((name.to_string(), value.to_string()));
+            }
+        }
+        Some(position).filter(GeoPosition::in_range)
+    }
+
+    /// Whether the reference system is WGS-84, the only one with known
+    /// bounds.
+    pub fn is_wgs84(&self) -> bool {
+        match &self.crs {
+            Some(crs) => crs.eq_ignore_ascii_case("wgs84"),
+            None => true,
+        }
+    }
+
+    /// The great circle distance to `other` in meters by the haversine
+    /// formula, ignoring altitude. Both positions are taken as WGS-84.
+    pub fn distance(&self, other: &GeoPosition) -> f64 {
+        let (latitude, other_latitude) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let half_latitude = (other_latitude - latitude) / 2.0;
+        let half_longitude = (other.longitude - self.longitude).to_radians() / 2.0;
+        let a = half_latitude.sin().powi(2)
+            + latitude.cos() * other_latitude.cos() * half_longitude.sin().powi(2);
+        2.0 * EARTH_RADIUS * a.sqrt().min(1.0).asin()
+    }
+
+    fn in_range(&self) -> bool {
+        !self.is_wgs84()
+            || ((-90.0..=90.0).contains(&self.latitude)
+                && (-180.0..=180.0).contains(&self.longitude))
+    }
+}
+
+/// An area between two latitudes and two longitudes. A box whose west edge
+/// is east of its east edge crosses the antimeridian.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+pub struct BoundingBox {
+    pub south: f64,
+    pub west: f64,
+    pub north: f64,
+    pub east: f64,
+}
+
+impl BoundingBox {
+    /// Whether `position` lies inside the box or on its edge.
+    pub fn contains(&self, position: &GeoPosition) -> bool {
+        let longitude = if self.west <= self.east {
+            (self.west..=self.east).contains(&position.longitude)
+        } else {
+            position.longitude >= self.west || position.longitude <= self.east
+        };
+        longitude && (self.south..=self.north).contains(&position.latitude)
+    }
+}
+
+/// A decimal number as written in a `geo:` URI, without exponents or the
+/// names `f64` would otherwise accept.
+fn number(text: &str) -> Option<f64> {
+    let digits = text.strip_prefix(|x| x == '-' || x == '+').unwrap_or(text);
+    if digits.is_empty()
+        || digits.starts_with('.')
+        || !digits.chars().all(|x| x.is_ascii_digit() || x == '.')
+    {
+        return None;
+    }
+    text.parse().ok()
+}
 
 impl fmt::Display for GeoPosition {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}, {}", self.0, self.1)
+        if !self.uri {
+            return write!(f, "{};{}", self.latitude, self.longitude);
+        }
+        write!(f, "geo:{},{}", self.latitude, self.longitude)?;
+        if let Some(altitude) = self.altitude {
+            write!(f, ",{}", altitude)?;
+        }
+        if let Some(crs) = &self.crs {
+            write!(f, ";crs={}", crs)?;
+        }
+        if let Some(uncertainty) = self.uncertainty {
+            write!(f, ";u={}", uncertainty)?;
+        }
+        for (name, value) in &self.parameters {
+            match value.as_str() {
+                "" => write!(f, ";{}", name)?,
+                value => write!(f, ";{}={}", name, value)?,
+            }
+        }
+        Ok(())
     }
 }
 
-impl<'a> Parse<'a> for GeoPosition {
-    fn parse(input: &'a str) -> IResult<&'a str, GeoPosition, ParseError> {
-        parse_geo_position(input).map_err(|c| match c {
-            nom::Err::Error(err) => nom::Err::Error(crate::parse::to_parse_error(input, err)),
-            nom::Err::Failure(err) => nom::Err::Failure(crate::parse::to_parse_error(input, err)),
-            nom::Err::Incomplete(n) => nom::Err::Incomplete(n),
-        })
+impl IntoOwned for GeoPosition {
+    type Owned = GeoPosition;
+
+    fn into_owned(self) -> Self::Owned {
+        self
     }
 }
 
-named!(parse_geo_position<&str, GeoPosition>, do_parse!(
-    x: parse_to!(f64) >>
-    tag!(",") >>
-    y: parse_to!(f64) >>
-    (GeoPosition(x, y))
-));
+impl<'a> Parse<'a> for GeoPosition {
+    fn parse(input: &'a str) -> IResult<&'a str, GeoPosition, ParseError<'a>> {
+        let (remains, value) = parse_value(input).map_err(|c| c.map(ParseError))?;
+        match GeoPosition::from_text(&value) {
+            Some(value) => Ok((remains, value)),
+            None => {
+                let error = VerboseError {
+                    errors: vec![(input, VerboseErrorKind::Context("Failed to parse geo position"))],
+                };
+                Err(nom::Err::Error(ParseError(error)))
+            }
+        }
+    }
+}
@@ -4,14 +4,14 @@ use nom::error::VerboseError;
 use std::borrow::Cow;
 use std::fmt;
 
-use super::{parse_url, Url};
-use crate::{Parse, ParseError};
+use super::Uri;
+use crate::{IntoOwned, Parse, ParseError};
 use crate::parse::parse_value;
 
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Image<'a> {
-    Url(Url<'a>),
+    Uri(Uri<'a>),
     Data {
         ty: Cow<'a, str>,
         encoding: Cow<'a, str>,
@@ -22,14 +22,29 @@ pub enum Image<'a> {
 impl<'a> fmt::Display for Image<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Image::Url(url) => write!(f, "{}", url),
+            Image::Uri(uri) => write!(f, "{}", uri),
             Image::Data { ty, encoding, data } => write!(f, "data:{};{},{}", ty, encoding, data),
         }
     }
 }
 
+impl<'a> IntoOwned for Image<'a> {
+    type Owned = Image<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            Image::Uri(uri) => Image::Uri(uri.into_owned()),
+            Image::Data { ty, encoding, data } => Image::Data {
+                ty: IntoOwned::into_owned(ty),
+                encoding: IntoOwned::into_owned(encoding),
+                data: IntoOwned::into_owned(data),
+            },
+        }
+    }
+}
+
 impl<'a> Parse<'a> for Image<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Image<'_>, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Image<'a>, ParseError<'a>> {
         parse_image(input).map_err(|c| match c {
             nom::Err::Error(err) => nom::Err::Error(err.into()),
             nom::Err::Failure(err) => nom::Err::Failure(err.into()),
@@ -40,13 +55,13 @@ impl<'a> Parse<'a> for Image<'a> {
 
 named!(parse_image<&str, Image<'_>, VerboseError<&str>>, alt!(
     parse_data_image |
-    parse_url_image
+    parse_uri_image
 ));
 
-named!(parse_url_image<&str, Image<'_>, VerboseError<&str>>, do_parse!(
-    url: parse_url >>
-    (Image::Url(url))
-));
+fn parse_uri_image(input: &str) -> IResult<&str, Image<'_>, VerboseError<&str>> {
+    let (remains, uri) = Uri::parse(input).map_err(|c| c.map(|x| x.0))?;
+    Ok((remains, Image::Uri(uri)))
+}
 
 named!(parse_data_image<&str, Image<'_>, VerboseError<&str>>, do_parse!(
     tag!("data:") >>
@@ -55,5 +70,5 @@ named!(parse_data_image<&str, Image<'_>, VerboseError<&str>>, do_parse!(
     encoding: take_until!(",") >>
     tag!(",") >>
     data: parse_value >>
-    (Image::Data { ty: ty.into(), encoding: encoding.into(), data: data.into() })
+    (Image::Data { ty: ty.into(), encoding: encoding.into(), data })
 ));
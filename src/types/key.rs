@@ -0,0 +1,223 @@
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
+
+use std::borrow::Cow;
+use std::fmt;
+
+use super::Uri;
+use crate::parse::{parse_value, Parse, ParseError};
+use crate::IntoOwned;
+
+#[cfg(feature = "key")]
+use super::{Certificate, OpenPgpKey};
+#[cfg(feature = "key")]
+use chrono::{DateTime, Utc};
+
+/// A `KEY` value: a URL to fetch the key from, a `data:` URI holding it,
+/// or the base64 of a vCard 3 `KEY;ENCODING=b` value.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Key<'a> {
+    Uri(Uri<'a>),
+    Data {
+        /// The media type with its parameters, such as `application/pgp-keys`,
+        /// empty if the URI has none.
+        media_type: Cow<'a, str>,
+        base64: bool,
+        data: Cow<'a, str>,
+    },
+    Binary(Cow<'a, str>),
+}
+
+/// What [`Key::inspect`] found in the payload of a key.
+#[cfg(feature = "key")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyInfo {
+    OpenPgp(OpenPgpKey),
+    X509(Certificate),
+}
+
+impl<'a> Key<'a> {
+    /// Read a key from the text of a value, `None` if it is neither a URI
+    /// nor base64.
+    pub fn from_text(text: &'a str) -> Option<Key<'a>> {
+        if let Some(rest) = strip_prefix_ignore_case(text, "data:") {
+            let (header, data) = rest.split_at(rest.find(',')?);
+            let (media_type, base64) = match strip_suffix_ignore_case(header, ";base64") {
+                Some(media_type) => (media_type, true),
+                None => (header, false),
+            };
+            return Some(Key::Data {
+                media_type: media_type.into(),
+                base64,
+                data: data[1..].into(),
+            });
+        }
+        if let Some(uri) = Uri::from_text(text) {
+            return Some(Key::Uri(uri));
+        }
+        let base64 = |x: char| x.is_ascii_alphanumeric() || "+/=".contains(x);
+        if !text.is_empty() && text.chars().all(base64) {
+            return Some(Key::Binary(text.into()));
+        }
+        None
+    }
+}
+
+#[cfg(feature = "key")]
+impl<'a> Key<'a> {
+    /// The key itself, with any ASCII armor removed. `None` for a URL,
+    /// which has to be fetched, or a payload that does not decode.
+    pub fn bytes(&self) -> Option<Vec<u8>> {
+        let bytes = match self {
+            Key::Uri(_) => return None,
+            Key::Data {
+                base64: true, data, ..
+            }
+            | Key::Binary(data) => decode_base64(data)?,
+            Key::Data { data, .. } => super::uri::percent_decode(data)?.into_owned().into_bytes(),
+        };
+        match std::str::from_utf8(&bytes) {
+            Ok(text) if text.trim_start().starts_with("-----BEGIN ") => dearmor(text),
+            _ => Some(bytes),
+        }
+    }
+
+    /// Detect the format of the key and read what it says about itself,
+    /// `None` for a URL or a payload that is neither an OpenPGP public key
+    /// nor an X.509 certificate.
+    pub fn inspect(&self) -> Option<KeyInfo> {
+        let bytes = self.bytes()?;
+        Certificate::from_der(&bytes)
+            .map(KeyInfo::X509)
+            .or_else(|| OpenPgpKey::from_bytes(&bytes).map(KeyInfo::OpenPgp))
+    }
+}
+
+#[cfg(feature = "key")]
+impl KeyInfo {
+    /// The fingerprint in upper case hex: the OpenPGP one, or the SHA-1 of
+    /// the certificate.
+    pub fn fingerprint(&self) -> Option<&str> {
+        match self {
+            KeyInfo::OpenPgp(key) => key.fingerprint.as_deref(),
+            KeyInfo::X509(certificate) => Some(&certificate.fingerprint),
+        }
+    }
+
+    pub fn expires(&self) -> Option<DateTime<Utc>> {
+        match self {
+            KeyInfo::OpenPgp(key) => key.expires,
+            KeyInfo::X509(certificate) => Some(certificate.not_after),
+        }
+    }
+}
+
+#[cfg(feature = "key")]
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+
+    let text: String = text.chars().filter(|x| !x.is_whitespace()).collect();
+    STANDARD.decode(text).ok()
+}
+
+/// Upper case hex, as fingerprints are shown.
+#[cfg(feature = "key")]
+pub(super) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|x| format!("{:02X}", x)).collect()
+}
+
+/// The payload of the first block of ASCII armor such as
+/// `-----BEGIN PGP PUBLIC KEY BLOCK-----`, without its headers and the
+/// OpenPGP checksum line.
+#[cfg(feature = "key")]
+fn dearmor(text: &str) -> Option<Vec<u8>> {
+    let mut lines = text
+        .lines()
+        .map(str::trim)
+        .skip_while(|x| !x.starts_with("-----BEGIN "));
+    lines.next()?;
+    let body: String = lines
+        .take_while(|x| !x.starts_with("-----END "))
+        .filter(|x| !x.contains(':') && !x.starts_with('='))
+        .collect();
+    decode_base64(&body)
+}
+
+fn strip_prefix_ignore_case<'t>(text: &'t str, prefix: &str) -> Option<&'t str> {
+    let head = text.get(..prefix.len())?;
+    if head.eq_ignore_ascii_case(prefix) {
+        Some(&text[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+fn strip_suffix_ignore_case<'t>(text: &'t str, suffix: &str) -> Option<&'t str> {
+    let start = text.len().checked_sub(suffix.len())?;
+    if text.get(start..)?.eq_ignore_ascii_case(suffix) {
+        Some(&text[..start])
+    } else {
+        None
+    }
+}
+
+impl<'a> fmt::Display for Key<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Key::Uri(uri) => write!(f, "{}", uri),
+            Key::Data {
+                media_type,
+                base64: true,
+                data,
+            } => {
+                write!(f, "data:{};base64,{}", media_type, data)
+            }
+            Key::Data {
+                media_type, data, ..
+            } => write!(f, "data:{},{}", media_type, data),
+            Key::Binary(data) => write!(f, "{}", data),
+        }
+    }
+}
+
+impl<'a> IntoOwned for Key<'a> {
+    type Owned = Key<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            Key::Uri(uri) => Key::Uri(uri.into_owned()),
+            Key::Data {
+                media_type,
+                base64,
+                data,
+            } => Key::Data {
+                media_type: IntoOwned::into_owned(media_type),
+                base64,
+                data: IntoOwned::into_owned(data),
+            },
+            Key::Binary(data) => Key::Binary(IntoOwned::into_owned(data)),
+        }
+    }
+}
+
+impl<'a> Parse<'a> for Key<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, Key<'a>, ParseError<'a>> {
+        let (remains, value) = parse_value(input).map_err(|c| c.map(ParseError))?;
+        let value = match value {
+            Cow::Borrowed(value) => Key::from_text(value),
+            Cow::Owned(value) => Key::from_text(&value).map(IntoOwned::into_owned),
+        };
+        match value {
+            Some(value) => Ok((remains, value)),
+            None => {
+                let error = VerboseError {
+                    errors: vec![(input, VerboseErrorKind::Context("Failed to parse key"))],
+                };
+                Err(nom::Err::Error(ParseError(error)))
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use nom::{alt, named, tag, IResult};
 use std::fmt;
 
 use crate::parse::{Parse, ParseError};
+use crate::IntoOwned;
 
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -24,8 +25,16 @@ impl fmt::Display for Kind {
     }
 }
 
+impl IntoOwned for Kind {
+    type Owned = Kind;
+
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+}
+
 impl<'a> Parse<'a> for Kind {
-    fn parse(input: &'a str) -> IResult<&'a str, Kind, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Kind, ParseError<'a>> {
         parse_kind(input).map_err(|c| match c {
             nom::Err::Error(err) => nom::Err::Error(crate::parse::to_parse_error(input, err)),
             nom::Err::Failure(err) => nom::Err::Failure(crate::parse::to_parse_error(input, err)),
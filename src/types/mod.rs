@@ -1,5 +1,5 @@
 mod geo;
-pub use self::geo::GeoPosition;
+pub use self::geo::{BoundingBox, GeoPosition};
 
 mod name;
 pub use self::name::FormattedName;
@@ -7,17 +7,50 @@ pub use self::name::FormattedName;
 mod address;
 pub use self::address::Address;
 
+mod organization;
+pub use self::organization::Organization;
+
 mod email;
 pub use self::email::Email;
 
-mod url;
-pub use self::url::{parse_url, Url};
+mod uri;
+pub use self::uri::{Authority, Uri};
 
 mod image;
 pub use self::image::Image;
 
+mod key;
+pub use self::key::Key;
+#[cfg(feature = "key")]
+pub use self::key::KeyInfo;
+
+#[cfg(feature = "key")]
+mod openpgp;
+#[cfg(feature = "key")]
+pub use self::openpgp::OpenPgpKey;
+
+#[cfg(feature = "key")]
+mod x509;
+#[cfg(feature = "key")]
+pub use self::x509::Certificate;
+
 mod kind;
 pub use self::kind::Kind;
 
 mod gender;
 pub use self::gender::{Gender, Sex};
+
+mod date;
+pub use self::date::{Date, DateAndOrTime, Time, Zone};
+
+mod timestamp;
+pub use self::timestamp::Timestamp;
+
+mod timezone;
+pub use self::timezone::TimeZone;
+
+mod social;
+pub use self::social::{Service, SocialProfile};
+
+mod telephone;
+pub use self::telephone::{Telephone, TelephoneType};
@@ -3,7 +3,7 @@ use nom::{do_parse, named, opt, separated_list0, tag, IResult};
 use std::borrow::Cow;
 use std::fmt;
 
-use crate::{Parse, ParseError};
+use crate::{IntoOwned, Parse, ParseError};
 
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -35,8 +35,22 @@ impl<'a> fmt::Display for FormattedName<'a> {
     }
 }
 
+impl<'a> IntoOwned for FormattedName<'a> {
+    type Owned = FormattedName<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        FormattedName {
+            surname: self.surname.into_owned(),
+            given: self.given.into_owned(),
+            additional: self.additional.into_owned(),
+            prefix: self.prefix.into_owned(),
+            suffix: self.suffix.into_owned(),
+        }
+    }
+}
+
 impl<'a> Parse<'a> for FormattedName<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, FormattedName, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, FormattedName<'a>, ParseError<'a>> {
         parse_formatted_name(input).map_err(|c| match c {
             nom::Err::Error(err) => nom::Err::Error(crate::parse::to_parse_error(input, err)),
             nom::Err::Failure(err) => nom::Err::Failure(crate::parse::to_parse_error(input, err)),
@@ -45,7 +59,7 @@ impl<'a> Parse<'a> for FormattedName<'a> {
     }
 }
 
-named!(parse_formatted_name<&str, FormattedName>, do_parse!(
+named!(parse_formatted_name<&str, FormattedName<'_>>, do_parse!(
     initial: opt!(parse_formatted_name_list) >>
     tag!(";") >>
     names: opt!(parse_formatted_name_list) >>
@@ -65,7 +79,7 @@ named!(parse_formatted_name<&str, FormattedName>, do_parse!(
 ));
 
 fn unwrap_vec<T>(arg: Option<Vec<T>>) -> Vec<T> {
-    arg.unwrap_or_else(Vec::new)
+    arg.unwrap_or_default()
 }
 
 named!(parse_formatted_name_list<&str, Vec<Cow<'_, str>>>, do_parse!(
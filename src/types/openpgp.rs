@@ -0,0 +1,179 @@
+use chrono::{DateTime, TimeZone, Utc};
+use sha1::{Digest, Sha1};
+
+use std::convert::{TryFrom, TryInto};
+
+use super::key::hex;
+
+/// What the primary key of an OpenPGP transferable public key says about
+/// itself, read by [`Key::inspect`](super::Key::inspect).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpenPgpKey {
+    pub version: u8,
+    /// The fingerprint in upper case hex, only known for version 4 keys.
+    pub fingerprint: Option<String>,
+    /// The low 64 bits of the fingerprint in upper case hex.
+    pub key_id: Option<String>,
+    pub created: DateTime<Utc>,
+    /// When the key expires, from its latest self-signature.
+    pub expires: Option<DateTime<Utc>>,
+    pub user_ids: Vec<String>,
+}
+
+/// Packet tags of RFC 4880.
+const SIGNATURE: u8 = 2;
+const PUBLIC_KEY: u8 = 6;
+const USER_ID: u8 = 13;
+const PUBLIC_SUBKEY: u8 = 14;
+
+impl OpenPgpKey {
+    /// Read the unarmored packets of a public key, `None` if they do not
+    /// start with a public key packet.
+    pub fn from_bytes(bytes: &[u8]) -> Option<OpenPgpKey> {
+        let (tag, body, mut rest) = packet(bytes)?;
+        if tag != PUBLIC_KEY {
+            return None;
+        }
+        let version = *body.first()?;
+        let created = u32::from_be_bytes(body.get(1..5)?.try_into().ok()?);
+        let fingerprint = match version {
+            4 => {
+                let mut data = vec![0x99];
+                data.extend_from_slice(&u16::try_from(body.len()).ok()?.to_be_bytes());
+                data.extend_from_slice(body);
+                Some(Sha1::digest(&data))
+            }
+            _ => None,
+        };
+        let key_id = fingerprint.map(|x| x[12..].to_vec());
+        let mut user_ids = vec![];
+        let mut latest: Option<(u32, Option<u32>)> = None;
+        // Packets after the first subkey are about the subkeys.
+        while let Some((tag, body, next)) = packet(rest) {
+            rest = next;
+            match tag {
+                USER_ID => user_ids.push(String::from_utf8_lossy(body).into_owned()),
+                SIGNATURE => match (self_signature(body, key_id.as_deref()), latest) {
+                    (Some(signature), Some(current)) if signature.0 < current.0 => {}
+                    (Some(signature), _) => latest = Some(signature),
+                    (None, _) => {}
+                },
+                PUBLIC_SUBKEY => break,
+                _ => {}
+            }
+        }
+        let expires = match latest.and_then(|(_, expires)| expires) {
+            Some(0) | None => None,
+            Some(seconds) => timestamp(created as i64 + seconds as i64),
+        };
+        Some(OpenPgpKey {
+            version,
+            fingerprint: fingerprint.map(|x| hex(&x)),
+            key_id: key_id.map(|x| hex(&x)),
+            created: timestamp(created as i64)?,
+            expires,
+            user_ids,
+        })
+    }
+}
+
+fn timestamp(seconds: i64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(seconds, 0).single()
+}
+
+/// The tag and body of the packet at the start of `bytes`, and the bytes
+/// after it.
+fn packet(bytes: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&header, rest) = bytes.split_first()?;
+    if header & 0x80 == 0 {
+        return None;
+    }
+    let (tag, length, rest) = if header & 0x40 != 0 {
+        let (length, rest) = match rest.split_first()? {
+            (&first, rest) if first < 192 => (first as usize, rest),
+            (&first, rest) if first < 224 => {
+                let (&second, rest) = rest.split_first()?;
+                (((first as usize - 192) << 8) + second as usize + 192, rest)
+            }
+            (255, rest) => (
+                u32::from_be_bytes(rest.get(..4)?.try_into().ok()?) as usize,
+                &rest[4..],
+            ),
+            // Partial lengths are not used for key packets.
+            _ => return None,
+        };
+        (header & 0x3f, length, rest)
+    } else {
+        let (length, rest) = match header & 0x03 {
+            0 => (*rest.first()? as usize, &rest[1..]),
+            1 => (
+                u16::from_be_bytes(rest.get(..2)?.try_into().ok()?) as usize,
+                &rest[2..],
+            ),
+            2 => (
+                u32::from_be_bytes(rest.get(..4)?.try_into().ok()?) as usize,
+                &rest[4..],
+            ),
+            _ => (rest.len(), rest),
+        };
+        ((header >> 2) & 0x0f, length, rest)
+    };
+    Some((tag, rest.get(..length)?, &rest[length..]))
+}
+
+/// The creation time and key expiration time of a version 4 certification
+/// or direct key signature, if it is not issued by another key.
+fn self_signature(body: &[u8], key_id: Option<&[u8]>) -> Option<(u32, Option<u32>)> {
+    if body.first() != Some(&4) || !matches!(body.get(1)?, 0x10..=0x13 | 0x1f) {
+        return None;
+    }
+    let length = u16::from_be_bytes(body.get(4..6)?.try_into().ok()?) as usize;
+    let hashed = body.get(6..6 + length)?;
+    let rest = &body[6 + length..];
+    let length = u16::from_be_bytes(rest.get(..2)?.try_into().ok()?) as usize;
+    let unhashed = rest.get(2..2 + length)?;
+
+    let (mut created, mut expires, mut issuer) = (None, None, None);
+    for (ty, data) in subpackets(hashed)? {
+        match ty {
+            2 => created = Some(u32::from_be_bytes(data.try_into().ok()?)),
+            9 => expires = Some(u32::from_be_bytes(data.try_into().ok()?)),
+            _ => {}
+        }
+    }
+    for (ty, data) in subpackets(hashed)?.into_iter().chain(subpackets(unhashed)?) {
+        match ty {
+            16 => issuer = Some(data),
+            33 if data.len() > 8 => issuer = Some(&data[data.len() - 8..]),
+            _ => {}
+        }
+    }
+    match (issuer, key_id) {
+        (Some(issuer), Some(key_id)) if issuer != key_id => None,
+        _ => Some((created?, expires)),
+    }
+}
+
+/// The types, without the critical bit, and data of signature subpackets.
+fn subpackets(mut bytes: &[u8]) -> Option<Vec<(u8, &[u8])>> {
+    let mut subpackets = vec![];
+    while let Some((&first, rest)) = bytes.split_first() {
+        let (length, rest) = match first {
+            0..=191 => (first as usize, rest),
+            192..=254 => {
+                let (&second, rest) = rest.split_first()?;
+                (((first as usize - 192) << 8) + second as usize + 192, rest)
+            }
+            255 => (
+                u32::from_be_bytes(rest.get(..4)?.try_into().ok()?) as usize,
+                &rest[4..],
+            ),
+        };
+        let subpacket = rest.get(..length)?;
+        let (&ty, data) = subpacket.split_first()?;
+        subpackets.push((ty & 0x7f, data));
+        bytes = &rest[length..];
+    }
+    Some(subpackets)
+}
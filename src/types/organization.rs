@@ -0,0 +1,88 @@
+use nom::IResult;
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::parse::{escape_text, parse_value, split_unescaped, unescape_text, Parse, ParseError};
+use crate::IntoOwned;
+
+/// An `ORG` value such as `ABC\, Inc.;North American Division;Marketing`,
+/// the name of the organization followed by its units from the largest
+/// down. The parts are kept unescaped.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+pub struct Organization<'a> {
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    pub name: Cow<'a, str>,
+    #[cfg_attr(feature = "typed-builder", builder(default))]
+    pub units: Vec<Cow<'a, str>>,
+}
+
+impl<'a> Organization<'a> {
+    /// Read an organization from the text of a value, leaving out empty
+    /// trailing units such as the one of `ORG:ABC\, Inc.;`.
+    pub fn from_text(text: &'a str) -> Organization<'a> {
+        let mut parts = split_unescaped(text, ';').into_iter().map(unescape);
+        let name = parts.next().unwrap_or_default();
+        let mut units: Vec<_> = parts.collect();
+        while units.last().is_some_and(|x| x.is_empty()) {
+            units.pop();
+        }
+        Organization { name, units }
+    }
+
+    /// The name followed by the units.
+    pub fn parts(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_ref()).chain(self.units.iter().map(|x| x.as_ref()))
+    }
+
+    /// The parts to sort by, each replaced by the matching value of the
+    /// `SORT-AS` parameter if it has a non-empty one.
+    pub fn sort_keys(&self, sort_as: &[String]) -> Vec<String> {
+        self.parts()
+            .enumerate()
+            .map(|(index, part)| match sort_as.get(index) {
+                Some(key) if !key.is_empty() => key.clone(),
+                _ => part.to_string(),
+            })
+            .collect()
+    }
+}
+
+fn unescape(text: &str) -> Cow<'_, str> {
+    if text.contains('\\') {
+        Cow::Owned(unescape_text(text))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+impl<'a> fmt::Display for Organization<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parts = self.parts().map(escape_text).collect::<Vec<_>>();
+        write!(f, "{}", parts.join(";"))
+    }
+}
+
+impl<'a> IntoOwned for Organization<'a> {
+    type Owned = Organization<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Organization {
+            name: IntoOwned::into_owned(self.name),
+            units: self.units.into_owned(),
+        }
+    }
+}
+
+impl<'a> Parse<'a> for Organization<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, Organization<'a>, ParseError<'a>> {
+        let (remains, value) = parse_value(input).map_err(|c| c.map(ParseError))?;
+        let value = match value {
+            Cow::Borrowed(value) => Organization::from_text(value),
+            Cow::Owned(value) => Organization::from_text(&value).into_owned(),
+        };
+        Ok((remains, value))
+    }
+}
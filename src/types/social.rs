@@ -0,0 +1,360 @@
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
+
+use std::borrow::Cow;
+use std::fmt;
+
+use super::Uri;
+use crate::parse::{parse_value, Parse, ParseError};
+use crate::IntoOwned;
+
+/// An `IMPP` or social profile value, read from URIs such as
+/// `xmpp:alice@example.com` and `https://twitter.com/alice` or handles
+/// such as `@alice@mastodon.social`. It is written back as it was read
+/// until its fields are changed, [`SocialProfile::uri`] and
+/// [`SocialProfile::handle`] give its canonical forms.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+pub struct SocialProfile<'a> {
+    pub service: Service,
+    /// The user on the service, without a leading `@` or the domain.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    pub username: Cow<'a, str>,
+    /// The server of federated services such as xmpp, sip, matrix and
+    /// mastodon.
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option, into), default))]
+    pub domain: Option<Cow<'a, str>>,
+    /// The value as it was read, `None` for a profile made in code. It is
+    /// only written while the service, username and domain still read from
+    /// it, otherwise the profile is written as its canonical URI.
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option, into), default))]
+    pub text: Option<Cow<'a, str>>,
+}
+
+/// The messaging or social service of a [`SocialProfile`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Service {
+    Xmpp,
+    Sip,
+    Skype,
+    Matrix,
+    Aim,
+    Mastodon,
+    Twitter,
+    LinkedIn,
+    Facebook,
+    GitHub,
+    Instagram,
+    /// A service without a canonical URI form, named in lowercase.
+    Other(String),
+}
+
+impl Service {
+    /// The service named by a `TYPE` or `SERVICE-TYPE` value, ignoring
+    /// case.
+    pub fn from_name(name: &str) -> Service {
+        match name.to_ascii_lowercase().as_str() {
+            "xmpp" | "jabber" | "gtalk" => Service::Xmpp,
+            "sip" => Service::Sip,
+            "skype" => Service::Skype,
+            "matrix" => Service::Matrix,
+            "aim" => Service::Aim,
+            "mastodon" => Service::Mastodon,
+            "twitter" | "x" => Service::Twitter,
+            "linkedin" => Service::LinkedIn,
+            "facebook" => Service::Facebook,
+            "github" => Service::GitHub,
+            "instagram" => Service::Instagram,
+            other => Service::Other(other.into()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Service::Xmpp => "xmpp",
+            Service::Sip => "sip",
+            Service::Skype => "skype",
+            Service::Matrix => "matrix",
+            Service::Aim => "aim",
+            Service::Mastodon => "mastodon",
+            Service::Twitter => "twitter",
+            Service::LinkedIn => "linkedin",
+            Service::Facebook => "facebook",
+            Service::GitHub => "github",
+            Service::Instagram => "instagram",
+            Service::Other(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for Service {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl<'a> SocialProfile<'a> {
+    /// Read a profile from a value that names its service, a URI of a
+    /// known scheme or site or a mastodon or matrix handle.
+    pub fn from_text(text: &'a str) -> Option<SocialProfile<'a>> {
+        read(text).map(|x| x.with_text(text))
+    }
+
+    /// Read a profile on `service`, named by a parameter such as
+    /// `SERVICE-TYPE`, from a value that may be a bare username or an
+    /// Apple `x-apple:` one. Values naming their own service are read as
+    /// in [`SocialProfile::from_text`].
+    pub fn with_service(service: &str, text: &'a str) -> Option<SocialProfile<'a>> {
+        read(text)
+            .or_else(|| read_with_service(service, text))
+            .map(|x| x.with_text(text))
+    }
+
+    fn with_text(self, text: &'a str) -> SocialProfile<'a> {
+        SocialProfile {
+            text: Some(text.into()),
+            ..self
+        }
+    }
+
+    /// The text the profile was read from, if its fields still match it.
+    fn current_text(&self) -> Option<&str> {
+        let text = self.text.as_deref()?;
+        let read = read(text).or_else(|| read_with_service(self.service.name(), text))?;
+        let matches = read.service == self.service
+            && read.username == self.username
+            && read.domain == self.domain;
+        Some(text).filter(|_| matches)
+    }
+
+    /// The canonical URI of the profile, such as `xmpp:alice@example.com`
+    /// or `https://mastodon.social/@alice`. Services without a URI form
+    /// have none.
+    pub fn uri(&self) -> Option<String> {
+        let username = &self.username;
+        let domain = self.domain.as_deref().unwrap_or_default();
+        let uri = match self.service {
+            Service::Xmpp => format!("xmpp:{}@{}", username, domain),
+            Service::Sip => format!("sip:{}@{}", username, domain),
+            Service::Skype => format!("skype:{}", username),
+            Service::Matrix => format!("matrix:u/{}:{}", username, domain),
+            Service::Aim => format!("aim:goim?screenname={}", username),
+            Service::Mastodon => format!("https://{}/@{}", domain, username),
+            Service::Twitter => format!("https://twitter.com/{}", username),
+            Service::LinkedIn => format!("https://www.linkedin.com/in/{}", username),
+            Service::Facebook => format!("https://www.facebook.com/{}", username),
+            Service::GitHub => format!("https://github.com/{}", username),
+            Service::Instagram => format!("https://www.instagram.com/{}", username),
+            Service::Other(_) => return None,
+        };
+        Some(uri)
+    }
+
+    /// The username as people write it on the service, such as
+    /// `@alice@mastodon.social`, `@alice:matrix.org` or `@alice`.
+    pub fn handle(&self) -> String {
+        let domain = self.domain.as_deref().unwrap_or_default();
+        match self.service {
+            Service::Xmpp | Service::Sip => format!("{}@{}", self.username, domain),
+            Service::Matrix => format!("@{}:{}", self.username, domain),
+            Service::Mastodon => format!("@{}@{}", self.username, domain),
+            Service::Twitter | Service::Instagram | Service::GitHub => {
+                format!("@{}", self.username)
+            }
+            _ => self.username.to_string(),
+        }
+    }
+}
+
+/// A profile from a value that names its service, without its text.
+fn read(text: &str) -> Option<SocialProfile<'_>> {
+    if let Some(handle) = text.strip_prefix('@') {
+        return match split(handle, '@') {
+            Some((username, domain)) => Some(profile(Service::Mastodon, username, Some(domain))),
+            None => from_matrix_id(handle),
+        };
+    }
+    Uri::from_text(text)?;
+    let at = text.find(':')?;
+    let rest = &text[at + 1..];
+    match text[..at].to_ascii_lowercase().as_str() {
+        "xmpp" => {
+            // An authority names the account to send from, not the
+            // contact.
+            let rest = match rest.strip_prefix("//") {
+                Some(rest) => &rest[rest.find('/')? + 1..],
+                None => rest,
+            };
+            let (username, domain) = split(until(rest, "?"), '@')?;
+            Some(profile(Service::Xmpp, username, Some(domain)))
+        }
+        "sip" | "sips" => {
+            let (username, domain) = split(until(rest, ";?"), '@')?;
+            Some(profile(Service::Sip, username, Some(domain)))
+        }
+        "skype" => {
+            let username = Some(until(rest, "?")).filter(|x| valid_username(x))?;
+            Some(profile(Service::Skype, username, None))
+        }
+        "matrix" => {
+            let id = until(rest, "?");
+            let user = id.strip_prefix("u/").or_else(|| id.strip_prefix("user/"))?;
+            from_matrix_id(user)
+        }
+        "aim" => {
+            let query = &rest[rest.find('?')? + 1..];
+            let username = query.split('&').find_map(|x| {
+                let (name, value) = split(x, '=')?;
+                Some(value).filter(|_| name.eq_ignore_ascii_case("screenname"))
+            })?;
+            Some(profile(Service::Aim, username, None))
+        }
+        "http" | "https" => from_url(rest.strip_prefix("//")?),
+        _ => None,
+    }
+}
+
+/// A profile on `service` from a bare username or an Apple `x-apple:` one,
+/// without its text.
+fn read_with_service<'a>(service: &str, text: &'a str) -> Option<SocialProfile<'a>> {
+    let text = match text.get(..8) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("x-apple:") => &text[8..],
+        _ => text,
+    };
+    let handle = text.strip_prefix('@').unwrap_or(text);
+    match Service::from_name(service) {
+        Service::Mastodon => {
+            let (username, domain) = match text.get(..8) {
+                Some(scheme) if scheme.eq_ignore_ascii_case("https://") => {
+                    let (domain, path) = split(&text[8..], '/')?;
+                    (path.strip_prefix('@')?, domain)
+                }
+                _ => split(handle, '@')?,
+            };
+            Some(profile(Service::Mastodon, username, Some(domain)))
+        }
+        Service::Xmpp => {
+            let (username, domain) = split(handle, '@')?;
+            Some(profile(Service::Xmpp, username, Some(domain)))
+        }
+        Service::Sip => {
+            let (username, domain) = split(handle, '@')?;
+            Some(profile(Service::Sip, username, Some(domain)))
+        }
+        Service::Matrix => from_matrix_id(handle),
+        service => {
+            let username = Some(handle).filter(|x| valid_username(x))?;
+            Some(profile(service, username, None))
+        }
+    }
+}
+
+fn profile<'a>(service: Service, username: &'a str, domain: Option<&'a str>) -> SocialProfile<'a> {
+    SocialProfile {
+        service,
+        username: username.into(),
+        domain: domain.map(|x| x.to_ascii_lowercase().into()),
+        text: None,
+    }
+}
+
+/// A matrix user id such as `alice:matrix.org`, without its `@`.
+fn from_matrix_id(id: &str) -> Option<SocialProfile<'_>> {
+    let (username, domain) = split(id, ':')?;
+    Some(profile(Service::Matrix, username, Some(domain)))
+}
+
+/// A profile page such as `twitter.com/alice`, the URL after its `//`.
+fn from_url(url: &str) -> Option<SocialProfile<'_>> {
+    let (host, path) = split(url, '/').unwrap_or((url, ""));
+    let host = host.to_ascii_lowercase();
+    let host = ["www.", "mobile.", "m."]
+        .iter()
+        .find_map(|x| host.strip_prefix(x))
+        .unwrap_or(&host);
+    if host == "matrix.to" {
+        let id = url.find("#/@").map(|at| &url[at + 3..])?;
+        return from_matrix_id(until(id, "?/"));
+    }
+    let path = until(path, "?#");
+    let mut segments = path.split('/').filter(|x| !x.is_empty());
+    let (service, username) = match host {
+        "twitter.com" | "x.com" => (Service::Twitter, segments.next()?),
+        "linkedin.com" => match segments.next()? {
+            "in" => (Service::LinkedIn, segments.next()?),
+            _ => return None,
+        },
+        "facebook.com" => (Service::Facebook, segments.next()?),
+        "github.com" => (Service::GitHub, segments.next()?),
+        "instagram.com" => (Service::Instagram, segments.next()?),
+        _ => return None,
+    };
+    Some(profile(service, username, None)).filter(|_| valid_username(username))
+}
+
+/// Split `text` at the first `separator` into two non-empty parts.
+fn split(text: &str, separator: char) -> Option<(&str, &str)> {
+    let at = text.find(separator)?;
+    let (first, second) = (&text[..at], &text[at + 1..]);
+    Some((first, second)).filter(|_| !first.is_empty() && !second.is_empty())
+}
+
+/// `text` up to the first of the `ends` characters.
+fn until<'a>(text: &'a str, ends: &str) -> &'a str {
+    let end = text.find(|x| ends.contains(x)).unwrap_or(text.len());
+    &text[..end]
+}
+
+fn valid_username(username: &str) -> bool {
+    !username.is_empty()
+        && !username
+            .chars()
+            .any(|x| x.is_whitespace() || x.is_control() || "/?#@".contains(x))
+}
+
+impl<'a> fmt::Display for SocialProfile<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.current_text(), self.uri()) {
+            (Some(text), _) => write!(f, "{}", text),
+            (None, Some(uri)) => write!(f, "{}", uri),
+            (None, None) => write!(f, "{}", self.username),
+        }
+    }
+}
+
+impl<'a> IntoOwned for SocialProfile<'a> {
+    type Owned = SocialProfile<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        SocialProfile {
+            service: self.service,
+            username: IntoOwned::into_owned(self.username),
+            domain: self.domain.into_owned(),
+            text: self.text.into_owned(),
+        }
+    }
+}
+
+impl<'a> Parse<'a> for SocialProfile<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, SocialProfile<'a>, ParseError<'a>> {
+        let (remains, value) = parse_value(input).map_err(|c| c.map(ParseError))?;
+        let value = match value {
+            Cow::Borrowed(value) => SocialProfile::from_text(value),
+            Cow::Owned(_) => None,
+        };
+        match value {
+            Some(value) => Ok((remains, value)),
+            None => {
+                let error = VerboseError {
+                    errors: vec![(
+                        input,
+                        VerboseErrorKind::Context("Failed to parse social profile"),
+                    )],
+                };
+                Err(nom::Err::Error(ParseError(error)))
+            }
+        }
+    }
+}
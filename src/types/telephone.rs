@@ -0,0 +1,260 @@
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
+
+use std::borrow::Cow;
+use std::fmt;
+
+use super::uri::percent_decode;
+use super::Uri;
+use crate::parse::{parse_value, Parse, ParseError};
+use crate::IntoOwned;
+
+/// A `TEL` value, read from text such as `+1 555 1212 x102` or from a
+/// `tel:` URI such as `tel:+1-555-1212;ext=102` and written back in the
+/// same form.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+pub struct Telephone<'a> {
+    /// The number as written, visual separators included.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    pub number: Cow<'a, str>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option, into), default))]
+    pub extension: Option<Cow<'a, str>>,
+    /// What the text form has between the number and the extension, such
+    /// as ` ext. `, written as ` x` if not set.
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option, into), default))]
+    pub extension_marker: Option<Cow<'a, str>>,
+    /// The ISDN subaddress, the `isub` parameter of a `tel:` URI.
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option, into), default))]
+    pub isdn_subaddress: Option<Cow<'a, str>>,
+    /// Any other `tel:` URI parameters such as `phone-context`, in order.
+    #[cfg_attr(feature = "typed-builder", builder(default))]
+    pub parameters: Vec<(Cow<'a, str>, Option<Cow<'a, str>>)>,
+    /// Written as a `tel:` URI, otherwise as text.
+    #[cfg_attr(feature = "typed-builder", builder(default))]
+    pub uri: bool,
+}
+
+/// What a number is for, from the `TYPE` parameter of a `TEL`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TelephoneType {
+    Text,
+    Voice,
+    Fax,
+    Cell,
+    Video,
+    Pager,
+    TextPhone,
+}
+
+impl TelephoneType {
+    /// The type named by a `TYPE` value, ignoring case.
+    pub fn from_name(name: &str) -> Option<TelephoneType> {
+        let ty = match name.to_ascii_lowercase().as_str() {
+            "text" => TelephoneType::Text,
+            "voice" => TelephoneType::Voice,
+            "fax" => TelephoneType::Fax,
+            "cell" => TelephoneType::Cell,
+            "video" => TelephoneType::Video,
+            "pager" => TelephoneType::Pager,
+            "textphone" => TelephoneType::TextPhone,
+            _ => return None,
+        };
+        Some(ty)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TelephoneType::Text => "text",
+            TelephoneType::Voice => "voice",
+            TelephoneType::Fax => "fax",
+            TelephoneType::Cell => "cell",
+            TelephoneType::Video => "video",
+            TelephoneType::Pager => "pager",
+            TelephoneType::TextPhone => "textphone",
+        }
+    }
+}
+
+impl fmt::Display for TelephoneType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Extension markers of the text form, longest first.
+const EXTENSION_MARKERS: &[&str] = &["extension", "ext.", "ext", "x"];
+
+impl<'a> Telephone<'a> {
+    /// Read a number from the text of a value, as a `tel:` URI or as text.
+    pub fn from_text(text: &'a str) -> Option<Telephone<'a>> {
+        match text.get(..4) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("tel:") => parse_uri(text),
+            _ => parse_text(text),
+        }
+    }
+
+    /// Whether the number is in international form, starting with `+`.
+    pub fn is_global(&self) -> bool {
+        self.number.starts_with('+')
+    }
+
+    /// The number without visual separators or percent-encoding, as a
+    /// dialer takes it.
+    pub fn digits(&self) -> String {
+        let number = percent_decode(&self.number).unwrap_or_default();
+        number
+            .chars()
+            .filter(|x| x.is_ascii_alphanumeric() || "+*#".contains(*x))
+            .collect()
+    }
+}
+
+fn parse_text(text: &str) -> Option<Telephone<'_>> {
+    let trimmed = text.trim_end();
+    let before_digits = trimmed.trim_end_matches(|x: char| x.is_ascii_digit());
+    let marker = EXTENSION_MARKERS.iter().find_map(|marker| {
+        let rest = before_digits.trim_end();
+        let at = rest.len().checked_sub(marker.len())?;
+        Some(rest)
+            .filter(|x| x.is_char_boundary(at) && x[at..].eq_ignore_ascii_case(marker))
+            .map(|x| x[..at].trim_end())
+    });
+    let (number, marker, extension) = match marker {
+        Some(number) if before_digits.len() < trimmed.len() && !number.is_empty() => (
+            number,
+            Some(&trimmed[number.len()..before_digits.len()]),
+            Some(&trimmed[before_digits.len()..]),
+        ),
+        _ => (text, None, None),
+    };
+    if !valid_number(number, " /") {
+        return None;
+    }
+    Some(Telephone {
+        number: number.into(),
+        extension: extension.map(Cow::from),
+        extension_marker: marker.map(Cow::from),
+        isdn_subaddress: None,
+        parameters: vec![],
+        uri: false,
+    })
+}
+
+fn parse_uri(text: &str) -> Option<Telephone<'_>> {
+    let uri = Uri::from_text(text)?;
+    if uri.authority.is_some() || uri.query.is_some() || uri.fragment.is_some() {
+        return None;
+    }
+    let mut parts = text[4..].split(';');
+    let number = parts.next()?;
+    // Local numbers may hold hex digits, and `#` is percent-encoded.
+    if !valid_number(number, "%ABCDEFabcdef") {
+        return None;
+    }
+    let mut telephone = Telephone {
+        number: number.into(),
+        extension: None,
+        extension_marker: None,
+        isdn_subaddress: None,
+        parameters: vec![],
+        uri: true,
+    };
+    for parameter in parts {
+        let (name, value) = match parameter.find('=') {
+            Some(at) => (&parameter[..at], Some(&parameter[at + 1..])),
+            None => (parameter, None),
+        };
+        match (name.to_ascii_lowercase().as_str(), value) {
+            ("", _) => return None,
+            ("ext", Some(value)) if telephone.extension.is_none() => {
+                telephone.extension = Some(value.into())
+            }
+            ("isub", Some(value)) if telephone.isdn_subaddress.is_none() => {
+                telephone.isdn_subaddress = Some(value.into())
+            }
+            _ => telephone
+                .parameters
+                .push((name.into(), value.map(Cow::from))),
+        }
+    }
+    Some(telephone)
+}
+
+/// Whether `number` has a digit and otherwise only the separators of RFC
+/// 3966, a leading `+`, `*`, `#` and the `extra` characters.
+fn valid_number(number: &str, extra: &str) -> bool {
+    let digits = number.strip_prefix('+').unwrap_or(number);
+    digits.chars().any(|x| x.is_ascii_digit())
+        && digits
+            .chars()
+            .all(|x| x.is_ascii_digit() || "-.()*#".contains(x) || extra.contains(x))
+}
+
+impl<'a> fmt::Display for Telephone<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.uri {
+            return match &self.extension {
+                Some(extension) => {
+                    let marker = self.extension_marker.as_deref().unwrap_or(" x");
+                    write!(f, "{}{}{}", self.number, marker, extension)
+                }
+                None => write!(f, "{}", self.number),
+            };
+        }
+        write!(f, "tel:{}", self.number)?;
+        if let Some(extension) = &self.extension {
+            write!(f, ";ext={}", extension)?;
+        }
+        if let Some(subaddress) = &self.isdn_subaddress {
+            write!(f, ";isub={}", subaddress)?;
+        }
+        for (name, value) in &self.parameters {
+            match value {
+                Some(value) => write!(f, ";{}={}", name, value)?,
+                None => write!(f, ";{}", name)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> IntoOwned for Telephone<'a> {
+    type Owned = Telephone<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Telephone {
+            number: IntoOwned::into_owned(self.number),
+            extension: self.extension.into_owned(),
+            extension_marker: self.extension_marker.into_owned(),
+            isdn_subaddress: self.isdn_subaddress.into_owned(),
+            parameters: self
+                .parameters
+                .into_iter()
+                .map(|(name, value)| (IntoOwned::into_owned(name), value.into_owned()))
+                .collect(),
+            uri: self.uri,
+        }
+    }
+}
+
+impl<'a> Parse<'a> for Telephone<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, Telephone<'a>, ParseError<'a>> {
+        let (remains, value) = parse_value(input).map_err(|c| c.map(ParseError))?;
+        let value = match value {
+            Cow::Borrowed(value) => Telephone::from_text(value),
+            Cow::Owned(_) => None,
+        };
+        match value {
+            Some(value) => Ok((remains, value)),
+            None => {
+                let error = VerboseError {
+                    errors: vec![(input, VerboseErrorKind::Context("Failed to parse telephone"))],
+                };
+                Err(nom::Err::Error(ParseError(error)))
+            }
+        }
+    }
+}
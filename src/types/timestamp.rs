@@ -0,0 +1,73 @@
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
+
+use std::fmt;
+
+use super::{DateAndOrTime, Zone};
+use crate::parse::{Parse, ParseError};
+use crate::IntoOwned;
+
+/// A `timestamp` value such as `REV`, a complete date and time converted to
+/// UTC. Times without a zone are taken as UTC.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timestamp(pub DateTime<Utc>);
+
+impl Timestamp {
+    /// The current time, to the second.
+    pub fn now() -> Timestamp {
+        let now = Utc::now();
+        Timestamp(Utc.timestamp_opt(now.timestamp(), 0).unwrap())
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(time: DateTime<Utc>) -> Timestamp {
+        Timestamp(time)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.format("%Y%m%dT%H%M%SZ"))
+    }
+}
+
+impl IntoOwned for Timestamp {
+    type Owned = Timestamp;
+
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+}
+
+impl<'a> Parse<'a> for Timestamp {
+    fn parse(input: &'a str) -> IResult<&'a str, Timestamp, ParseError<'a>> {
+        let error = || {
+            let error = VerboseError {
+                errors: vec![(input, VerboseErrorKind::Context("Failed to parse timestamp"))],
+            };
+            nom::Err::Error(ParseError(error))
+        };
+        let (remains, value) = DateAndOrTime::parse(input)?;
+        let (date, time) = match value {
+            DateAndOrTime::DateTime(date, time) => (date, time),
+            _ => return Err(error()),
+        };
+        // Seconds are often left out, as in `20080424T1952Z`.
+        let local = NaiveDate::from_ymd_opt(
+            date.year.ok_or_else(error)?,
+            date.month.ok_or_else(error)?,
+            date.day.ok_or_else(error)?,
+        )
+        .and_then(|x| x.and_hms_opt(time.hour?, time.minute?, time.second.unwrap_or(0)))
+        .ok_or_else(error)?;
+        let offset = match time.zone {
+            Some(Zone::Offset(minutes)) => minutes,
+            Some(Zone::Utc) | None => 0,
+        };
+        let utc = local - chrono::Duration::minutes(offset.into());
+        Ok((remains, Timestamp(Utc.from_utc_datetime(&utc))))
+    }
+}
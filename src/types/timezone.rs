@@ -0,0 +1,123 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
+
+use std::borrow::Cow;
+use std::fmt;
+
+use super::date::parse_zone;
+use super::Zone;
+use crate::parse::{escape_text, parse_value, unescape_text, Parse, ParseError};
+use crate::IntoOwned;
+
+/// A `TZ` property or parameter value.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeZone<'a> {
+    /// Minutes east of UTC, such as `-0500` or `-05:00`.
+    Offset(i32),
+    /// A name given as text, normally from the IANA database such as
+    /// `America/New_York`.
+    Name(Cow<'a, str>),
+    /// A URI, which is kept as it is and has no known offset.
+    Uri(Cow<'a, str>),
+}
+
+impl<'a> TimeZone<'a> {
+    /// Read a time zone from the text of a value or parameter, which must
+    /// not be empty.
+    pub fn from_text(text: &'a str) -> Option<TimeZone<'a>> {
+        if text.is_empty() {
+            return None;
+        }
+        match parse_zone(text) {
+            Some(Zone::Offset(minutes)) => return Some(TimeZone::Offset(minutes)),
+            Some(Zone::Utc) => return Some(TimeZone::Offset(0)),
+            None => {}
+        }
+        let scheme = text.split(':').next().unwrap_or("");
+        let is_uri = scheme.len() < text.len()
+            && scheme.starts_with(|x: char| x.is_ascii_alphabetic())
+            && scheme.chars().all(|x| x.is_ascii_alphanumeric() || "+-.".contains(x));
+        if is_uri {
+            Some(TimeZone::Uri(text.into()))
+        } else {
+            Some(TimeZone::Name(text.into()))
+        }
+    }
+
+    /// The UTC offset in effect at `at`. Names are looked up in the IANA
+    /// database with the `tz` feature, URIs and unknown names give `None`.
+    pub fn offset_at(&self, at: DateTime<Utc>) -> Option<FixedOffset> {
+        match self {
+            TimeZone::Offset(minutes) => FixedOffset::east_opt(minutes * 60),
+            TimeZone::Name(name) => named_offset(name, at),
+            TimeZone::Uri(_) => None,
+        }
+    }
+
+    /// The local time at `at`.
+    pub fn local_time(&self, at: DateTime<Utc>) -> Option<DateTime<FixedOffset>> {
+        Some(at.with_timezone(&self.offset_at(at)?))
+    }
+}
+
+#[cfg(feature = "tz")]
+fn named_offset(name: &str, at: DateTime<Utc>) -> Option<FixedOffset> {
+    use chrono::Offset;
+    let zone: chrono_tz::Tz = name.parse().ok()?;
+    Some(at.with_timezone(&zone).offset().fix())
+}
+
+#[cfg(not(feature = "tz"))]
+fn named_offset(_: &str, _: DateTime<Utc>) -> Option<FixedOffset> {
+    None
+}
+
+impl<'a> fmt::Display for TimeZone<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeZone::Offset(minutes) => write!(f, "{}", Zone::Offset(*minutes)),
+            TimeZone::Name(name) => write!(f, "{}", escape_text(name)),
+            TimeZone::Uri(uri) => write!(f, "{}", uri),
+        }
+    }
+}
+
+impl<'a> IntoOwned for TimeZone<'a> {
+    type Owned = TimeZone<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            TimeZone::Offset(minutes) => TimeZone::Offset(minutes),
+            TimeZone::Name(name) => TimeZone::Name(IntoOwned::into_owned(name)),
+            TimeZone::Uri(uri) => TimeZone::Uri(IntoOwned::into_owned(uri)),
+        }
+    }
+}
+
+impl<'a> Parse<'a> for TimeZone<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, TimeZone<'a>, ParseError<'a>> {
+        let (remains, value) = parse_value(input).map_err(|c| c.map(ParseError))?;
+        let value = match value {
+            Cow::Borrowed(value) => TimeZone::from_text(value),
+            Cow::Owned(value) => TimeZone::from_text(&value).map(IntoOwned::into_owned),
+        };
+        // Names are text and may have escapes, URIs are kept as they are.
+        let value = value.map(|zone| match zone {
+            TimeZone::Name(name) if name.contains('\\') => {
+                TimeZone::Name(unescape_text(&name).into())
+            }
+            zone => zone,
+        });
+        match value {
+            Some(value) => Ok((remains, value)),
+            None => {
+                let error = VerboseError {
+                    errors: vec![(input, VerboseErrorKind::Context("Failed to parse time zone"))],
+                };
+                Err(nom::Err::Error(ParseError(error)))
+            }
+        }
+    }
+}
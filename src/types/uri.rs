@@ -0,0 +1,314 @@
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
+
+use std::borrow::Cow;
+use std::fmt;
+use std::net::Ipv6Addr;
+
+use crate::parse::{parse_value, Parse, ParseError};
+use crate::IntoOwned;
+
+/// A URI as in RFC 3986, such as `https://example.com/a?b=c#d`,
+/// `tel:+1-555-555-0100` or `urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6`.
+/// The parts are kept as written, percent-encoding included.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+pub struct Uri<'a> {
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    pub scheme: Cow<'a, str>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub authority: Option<Authority<'a>>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(into), default))]
+    pub path: Cow<'a, str>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option, into), default))]
+    pub query: Option<Cow<'a, str>>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option, into), default))]
+    pub fragment: Option<Cow<'a, str>>,
+}
+
+/// The `//userinfo@host:port` part of a [`Uri`].
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+pub struct Authority<'a> {
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option, into), default))]
+    pub userinfo: Option<Cow<'a, str>>,
+    /// A registered name, IPv4 address or bracketed IPv6 literal such as
+    /// `[::1]`.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    pub host: Cow<'a, str>,
+    #[cfg_attr(feature = "typed-builder", builder(setter(strip_option), default))]
+    pub port: Option<u16>,
+}
+
+impl<'a> Uri<'a> {
+    /// Read a URI from the whole of `text`.
+    pub fn from_text(text: &'a str) -> Option<Uri<'a>> {
+        let (scheme, rest) = split_at(text, ':')?;
+        let mut chars = scheme.chars();
+        if !chars.next()?.is_ascii_alphabetic()
+            || !chars.all(|x| x.is_ascii_alphanumeric() || "+-.".contains(x))
+        {
+            return None;
+        }
+        let (rest, fragment) = match split_at(rest, '#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (rest, None),
+        };
+        let (rest, query) = match split_at(rest, '?') {
+            Some((rest, query)) => (rest, Some(query)),
+            None => (rest, None),
+        };
+        let (authority, path) = match rest.strip_prefix("//") {
+            Some(rest) => {
+                let end = rest.find('/').unwrap_or(rest.len());
+                (Some(Authority::from_text(&rest[..end])?), &rest[end..])
+            }
+            None => (None, rest),
+        };
+        let extra = |x: char| "/?".contains(x);
+        if (authority.is_none() && path.is_empty())
+            || !valid(path, |x| x == '/')
+            || !query.into_iter().all(|x| valid(x, extra))
+            || !fragment.into_iter().all(|x| valid(x, extra))
+        {
+            return None;
+        }
+        Some(Uri {
+            scheme: scheme.into(),
+            authority,
+            path: path.into(),
+            query: query.map(Cow::from),
+            fragment: fragment.map(Cow::from),
+        })
+    }
+
+    /// Whether the scheme is `scheme`, ignoring case.
+    pub fn has_scheme(&self, scheme: &str) -> bool {
+        self.scheme.eq_ignore_ascii_case(scheme)
+    }
+
+    pub fn host(&self) -> Option<&str> {
+        self.authority.as_ref().map(|x| x.host.as_ref())
+    }
+
+    /// The port, or the default port of schemes such as `http` and `https`
+    /// if none is written.
+    pub fn port(&self) -> Option<u16> {
+        let authority = self.authority.as_ref()?;
+        authority.port.or_else(|| {
+            let scheme = self.scheme.to_ascii_lowercase();
+            let port = match scheme.as_str() {
+                "http" | "ws" => 80,
+                "https" | "wss" => 443,
+                "ftp" => 21,
+                "ldap" => 389,
+                "ldaps" => 636,
+                _ => return None,
+            };
+            Some(port)
+        })
+    }
+
+    /// The `name=value` pairs of the query in order, still
+    /// percent-encoded.
+    pub fn query_pairs(&self) -> Vec<(&str, Option<&str>)> {
+        self.query.as_deref().map(pairs).unwrap_or_default()
+    }
+
+    /// The UUID of a `urn:uuid:` URI.
+    pub fn uuid(&self) -> Option<&str> {
+        if !self.has_scheme("urn") {
+            return None;
+        }
+        let uuid = self.path.get(5..)?;
+        Some(uuid).filter(|_| self.path[..5].eq_ignore_ascii_case("uuid:"))
+    }
+
+    /// The percent-decoded address of a URI without an authority, such as
+    /// the number of a `tel:`, the mailbox of a `mailto:` or the id of a
+    /// `cid:`, with any `;` parameters left out.
+    pub fn address(&self) -> Option<Cow<'_, str>> {
+        if self.authority.is_some() {
+            return None;
+        }
+        let end = self.path.find(';').unwrap_or(self.path.len());
+        percent_decode(&self.path[..end])
+    }
+
+    /// The `;name=value` parameters following the address, as used by
+    /// `tel:` and `sip:`, still percent-encoded.
+    pub fn parameters(&self) -> Vec<(&str, Option<&str>)> {
+        match self.path.find(';') {
+            Some(at) if self.authority.is_none() => self.path[at + 1..]
+                .split(';')
+                .filter(|x| !x.is_empty())
+                .map(pair)
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl<'a> Authority<'a> {
+    fn from_text(text: &'a str) -> Option<Authority<'a>> {
+        let (userinfo, rest) = match text.rfind('@') {
+            Some(at) => (Some(&text[..at]), &text[at + 1..]),
+            None => (None, text),
+        };
+        let port_at = match rest.rfind(':') {
+            Some(at) if !rest[at..].contains(']') => Some(at),
+            _ => None,
+        };
+        let (host, port) = match port_at {
+            Some(at) => (&rest[..at], Some(&rest[at + 1..])),
+            None => (rest, None),
+        };
+        let port = match port {
+            Some("") | None => None,
+            Some(port) if port.chars().all(|x| x.is_ascii_digit()) => Some(port.parse().ok()?),
+            Some(_) => return None,
+        };
+        let host_valid = match host.strip_prefix('[').and_then(|x| x.strip_suffix(']')) {
+            Some(literal) => {
+                literal.parse::<Ipv6Addr>().is_ok()
+                    || (literal.starts_with(['v', 'V']) && valid(literal, |x| x == ':'))
+            }
+            None => valid(host, |_| false),
+        };
+        if !host_valid || !userinfo.into_iter().all(|x| valid(x, |x| x == ':')) {
+            return None;
+        }
+        Some(Authority {
+            userinfo: userinfo.map(Cow::from),
+            host: host.into(),
+            port,
+        })
+    }
+}
+
+/// Split `text` at the first `separator`, leaving it out.
+fn split_at(text: &str, separator: char) -> Option<(&str, &str)> {
+    let at = text.find(separator)?;
+    Some((&text[..at], &text[at + 1..]))
+}
+
+/// Whether `text` holds only unreserved characters, sub-delims, `:`, `@`,
+/// percent-encoded octets and the `extra` characters allowed in its part.
+fn valid(text: &str, extra: impl Fn(char) -> bool) -> bool {
+    let bytes = text.as_bytes();
+    text.char_indices().all(|(index, x)| match x {
+        '%' => bytes
+            .get(index + 1..index + 3)
+            .is_some_and(|x| x.iter().all(u8::is_ascii_hexdigit)),
+        x if x.is_ascii_alphanumeric() || "-._~!$&'()*+,;=:@".contains(x) => true,
+        x => extra(x),
+    })
+}
+
+fn pair(text: &str) -> (&str, Option<&str>) {
+    match split_at(text, '=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (text, None),
+    }
+}
+
+fn pairs(text: &str) -> Vec<(&str, Option<&str>)> {
+    text.split('&').filter(|x| !x.is_empty()).map(pair).collect()
+}
+
+/// Decode the `%XX` octets of `text`, `None` if they are not UTF-8.
+pub(super) fn percent_decode(text: &str) -> Option<Cow<'_, str>> {
+    if !text.contains('%') {
+        return Some(text.into());
+    }
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok().map(Cow::Owned)
+}
+
+impl<'a> fmt::Display for Uri<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:", self.scheme)?;
+        if let Some(authority) = &self.authority {
+            write!(f, "//{}", authority)?;
+        }
+        write!(f, "{}", self.path)?;
+        if let Some(query) = &self.query {
+            write!(f, "?{}", query)?;
+        }
+        if let Some(fragment) = &self.fragment {
+            write!(f, "#{}", fragment)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for Authority<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(userinfo) = &self.userinfo {
+            write!(f, "{}@", userinfo)?;
+        }
+        write!(f, "{}", self.host)?;
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> IntoOwned for Uri<'a> {
+    type Owned = Uri<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Uri {
+            scheme: IntoOwned::into_owned(self.scheme),
+            authority: self.authority.map(IntoOwned::into_owned),
+            path: IntoOwned::into_owned(self.path),
+            query: self.query.into_owned(),
+            fragment: self.fragment.into_owned(),
+        }
+    }
+}
+
+impl<'a> IntoOwned for Authority<'a> {
+    type Owned = Authority<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Authority {
+            userinfo: self.userinfo.into_owned(),
+            host: IntoOwned::into_owned(self.host),
+            port: self.port,
+        }
+    }
+}
+
+impl<'a> Parse<'a> for Uri<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, Uri<'a>, ParseError<'a>> {
+        let (remains, value) = parse_value(input).map_err(|c| c.map(ParseError))?;
+        let value = match value {
+            Cow::Borrowed(value) => Uri::from_text(value),
+            Cow::Owned(_) => None,
+        };
+        match value {
+            Some(value) => Ok((remains, value)),
+            None => {
+                let error = VerboseError {
+                    errors: vec![(input, VerboseErrorKind::Context("Failed to parse uri"))],
+                };
+                Err(nom::Err::Error(ParseError(error)))
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sha1::{Digest, Sha1};
+
+use super::key::hex;
+
+/// What an X.509 certificate says about its subject, read by
+/// [`Key::inspect`](super::Key::inspect).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Certificate {
+    /// The subject as an RFC 4514 string such as `CN=Jane Doe,O=Example`.
+    pub subject: String,
+    pub issuer: String,
+    pub not_before: DateTime<Utc>,
+    /// When the certificate expires.
+    pub not_after: DateTime<Utc>,
+    /// The SHA-1 fingerprint of the DER in upper case hex.
+    pub fingerprint: String,
+}
+
+/// DER tags of the elements read.
+const INTEGER: u8 = 0x02;
+const OID: u8 = 0x06;
+const UTC_TIME: u8 = 0x17;
+const GENERALIZED_TIME: u8 = 0x18;
+const BMP_STRING: u8 = 0x1e;
+const SEQUENCE: u8 = 0x30;
+const SET: u8 = 0x31;
+const VERSION: u8 = 0xa0;
+
+/// Attribute types of RFC 4514 and a few more in common use, by the DER
+/// of their OID.
+const ATTRIBUTES: &[(&[u8], &str)] = &[
+    (&[0x55, 0x04, 0x03], "CN"),
+    (&[0x55, 0x04, 0x05], "serialNumber"),
+    (&[0x55, 0x04, 0x06], "C"),
+    (&[0x55, 0x04, 0x07], "L"),
+    (&[0x55, 0x04, 0x08], "ST"),
+    (&[0x55, 0x04, 0x09], "STREET"),
+    (&[0x55, 0x04, 0x0a], "O"),
+    (&[0x55, 0x04, 0x0b], "OU"),
+    (&[0x55, 0x04, 0x0c], "title"),
+    (
+        &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x01],
+        "emailAddress",
+    ),
+    (
+        &[0x09, 0x92, 0x26, 0x89, 0x93, 0xf2, 0x2c, 0x64, 0x01, 0x01],
+        "UID",
+    ),
+    (
+        &[0x09, 0x92, 0x26, 0x89, 0x93, 0xf2, 0x2c, 0x64, 0x01, 0x19],
+        "DC",
+    ),
+];
+
+impl Certificate {
+    /// Read a DER certificate, `None` if it is not one.
+    pub fn from_der(der: &[u8]) -> Option<Certificate> {
+        let (certificate, rest) = element(der, SEQUENCE)?;
+        if !rest.is_empty() {
+            return None;
+        }
+        let (tbs, _) = element(certificate, SEQUENCE)?;
+        let rest = match element(tbs, VERSION) {
+            Some((_, rest)) => rest,
+            None => tbs,
+        };
+        let (_, rest) = element(rest, INTEGER)?;
+        let (_, rest) = element(rest, SEQUENCE)?;
+        let (issuer, rest) = element(rest, SEQUENCE)?;
+        let (validity, rest) = element(rest, SEQUENCE)?;
+        let (subject, _) = element(rest, SEQUENCE)?;
+        let (not_before, rest) = time(validity)?;
+        let (not_after, _) = time(rest)?;
+        Some(Certificate {
+            subject: name(subject)?,
+            issuer: name(issuer)?,
+            not_before,
+            not_after,
+            fingerprint: hex(&Sha1::digest(der)),
+        })
+    }
+}
+
+/// The tag and contents of the element at the start of `der`, and the
+/// bytes after it.
+fn any_element(der: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = der.split_first()?;
+    let (&first, rest) = rest.split_first()?;
+    let (length, rest) = match first {
+        0..=0x7f => (first as usize, rest),
+        0x81..=0x84 => {
+            let count = (first & 0x7f) as usize;
+            let bytes = rest.get(..count)?;
+            let length = bytes.iter().fold(0, |length, &x| length << 8 | x as usize);
+            (length, &rest[count..])
+        }
+        _ => return None,
+    };
+    Some((tag, rest.get(..length)?, &rest[length..]))
+}
+
+fn element(der: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    match any_element(der)? {
+        (found, contents, rest) if found == tag => Some((contents, rest)),
+        _ => None,
+    }
+}
+
+fn time(der: &[u8]) -> Option<(DateTime<Utc>, &[u8])> {
+    let (tag, contents, rest) = any_element(der)?;
+    let text = std::str::from_utf8(contents).ok()?;
+    let text = match tag {
+        // Two digit years from 50 are in the 1900s, RFC 5280 4.1.2.5.1.
+        UTC_TIME if text.get(..2)? >= "50" => format!("19{}", text),
+        UTC_TIME => format!("20{}", text),
+        GENERALIZED_TIME => text.to_string(),
+        _ => return None,
+    };
+    // GeneralizedTime may have fractional seconds, as in `…000000.123Z`.
+    let time = NaiveDateTime::parse_from_str(&text, "%Y%m%d%H%M%S%.fZ").ok()?;
+    Some((DateTime::from_naive_utc_and_offset(time, Utc), rest))
+}
+
+/// A distinguished name as an RFC 4514 string, most specific part first.
+fn name(mut der: &[u8]) -> Option<String> {
+    let mut parts = vec![];
+    while !der.is_empty() {
+        let (mut set, rest) = element(der, SET)?;
+        der = rest;
+        let mut attributes = vec![];
+        while !set.is_empty() {
+            let (attribute, rest) = element(set, SEQUENCE)?;
+            set = rest;
+            let (oid, value) = element(attribute, OID)?;
+            let (tag, value, _) = any_element(value)?;
+            let value = match tag {
+                BMP_STRING => {
+                    let units = value
+                        .chunks(2)
+                        .map(|x| Some(u16::from_be_bytes([x[0], *x.get(1)?])));
+                    String::from_utf16(&units.collect::<Option<Vec<_>>>()?).ok()?
+                }
+                _ => String::from_utf8_lossy(value).into_owned(),
+            };
+            let name = match ATTRIBUTES.iter().find(|(x, _)| *x == oid) {
+                Some((_, name)) => name.to_string(),
+                None => dotted(oid)?,
+            };
+            attributes.push(format!("{}={}", name, escape(&value)));
+        }
+        parts.push(attributes.join("+"));
+    }
+    parts.reverse();
+    Some(parts.join(","))
+}
+
+/// The dotted form of an OID such as `2.5.4.3`.
+fn dotted(oid: &[u8]) -> Option<String> {
+    let (&first, rest) = oid.split_first()?;
+    let mut arcs = vec![(first / 40) as u64, (first % 40) as u64];
+    let mut arc = 0u64;
+    for &x in rest {
+        arc = arc.checked_mul(128)? | (x & 0x7f) as u64;
+        if x & 0x80 == 0 {
+            arcs.push(arc);
+            arc = 0;
+        }
+    }
+    Some(
+        arcs.iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join("."),
+    )
+}
+
+/// Escape the characters RFC 4514 does not allow as they are.
+fn escape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for (index, x) in value.chars().enumerate() {
+        let edge = (index == 0 && (x == '#' || x == ' '))
+            || (x == ' ' && index + 1 == value.chars().count());
+        if edge || ",+\"\\<>;".contains(x) {
+            output.push('\\');
+        }
+        output.push(x);
+    }
+    output
+}
@@ -1,6 +1,6 @@
 use std::fmt::{Debug, Display, Formatter, Result};
 
-use crate::PropertyValue;
+use crate::{IntoOwned, PropertyValue};
 
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -17,6 +17,14 @@ impl<T> AtLeastOne<T>
 where
     T: PropertyValue,
 {
+    /// Iterate over every item.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        match self {
+            AtLeastOne::Single(item) => std::slice::from_ref(item).iter(),
+            AtLeastOne::Multiple(multi) => multi.iter(),
+        }
+    }
+
     #[allow(dead_code)]
     fn into_iterator(self) -> std::vec::IntoIter<T> {
         match self {
@@ -26,7 +34,7 @@ where
     }
 }
 
-impl<'a, T> Display for AtLeastOne<T>
+impl<T> Display for AtLeastOne<T>
 where
     T: PropertyValue,
 {
@@ -39,8 +47,23 @@ where
                 ty.iter()
                     .map(|x| format!("{}", x))
                     .collect::<Vec<String>>()
-                    .join(", ")
+                    .join(",")
             ),
         }
     }
 }
+
+impl<T> IntoOwned for AtLeastOne<T>
+where
+    T: PropertyValue + IntoOwned,
+    T::Owned: PropertyValue,
+{
+    type Owned = AtLeastOne<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            AtLeastOne::Single(item) => AtLeastOne::Single(item.into_owned()),
+            AtLeastOne::Multiple(items) => AtLeastOne::Multiple(items.into_owned()),
+        }
+    }
+}
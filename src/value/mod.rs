@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::fmt::{Debug, Display};
+use std::fmt::{self, Debug, Display};
 
 mod ty;
 pub use self::ty::TypeOrRaw;
@@ -7,7 +7,13 @@ pub use self::ty::TypeOrRaw;
 mod at_least;
 pub use self::at_least::AtLeastOne;
 
-use crate::types::{Address, Email, FormattedName, Gender, GeoPosition, Image, Kind, Url};
+mod owned;
+pub use self::owned::IntoOwned;
+
+use crate::types::{
+    Address, DateAndOrTime, Email, FormattedName, Gender, GeoPosition, Image, Key, Kind,
+    Organization, SocialProfile, Telephone, TimeZone, Timestamp, Uri,
+};
 
 #[cfg(feature = "serde")]
 pub trait PropertyValue: Debug + PartialEq + Clone + Display + serde::Serialize {}
@@ -24,42 +30,46 @@ impl<T> PropertyValue for T where T: Debug + PartialEq + Clone + Display + serde
 pub enum Value<'a> {
     Begin,
     End,
-    Source(TypeOrRaw<Url<'a>>),
+    Source(TypeOrRaw<Uri<'a>>),
     Kind(TypeOrRaw<Kind>),
     Xml(Cow<'a, str>),
     Fn(Cow<'a, str>),
     N(TypeOrRaw<FormattedName<'a>>),
     Nickname(AtLeastOne<Cow<'a, str>>),
     Photo(TypeOrRaw<Image<'a>>),
-    Birthday(TypeOrRaw<chrono::NaiveDate>),
-    Anniversary(TypeOrRaw<chrono::NaiveDate>),
+    Birthday(TypeOrRaw<DateAndOrTime>),
+    Anniversary(TypeOrRaw<DateAndOrTime>),
+    /// `DEATHDATE` from RFC 6474.
+    DeathDate(TypeOrRaw<DateAndOrTime>),
     Gender(TypeOrRaw<Gender<'a>>),
     Adr(TypeOrRaw<Address<'a>>),
-    Tel(Cow<'a, str>),
+    Tel(TypeOrRaw<Telephone<'a>>),
     Email(TypeOrRaw<Email<'a>>),
-    Impp(Cow<'a, str>),
+    Impp(TypeOrRaw<SocialProfile<'a>>),
+    /// `SOCIALPROFILE` from RFC 9554.
+    SocialProfile(TypeOrRaw<SocialProfile<'a>>),
     Lang(Cow<'a, str>),
-    Timezone(Cow<'a, str>),
+    Timezone(TypeOrRaw<TimeZone<'a>>),
     Geo(TypeOrRaw<GeoPosition>),
     Title(Cow<'a, str>),
     Role(Cow<'a, str>),
     Logo(TypeOrRaw<Image<'a>>),
-    Organization(Cow<'a, str>),
-    Member(Cow<'a, str>),
-    Related(Cow<'a, str>),
+    Organization(TypeOrRaw<Organization<'a>>),
+    Member(TypeOrRaw<Uri<'a>>),
+    Related(TypeOrRaw<Uri<'a>>),
     Categories(AtLeastOne<Cow<'a, str>>),
     Note(Cow<'a, str>),
     ProdID(Cow<'a, str>),
-    Rev(Cow<'a, str>),
+    Rev(TypeOrRaw<Timestamp>),
     Sound(Cow<'a, str>),
     Uid(Cow<'a, str>),
     ClientPIDMap(Cow<'a, str>),
-    Url(TypeOrRaw<Url<'a>>),
+    Url(TypeOrRaw<Uri<'a>>),
     Version(Cow<'a, str>),
-    Key(Cow<'a, str>),
-    FbUrl(Cow<'a, str>),
-    CalAdrURL(Cow<'a, str>),
-    CalURL(Cow<'a, str>),
+    Key(TypeOrRaw<Key<'a>>),
+    FbUrl(TypeOrRaw<Uri<'a>>),
+    CalAdrURL(TypeOrRaw<Uri<'a>>),
+    CalURL(TypeOrRaw<Uri<'a>>),
     Other(Cow<'a, str>, Cow<'a, str>),
 }
 
@@ -75,20 +85,22 @@ impl<'a> Value<'a> {
             Value::N(_) => "N",
             Value::Nickname(_) => "NICKNAME",
             Value::Photo(_) => "PHOTO",
-            Value::Birthday(_) => "BIRTHDAY",
+            Value::Birthday(_) => "BDAY",
             Value::Anniversary(_) => "ANNIVERSARY",
+            Value::DeathDate(_) => "DEATHDATE",
             Value::Gender(_) => "GENDER",
             Value::Adr(_) => "ADR",
-            Value::Tel(_) => "PHONE",
+            Value::Tel(_) => "TEL",
             Value::Email(_) => "EMAIL",
             Value::Impp(_) => "IMPP",
+            Value::SocialProfile(_) => "SOCIALPROFILE",
             Value::Lang(_) => "LANG",
             Value::Timezone(_) => "TZ",
             Value::Geo(_) => "GEO",
             Value::Title(_) => "TITLE",
             Value::Role(_) => "ROLE",
             Value::Logo(_) => "LOGO",
-            Value::Organization(_) => "ORGANIZATION",
+            Value::Organization(_) => "ORG",
             Value::Member(_) => "MEMBER",
             Value::Related(_) => "RELATED",
             Value::Categories(_) => "CATEGORIES",
@@ -100,10 +112,10 @@ impl<'a> Value<'a> {
             Value::ClientPIDMap(_) => "CLIENTPIDMAP",
             Value::Url(_) => "URL",
             Value::Version(_) => "VERSION",
-            Value::Key(_) => "Key",
+            Value::Key(_) => "KEY",
             Value::FbUrl(_) => "FBURL",
-            Value::CalAdrURL(_) => "CALADRURL",
-            Value::CalURL(_) => "CALURL",
+            Value::CalAdrURL(_) => "CALADRURI",
+            Value::CalURL(_) => "CALURI",
             Value::Other(name, _) => name,
         }
     }
@@ -121,11 +133,13 @@ impl<'a> Value<'a> {
             Value::Photo(_) => "Photo",
             Value::Birthday(_) => "Birthday",
             Value::Anniversary(_) => "Anniversary",
+            Value::DeathDate(_) => "Death Date",
             Value::Gender(_) => "Gender",
             Value::Adr(_) => "Address",
             Value::Tel(_) => "Phone",
             Value::Email(_) => "Email",
             Value::Impp(_) => "Impp",
+            Value::SocialProfile(_) => "Social Profile",
             Value::Lang(_) => "Language",
             Value::Timezone(_) => "Timezone",
             Value::Geo(_) => "Location",
@@ -152,3 +166,100 @@ impl<'a> Value<'a> {
         }
     }
 }
+
+/// Writes only the value part of the property as it appears in vcard data.
+impl<'a> fmt::Display for Value<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Begin | Value::End => write!(f, "VCARD"),
+            Value::Source(inner)
+            | Value::Url(inner)
+            | Value::Member(inner)
+            | Value::Related(inner)
+            | Value::FbUrl(inner)
+            | Value::CalAdrURL(inner)
+            | Value::CalURL(inner) => write!(f, "{}", inner),
+            Value::Photo(inner) | Value::Logo(inner) => write!(f, "{}", inner),
+            Value::Birthday(inner) | Value::Anniversary(inner) | Value::DeathDate(inner) => {
+                write!(f, "{}", inner)
+            }
+            Value::Nickname(inner) | Value::Categories(inner) => write!(f, "{}", inner),
+            Value::Kind(inner) => write!(f, "{}", inner),
+            Value::Rev(inner) => write!(f, "{}", inner),
+            Value::Timezone(inner) => write!(f, "{}", inner),
+            Value::N(inner) => write!(f, "{}", inner),
+            Value::Gender(inner) => write!(f, "{}", inner),
+            Value::Adr(inner) => write!(f, "{}", inner),
+            Value::Email(inner) => write!(f, "{}", inner),
+            Value::Tel(inner) => write!(f, "{}", inner),
+            Value::Geo(inner) => write!(f, "{}", inner),
+            Value::Organization(inner) => write!(f, "{}", inner),
+            Value::Impp(inner) | Value::SocialProfile(inner) => write!(f, "{}", inner),
+            Value::Xml(inner)
+            | Value::Fn(inner)
+            | Value::Lang(inner)
+            | Value::Title(inner)
+            | Value::Role(inner)
+            | Value::Note(inner)
+            | Value::ProdID(inner)
+            | Value::Sound(inner)
+            | Value::Uid(inner)
+            | Value::ClientPIDMap(inner)
+            | Value::Version(inner) => write!(f, "{}", inner),
+            Value::Key(inner) => write!(f, "{}", inner),
+            Value::Other(_, value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl<'a> IntoOwned for Value<'a> {
+    type Owned = Value<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            Value::Begin => Value::Begin,
+            Value::End => Value::End,
+            Value::Source(inner) => Value::Source(IntoOwned::into_owned(inner)),
+            Value::Kind(inner) => Value::Kind(IntoOwned::into_owned(inner)),
+            Value::Xml(inner) => Value::Xml(IntoOwned::into_owned(inner)),
+            Value::Fn(inner) => Value::Fn(IntoOwned::into_owned(inner)),
+            Value::N(inner) => Value::N(IntoOwned::into_owned(inner)),
+            Value::Nickname(inner) => Value::Nickname(IntoOwned::into_owned(inner)),
+            Value::Photo(inner) => Value::Photo(IntoOwned::into_owned(inner)),
+            Value::Birthday(inner) => Value::Birthday(IntoOwned::into_owned(inner)),
+            Value::Anniversary(inner) => Value::Anniversary(IntoOwned::into_owned(inner)),
+            Value::DeathDate(inner) => Value::DeathDate(IntoOwned::into_owned(inner)),
+            Value::Gender(inner) => Value::Gender(IntoOwned::into_owned(inner)),
+            Value::Adr(inner) => Value::Adr(IntoOwned::into_owned(inner)),
+            Value::Tel(inner) => Value::Tel(IntoOwned::into_owned(inner)),
+            Value::Email(inner) => Value::Email(IntoOwned::into_owned(inner)),
+            Value::Impp(inner) => Value::Impp(IntoOwned::into_owned(inner)),
+            Value::SocialProfile(inner) => Value::SocialProfile(IntoOwned::into_owned(inner)),
+            Value::Lang(inner) => Value::Lang(IntoOwned::into_owned(inner)),
+            Value::Timezone(inner) => Value::Timezone(IntoOwned::into_owned(inner)),
+            Value::Geo(inner) => Value::Geo(IntoOwned::into_owned(inner)),
+            Value::Title(inner) => Value::Title(IntoOwned::into_owned(inner)),
+            Value::Role(inner) => Value::Role(IntoOwned::into_owned(inner)),
+            Value::Logo(inner) => Value::Logo(IntoOwned::into_owned(inner)),
+            Value::Organization(inner) => Value::Organization(IntoOwned::into_owned(inner)),
+            Value::Member(inner) => Value::Member(IntoOwned::into_owned(inner)),
+            Value::Related(inner) => Value::Related(IntoOwned::into_owned(inner)),
+            Value::Categories(inner) => Value::Categories(IntoOwned::into_owned(inner)),
+            Value::Note(inner) => Value::Note(IntoOwned::into_owned(inner)),
+            Value::ProdID(inner) => Value::ProdID(IntoOwned::into_owned(inner)),
+            Value::Rev(inner) => Value::Rev(IntoOwned::into_owned(inner)),
+            Value::Sound(inner) => Value::Sound(IntoOwned::into_owned(inner)),
+            Value::Uid(inner) => Value::Uid(IntoOwned::into_owned(inner)),
+            Value::ClientPIDMap(inner) => Value::ClientPIDMap(IntoOwned::into_owned(inner)),
+            Value::Url(inner) => Value::Url(IntoOwned::into_owned(inner)),
+            Value::Version(inner) => Value::Version(IntoOwned::into_owned(inner)),
+            Value::Key(inner) => Value::Key(IntoOwned::into_owned(inner)),
+            Value::FbUrl(inner) => Value::FbUrl(IntoOwned::into_owned(inner)),
+            Value::CalAdrURL(inner) => Value::CalAdrURL(IntoOwned::into_owned(inner)),
+            Value::CalURL(inner) => Value::CalURL(IntoOwned::into_owned(inner)),
+            Value::Other(name, value) => {
+                Value::Other(IntoOwned::into_owned(name), IntoOwned::into_owned(value))
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::borrow::Cow;
+
+/// Convert a value borrowing from the vcard data into one owning all of it.
+///
+/// This is used by the format conversions, they build vcard text on the fly
+/// so the parsed result cannot borrow from it.
+pub trait IntoOwned {
+    type Owned: 'static;
+
+    fn into_owned(self) -> Self::Owned;
+}
+
+impl IntoOwned for Cow<'_, str> {
+    type Owned = Cow<'static, str>;
+
+    fn into_owned(self) -> Cow<'static, str> {
+        Cow::Owned(self.into_owned())
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.map(IntoOwned::into_owned)
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Vec<T> {
+    type Owned = Vec<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter().map(IntoOwned::into_owned).collect()
+    }
+}
+
+impl IntoOwned for chrono::NaiveDate {
+    type Owned = chrono::NaiveDate;
+
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+}
@@ -3,7 +3,7 @@ use nom::IResult;
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter, Result};
 
-use super::PropertyValue;
+use super::{IntoOwned, PropertyValue};
 use crate::{ Parse, ParseError };
 
 /// Represents Either a type or a raw value read from the vcard data.
@@ -19,7 +19,7 @@ impl<'a, T> Parse<'a> for TypeOrRaw<T>
 where
     T: Parse<'a> + PropertyValue
 {
-    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self, ParseError<'a>> {
         match crate::parse::parse_typed_value(input) {
             Err(nom::Err::Failure(err)) => Err(nom::Err::Failure(ParseError(err))),
             Err(nom::Err::Error(err)) => Err(nom::Err::Error(ParseError(err))),
@@ -49,3 +49,18 @@ impl<T: PropertyValue> Display for TypeOrRaw<T> {
         }
     }
 }
+
+impl<T> IntoOwned for TypeOrRaw<T>
+where
+    T: PropertyValue + IntoOwned,
+    T::Owned: PropertyValue,
+{
+    type Owned = TypeOrRaw<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            TypeOrRaw::Type(ty) => TypeOrRaw::Type(ty.into_owned()),
+            TypeOrRaw::Raw(raw) => TypeOrRaw::Raw(raw),
+        }
+    }
+}
@@ -1,9 +1,12 @@
+use chrono::{DateTime, FixedOffset, Utc};
 use nom::{error::VerboseError, IResult};
 
 use std::fmt;
+use std::ops::{Deref, DerefMut};
 
 use crate::property::{ parse_end, parse_begin };
-use crate::{ Parse, ParseError, Property };
+use crate::types::{Organization, SocialProfile, TelephoneType, TimeZone, Timestamp};
+use crate::{ IntoOwned, Parse, ParseError, Property, TypeOrRaw, Value };
 
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -25,13 +28,182 @@ impl<'a> VCard<'a> {
         self.0.iter()
     }
 
-    pub fn parse(input: &'a str) -> std::result::Result<VCard<'a>, nom::Err<ParseError>> {
+    pub fn parse(input: &'a str) -> std::result::Result<VCard<'a>, nom::Err<ParseError<'a>>> {
         Ok(Parse::parse(input)?.1)
     }
+
+    pub fn push(&mut self, property: Property<'a>) {
+        self.0.push(property);
+    }
+
+    pub fn insert(&mut self, index: usize, property: Property<'a>) {
+        self.0.insert(index, property);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Property<'a> {
+        self.0.remove(index)
+    }
+
+    pub fn retain(&mut self, f: impl FnMut(&Property<'a>) -> bool) {
+        self.0.retain(f);
+    }
+
+    /// Replace the first property with the same name as `property` and
+    /// remove the others, or add it if there is none.
+    pub fn set(&mut self, property: Property<'a>) {
+        let name = property.value.name_raw().to_ascii_uppercase();
+        let same = |x: &Property| x.value.name_raw().eq_ignore_ascii_case(&name);
+        match self.0.iter().position(same) {
+            Some(index) => {
+                self.0[index] = property;
+                let mut current = 0;
+                self.0.retain(|x| {
+                    current += 1;
+                    current - 1 == index || !same(x)
+                });
+            }
+            None => self.0.push(property),
+        }
+    }
+
+    /// The revision of the card, from its first typed `REV`.
+    pub fn rev(&self) -> Option<Timestamp> {
+        self.iter().find_map(|x| match &x.value {
+            Value::Rev(TypeOrRaw::Type(rev)) => Some(*rev),
+            _ => None,
+        })
+    }
+
+    /// Set `REV` to `at`, or to a second past the current revision if that
+    /// is not earlier, so revisions only ever go forward.
+    pub fn bump_rev(&mut self, at: Timestamp) {
+        let at = match self.rev() {
+            Some(rev) if rev >= at => Timestamp(rev.0 + chrono::Duration::seconds(1)),
+            _ => at,
+        };
+        self.set(Property::from(Value::Rev(TypeOrRaw::Type(at))));
+    }
+
+    /// The time zone of the contact, from `TZ` or else from the `TZ`
+    /// parameter of another property such as `ADR`.
+    pub fn timezone(&self) -> Option<TimeZone<'_>> {
+        self.timezones().next()
+    }
+
+    /// The local time of the contact at `at`, from the first of its time
+    /// zones with a known offset.
+    pub fn local_time(&self, at: DateTime<Utc>) -> Option<DateTime<FixedOffset>> {
+        self.timezones().find_map(|x| x.local_time(at))
+    }
+
+    fn timezones(&self) -> impl Iterator<Item = TimeZone<'_>> {
+        let properties = self.iter().filter_map(|x| match &x.value {
+            Value::Timezone(TypeOrRaw::Type(zone)) => Some(zone.clone()),
+            _ => None,
+        });
+        properties.chain(self.iter().filter_map(|x| x.params.timezone()))
+    }
+
+    /// The most preferred `TEL` of type `ty`, the first one when several
+    /// are equally preferred.
+    pub fn telephone(&self, ty: TelephoneType) -> Option<&Property<'a>> {
+        self.iter()
+            .filter(|x| matches!(x.value, Value::Tel(_)))
+            .filter(|x| x.params.telephone_types().contains(&ty))
+            .min_by_key(|x| x.params.preference().unwrap_or(u8::MAX))
+    }
+
+    /// The most preferred typed `ORG`, the first one when several are
+    /// equally preferred.
+    pub fn organization(&self) -> Option<&Organization<'a>> {
+        self.iter()
+            .filter_map(|x| match &x.value {
+                Value::Organization(TypeOrRaw::Type(org)) => Some((x, org)),
+                _ => None,
+            })
+            .min_by_key(|(x, _)| x.params.preference().unwrap_or(u8::MAX))
+            .map(|(_, org)| org)
+    }
+
+    /// The messaging and social profiles of the contact from `IMPP`,
+    /// `SOCIALPROFILE` and `X-SOCIALPROFILE`. Values that do not name
+    /// their service are read as a username on the service their
+    /// parameters name.
+    pub fn social_profiles(&self) -> Vec<SocialProfile<'_>> {
+        self.iter()
+            .filter_map(|x| {
+                let text = match &x.value {
+                    Value::Impp(TypeOrRaw::Type(profile))
+                    | Value::SocialProfile(TypeOrRaw::Type(profile)) => {
+                        return Some(profile.clone())
+                    }
+                    Value::Impp(TypeOrRaw::Raw(text))
+                    | Value::SocialProfile(TypeOrRaw::Raw(text)) => text.as_ref(),
+                    Value::Other(name, text) if name.eq_ignore_ascii_case("X-SOCIALPROFILE") => {
+                        text.as_ref()
+                    }
+                    _ => return None,
+                };
+                match x.params.service_type() {
+                    Some(service) => SocialProfile::with_service(&service, text).or_else(|| {
+                        SocialProfile::with_service(&service, x.params.username()?)
+                    }),
+                    None => SocialProfile::from_text(text),
+                }
+            })
+            .collect()
+    }
+
+    /// Borrow the card for changes, bumping `REV` to the current time when
+    /// the borrow ends if any property other than `REV` changed.
+    pub fn revise(&mut self) -> Revise<'_, 'a> {
+        let original = self.clone();
+        Revise { vcard: self, original }
+    }
+}
+
+/// A vcard borrowed by [`VCard::revise`].
+pub struct Revise<'v, 'a> {
+    vcard: &'v mut VCard<'a>,
+    original: VCard<'a>,
+}
+
+impl<'v, 'a> Deref for Revise<'v, 'a> {
+    type Target = VCard<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.vcard
+    }
+}
+
+impl<'v, 'a> DerefMut for Revise<'v, 'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.vcard
+    }
+}
+
+impl<'v, 'a> Drop for Revise<'v, 'a> {
+    fn drop(&mut self) {
+        if !without_rev(self.vcard).eq(without_rev(&self.original)) {
+            self.vcard.bump_rev(Timestamp::now());
+        }
+    }
+}
+
+fn without_rev<'v, 'a>(vcard: &'v VCard<'a>) -> impl Iterator<Item = &'v Property<'a>> + 'v {
+    vcard.iter().filter(|x| !matches!(x.value, Value::Rev(_)))
+}
+
+impl<'a> IntoOwned for VCard<'a> {
+    type Owned = VCard<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        VCard(self.0.into_owned())
+    }
 }
 
 impl<'a> Parse<'a> for VCard<'a> {
-    fn parse(mut input: &'a str) -> IResult<&'a str, Self, ParseError> {
+    fn parse(mut input: &'a str) -> IResult<&'a str, Self, ParseError<'a>> {
         use nom::bytes::complete::tag;
         let mut properties = vec![];
         match parse_begin(input) {
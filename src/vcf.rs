@@ -1,9 +1,11 @@
 use nom::IResult;
 
-use crate::{VCard, Parse, ParseError};
+use crate::{IntoOwned, VCard, Parse, ParseError};
 
 use std::fmt;
 
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vcf<'a>(pub Vec<VCard<'a>>);
 
 impl <'a>fmt::Display for Vcf<'a> {
@@ -16,8 +18,16 @@ impl <'a>fmt::Display for Vcf<'a> {
     }
 }
 
+impl<'a> IntoOwned for Vcf<'a> {
+    type Owned = Vcf<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        Vcf(self.0.into_owned())
+    }
+}
+
 impl<'a> Parse<'a> for Vcf<'a> {
-    fn parse(mut input: &'a str) -> IResult<&'a str, Self, ParseError> {
+    fn parse(mut input: &'a str) -> IResult<&'a str, Self, ParseError<'a>> {
         let mut vcards = vec![];
         while let Ok((remains, vcard)) = Parse::parse(input) {
             input = remains;
@@ -0,0 +1,129 @@
+#![cfg(feature = "directory")]
+
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Zoë Adams
+N:Adams;Zoë;;;
+ORG:ABC\\, Inc.;North American Division;Marketing
+TITLE:Director
+ROLE:Project Lead
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Émile Zola
+N:Zola;Émile;;;
+ORG;SORT-AS=\"Aardvark\":The Zoo;Keepers
+TITLE:Keeper
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Bob Brown
+N:Brown;Bob;;;
+ORG:abc\\, inc.
+ORG:ABC\\, Inc.;North American Division
+TITLE:VP <North>
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Amy Adams
+N:Adams;Amy;;;
+ORG:ABC\\, Inc.;North American Division;Marketing
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:No Org
+END:VCARD
+";
+
+#[test]
+fn tree() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    let directory = vcf.directory();
+    let names: Vec<_> = directory
+        .organizations
+        .iter()
+        .map(|x| x.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["The Zoo", "ABC, Inc.", "abc, inc."]);
+
+    let abc = &directory.organizations[1];
+    assert!(abc.people.is_empty());
+    let division = &abc.units[0];
+    assert_eq!("North American Division", division.name);
+    assert_eq!("Bob Brown", division.people[0].name);
+    assert_eq!(vec!["VP <North>"], division.people[0].titles);
+    let marketing = &division.units[0];
+    let people: Vec<_> = marketing.people.iter().map(|x| x.name.as_str()).collect();
+    assert_eq!(people, vec!["Amy Adams", "Zoë Adams"]);
+    assert_eq!(vec!["Project Lead"], marketing.people[1].roles);
+}
+
+#[test]
+fn text() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    assert_eq!(
+        vcf.directory().to_text(),
+        "The Zoo
+  Keepers
+    - Émile Zola, Keeper
+ABC, Inc.
+  North American Division
+    - Bob Brown, VP <North>
+    Marketing
+      - Amy Adams
+      - Zoë Adams, Director (Project Lead)
+abc, inc.
+  - Bob Brown, VP <North>
+"
+    );
+}
+
+#[test]
+fn json() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    let json = vcf.directory().to_json();
+    let zoo = &json["organizations"][0];
+    assert_eq!(
+        zoo,
+        &serde_json::json!({
+            "name": "The Zoo",
+            "units": [{
+                "name": "Keepers",
+                "units": [],
+                "people": [{"name": "Émile Zola", "titles": ["Keeper"], "roles": []}],
+            }],
+            "people": [],
+        })
+    );
+}
+
+#[test]
+fn html() {
+    let (_, vcf) = Vcf::parse(
+        "BEGIN:VCARD\nVERSION:4.0\nFN:Bob <Brown>\nORG:R&D\nTITLE:VP\nROLE:Lead\nEND:VCARD\n",
+    )
+    .unwrap();
+    assert_eq!(
+        vcf.directory().to_html(),
+        "<ul class=\"directory\">
+<li class=\"org\"><span class=\"name\">R&amp;D</span>
+<ul>
+<li class=\"person\"><span class=\"name\">Bob &lt;Brown&gt;</span> <span class=\"title\">VP</span> <span class=\"role\">Lead</span></li>
+</ul>
+</li>
+</ul>
+"
+    );
+}
+
+#[test]
+fn empty_units() {
+    let (_, vcf) = Vcf::parse(
+        "BEGIN:VCARD\nVERSION:4.0\nFN:No Name\nORG:;Sales\nEND:VCARD\n\
+         BEGIN:VCARD\nVERSION:4.0\nFN:Gap\nORG:ABC;;Marketing\nEND:VCARD\n",
+    )
+    .unwrap();
+    assert_eq!(vcf.directory().to_text(), "ABC\n  - Gap\n");
+}
@@ -1,7 +1,7 @@
 use rolodex::types::*;
 use rolodex::*;
 
-const DATA: &'static str = "BEGIN:VCARD
+const DATA: &str = "BEGIN:VCARD
 VERSION:3.0
 FN;CHARSET=UTF-8:John D Doe
 N;CHARSET=UTF-8:Doe;John;D;MR;JR
@@ -58,8 +58,11 @@ fn expected() -> VCard<'static> {
             .name("TYPE")
             .value("png")
             .build()]))
-            .value(Value::Logo(TypeOrRaw::Type(Image::Url(
-                Url::builder().schema("https").domain("testurl").build(),
+            .value(Value::Logo(TypeOrRaw::Type(Image::Uri(
+                Uri::builder()
+                    .scheme("https")
+                    .authority(Authority::builder().host("testurl").build())
+                    .build(),
             ))))
             .build(),
         Property::builder()
@@ -67,8 +70,11 @@ fn expected() -> VCard<'static> {
             .name("TYPE")
             .value("png")
             .build()]))
-            .value(Value::Photo(TypeOrRaw::Type(Image::Url(
-                Url::builder().schema("https").domain("testurl").build(),
+            .value(Value::Photo(TypeOrRaw::Type(Image::Uri(
+                Uri::builder()
+                    .scheme("https")
+                    .authority(Authority::builder().host("testurl").build())
+                    .build(),
             ))))
             .build(),
         Property::builder()
@@ -107,14 +113,22 @@ fn expected() -> VCard<'static> {
                 .name("TYPE")
                 .value("CELL")
                 .build()]))
-            .value(Value::Tel("12345678900".into()))
+            .value(Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("12345678900")
+                    .build(),
+            )))
             .build(),
         Property::builder()
             .params(Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("PAGER")
                 .build()]))
-            .value(Value::Tel("312-555-1515".into()))
+            .value(Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("312-555-1515")
+                    .build(),
+            )))
             .build(),
         Property::builder()
             .params(Parameters(vec![Parameter::builder()
@@ -122,28 +136,44 @@ fn expected() -> VCard<'static> {
                 .value("HOME,VOICE")
                 .build()
             ]))
-            .value(Value::Tel("312-555-1313".into()))
+            .value(Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("312-555-1313")
+                    .build(),
+            )))
             .build(),
         Property::builder()
             .params(Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("WORK,VOICE")
                 .build()]))
-            .value(Value::Tel("312-555-1212".into()))
+            .value(Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("312-555-1212")
+                    .build(),
+            )))
             .build(),
         Property::builder()
             .params(Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("HOME,FAX")
                 .build()]))
-            .value(Value::Tel("312-555-1616".into()))
+            .value(Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("312-555-1616")
+                    .build(),
+            )))
             .build(),
         Property::builder()
             .params(Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("WORK,FAX")
                 .build()]))
-            .value(Value::Tel("312-555-1717".into()))
+            .value(Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("312-555-1717")
+                    .build(),
+            )))
             .build(),
     ])
 }
@@ -1,7 +1,8 @@
+use chrono::TimeZone;
 use rolodex::types::*;
 use rolodex::*;
 
-const DATA: &'static str = "BEGIN:VCARD
+const DATA: &str = "BEGIN:VCARD
 VERSION:2.1
 N:Gump;Forrest;;Mr.
 FN:Forrest Gump
@@ -62,17 +63,19 @@ fn expected() -> VCard<'static> {
             .value(Value::Fn("Forrest Gump".into()))
             .build(),
         Property::builder()
-            .value(Value::Organization("Bubba Gump Shrimp Co.".into()))
+            .value(Value::Organization(TypeOrRaw::Type(
+                Organization::builder().name("Bubba Gump Shrimp Co.").build(),
+            )))
             .build(),
         Property::builder()
             .value(Value::Title("Shrimp Man".into()))
             .build(),
         Property::builder()
             .params(Parameters(vec![Parameter::builder().name("GIF").build()]))
-            .value(Value::Photo(TypeOrRaw::Type(Image::Url(
-                Url::builder()
-                    .schema("http")
-                    .domain("www.example.com")
+            .value(Value::Photo(TypeOrRaw::Type(Image::Uri(
+                Uri::builder()
+                    .scheme("http")
+                    .authority(Authority::builder().host("www.example.com").build())
                     .path("/dir_photos/my_photo.gif")
                     .build(),
             ))))
@@ -82,14 +85,22 @@ fn expected() -> VCard<'static> {
                 Parameter::builder().name("WORK").build(),
                 Parameter::builder().name("VOICE").build(),
             ]))
-            .value(Value::Tel("(111) 555-1212".into()))
+            .value(Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("(111) 555-1212")
+                    .build(),
+            )))
             .build(),
         Property::builder()
             .params(Parameters(vec![
                 Parameter::builder().name("HOME").build(),
                 Parameter::builder().name("VOICE").build(),
             ]))
-            .value(Value::Tel("(404) 555-1212".into()))
+            .value(Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("(404) 555-1212")
+                    .build(),
+            )))
             .build(),
         Property::builder()
             .params(Parameters(vec![
@@ -142,7 +153,9 @@ fn expected() -> VCard<'static> {
             )))
             .build(),
         Property::builder()
-            .value(Value::Rev("20080424T195243Z".into()))
+            .value(Value::Rev(TypeOrRaw::Type(Timestamp(
+                chrono::Utc.with_ymd_and_hms(2008, 4, 24, 19, 52, 43).unwrap(),
+            ))))
             .build(),
     ])
 }
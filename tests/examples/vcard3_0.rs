@@ -1,7 +1,8 @@
+use chrono::TimeZone;
 use rolodex::types::*;
 use rolodex::*;
 
-const DATA: &'static str = "BEGIN:VCARD
+const DATA: &str = "BEGIN:VCARD
 VERSION:3.0
 N:Gump;Forrest;;Mr.;
 FN:Forrest Gump
@@ -62,39 +63,53 @@ fn expected() -> VCard<'static> {
             .value(Value::Fn("Forrest Gump".into()))
             .build(),
         Property::builder()
-            .value(Value::Organization("Bubba Gump Shrimp Co.".into()))
+            .value(Value::Organization(TypeOrRaw::Type(
+                Organization::builder().name("Bubba Gump Shrimp Co.").build(),
+            )))
             .build(),
         Property::builder()
             .value(Value::Title("Shrimp Man".into()))
             .build(),
         Property {
+            group: None,
             params: Parameters(vec![
                 Parameter::builder().name("VALUE").value("URI").build(),
                 Parameter::builder().name("TYPE").value("GIF").build(),
             ]),
-            value: Value::Photo(TypeOrRaw::Type(Image::Url(
-                Url::builder()
-                    .schema("http")
-                    .domain("www.example.com")
+            value: Value::Photo(TypeOrRaw::Type(Image::Uri(
+                Uri::builder()
+                    .scheme("http")
+                    .authority(Authority::builder().host("www.example.com").build())
                     .path("/dir_photos/my_photo.gif")
                     .build(),
             ))),
         },
         Property {
+            group: None,
             params: Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("WORK,VOICE")
                 .build()]),
-            value: Value::Tel("(111) 555-1212".into()),
+            value: Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("(111) 555-1212")
+                    .build(),
+            )),
         },
         Property {
+            group: None,
             params: Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("HOME,VOICE")
                 .build()]),
-            value: Value::Tel("(404) 555-1212".into()),
+            value: Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("(404) 555-1212")
+                    .build(),
+            )),
         },
         Property {
+            group: None,
             params: Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("WORK,PREF")
@@ -102,6 +117,7 @@ fn expected() -> VCard<'static> {
             value: Value::Adr(address1()),
         },
         Property {
+            group: None,
             params: Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("WORK,PREF")
@@ -112,6 +128,7 @@ fn expected() -> VCard<'static> {
             ),
         },
         Property {
+            group: None,
             params: Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("HOME")
@@ -119,6 +136,7 @@ fn expected() -> VCard<'static> {
             value: Value::Adr(address2()),
         },
         Property {
+            group: None,
             params: Parameters(vec![Parameter::builder()
                 .name("TYPE")
                 .value("HOME")
@@ -137,7 +155,9 @@ fn expected() -> VCard<'static> {
             )))
             .build(),
         Property::builder()
-            .value(Value::Rev("2008-04-24T19:52:43Z".into()))
+            .value(Value::Rev(TypeOrRaw::Type(Timestamp(
+                chrono::Utc.with_ymd_and_hms(2008, 4, 24, 19, 52, 43).unwrap(),
+            ))))
             .build(),
     ])
 }
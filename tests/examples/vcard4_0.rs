@@ -1,7 +1,8 @@
+use chrono::TimeZone;
 use rolodex::types::*;
 use rolodex::*;
 
-const DATA: &'static str = "BEGIN:VCARD
+const DATA: &str = "BEGIN:VCARD
 VERSION:4.0
 N:Gump;Forrest;;Mr.;
 FN:Forrest Gump
@@ -61,7 +62,9 @@ fn expected() -> VCard<'static> {
             .value(Value::Fn("Forrest Gump".into()))
             .build(),
         Property::builder()
-            .value(Value::Organization("Bubba Gump Shrimp Co.".into()))
+            .value(Value::Organization(TypeOrRaw::Type(
+                Organization::builder().name("Bubba Gump Shrimp Co.").build(),
+            )))
             .build(),
         Property::builder()
             .value(Value::Title("Shrimp Man".into()))
@@ -71,15 +74,16 @@ fn expected() -> VCard<'static> {
                 .name("MEDIATYPE")
                 .value("image/gif")
                 .build()]))
-            .value(Value::Photo(TypeOrRaw::Type(Image::Url(
-                Url::builder()
-                    .schema("http")
-                    .domain("www.example.com")
+            .value(Value::Photo(TypeOrRaw::Type(Image::Uri(
+                Uri::builder()
+                    .scheme("http")
+                    .authority(Authority::builder().host("www.example.com").build())
                     .path("/dir_photos/my_photo.gif")
                     .build(),
             ))))
             .build(),
         Property {
+            group: None,
             params: Parameters(vec![
                 Parameter::builder()
                     .name("TYPE")
@@ -87,9 +91,15 @@ fn expected() -> VCard<'static> {
                     .build(),
                 Parameter::builder().name("VALUE").value("uri").build(),
             ]),
-            value: Value::Tel("tel:+1-111-555-1212".into()),
+            value: Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("+1-111-555-1212")
+                    .uri(true)
+                    .build(),
+            )),
         },
         Property {
+            group: None,
             params: Parameters(vec![
                 Parameter::builder()
                     .name("TYPE")
@@ -97,9 +107,15 @@ fn expected() -> VCard<'static> {
                     .build(),
                 Parameter::builder().name("VALUE").value("uri").build(),
             ]),
-            value: Value::Tel("tel:+1-404-555-1212".into()),
+            value: Value::Tel(TypeOrRaw::Type(
+                Telephone::builder()
+                    .number("+1-404-555-1212")
+                    .uri(true)
+                    .build(),
+            )),
         },
         Property {
+            group: None,
             params: Parameters(vec![
                 Parameter::builder().name("TYPE").value("WORK").build(),
                 Parameter::builder().name("PREF").value("1").build(),
@@ -111,6 +127,7 @@ fn expected() -> VCard<'static> {
             value: Value::Adr(address1()),
         },
         Property {
+            group: None,
             params: Parameters(vec![
                 Parameter::builder().name("TYPE").value("HOME").build(),
                 Parameter::builder()
@@ -129,7 +146,9 @@ fn expected() -> VCard<'static> {
             )))
             .build(),
         Property::builder()
-            .value(Value::Rev("20080424T195243Z".into()))
+            .value(Value::Rev(TypeOrRaw::Type(Timestamp(
+                chrono::Utc.with_ymd_and_hms(2008, 4, 24, 19, 52, 43).unwrap(),
+            ))))
             .build(),
         Property::builder()
             .value(Value::Other("x-qq".into(), "21588891".into()))
@@ -0,0 +1,135 @@
+use rolodex::formats::csv::{self, AddressPart, Columns, Field, Selector};
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+N:Gump;Forrest;;Mr.;
+ORG:Bubba Gump Shrimp Co.
+TITLE:Shrimp Man
+EMAIL;TYPE=work;PREF=1:forrestgump@example.com
+TEL;TYPE=work,voice:+1-111-555-1212
+TEL;TYPE=cell:+1-404-555-1212
+ADR;TYPE=work:;;100 Waters Edge;Baytown;LA;30314;United States of America
+BDAY:19850412
+END:VCARD\r\n";
+
+#[test]
+fn google() {
+    let vcf = Vcf(vec![VCard::parse(DATA).unwrap()]);
+    let output = csv::vcf_to_string(&vcf, &Columns::google());
+    let mut lines = output.lines();
+    assert!(lines
+        .next()
+        .unwrap()
+        .starts_with("Name,Given Name,Additional Name,Family Name"));
+    assert_eq!(
+        "Forrest Gump,Forrest,,Gump,,Mr.,,1985-04-12,,* Work,forrestgump@example.com,,,\
+         Work,+1-111-555-1212,Mobile,+1-404-555-1212,,,Work,100 Waters Edge,Baytown,,LA,\
+         30314,United States of America,,Bubba Gump Shrimp Co.,Shrimp Man,,",
+        lines.next().unwrap()
+    );
+    let vcard = &csv::vcf_from_str(&output, &Columns::google()).unwrap().0[0];
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:Forrest Gump",
+            "N:Gump;Forrest;;Mr.;",
+            "ORG:Bubba Gump Shrimp Co.",
+            "TITLE:Shrimp Man",
+            "EMAIL;TYPE=work;PREF=1:forrestgump@example.com",
+            "TEL;TYPE=work:+1-111-555-1212",
+            "TEL;TYPE=cell:+1-404-555-1212",
+            "ADR;TYPE=work:;;100 Waters Edge;Baytown;LA;30314;United States of America",
+            "BDAY:19850412",
+        ],
+        vcard.iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn outlook() {
+    let vcf = Vcf(vec![VCard::parse(DATA).unwrap()]);
+    let output = csv::vcf_to_string(&vcf, &Columns::outlook());
+    let mut rows = output.lines().map(|x| x.split(',').collect::<Vec<_>>());
+    let (headers, row) = (rows.next().unwrap(), rows.next().unwrap());
+    let birthday = headers.iter().position(|x| *x == "Birthday").unwrap();
+    assert_eq!("1985-04-12", row[birthday]);
+    let vcard = &csv::vcf_from_str(&output, &Columns::outlook()).unwrap().0[0];
+    let lines = vcard.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    assert!(lines.contains(&"FN:Mr. Forrest Gump".to_string()));
+    assert!(lines.contains(&"TEL;TYPE=work:+1-111-555-1212".to_string()));
+    assert!(lines.contains(&"TEL;TYPE=cell:+1-404-555-1212".to_string()));
+    assert!(lines.contains(
+        &"ADR;TYPE=work:;;100 Waters Edge;Baytown;LA;30314;United States of America".to_string()
+    ));
+    assert!(lines.contains(&"BDAY:19850412".to_string()));
+}
+
+#[test]
+fn outlook_fax() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD\nVERSION:4.0\nFN:Jane\nTEL;TYPE=work,fax:+1-555-0100\nTEL;TYPE=work,voice:+1-555-0101\nEND:VCARD\n",
+    )
+    .unwrap();
+    let output = csv::vcf_to_string(&Vcf(vec![vcard]), &Columns::outlook());
+    let vcard = &csv::vcf_from_str(&output, &Columns::outlook()).unwrap().0[0];
+    let tels = vcard
+        .iter()
+        .filter(|x| matches!(x.value, Value::Tel(_)))
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec!["TEL;TYPE=work:+1-555-0101", "TEL;TYPE=work,fax:+1-555-0100"],
+        tels
+    );
+}
+
+#[test]
+fn vcard2_1_telephones() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD\nVERSION:2.1\nFN:Jane\nTEL;HOME;VOICE:+1-555-0100\nTEL;CELL:+1-555-0101\nTEL:+1-555-0102\nEND:VCARD\n",
+    )
+    .unwrap();
+    let vcf = Vcf(vec![vcard]);
+    let output = csv::vcf_to_string(&vcf, &Columns::google());
+    assert!(output.contains(",Home,+1-555-0100,Mobile,+1-555-0101,,+1-555-0102,"));
+    let output = csv::vcf_to_string(&vcf, &Columns::outlook());
+    let mut rows = output.lines().map(|x| x.split(',').collect::<Vec<_>>());
+    let (headers, row) = (rows.next().unwrap(), rows.next().unwrap());
+    let cell = |header| row[headers.iter().position(|x| *x == header).unwrap()];
+    assert_eq!("+1-555-0100", cell("Home Phone"));
+    assert_eq!("+1-555-0101", cell("Mobile Phone"));
+    assert_eq!("+1-555-0102", cell("Other Phone"));
+}
+
+#[test]
+fn custom() {
+    let columns = Columns::new()
+        .column("Full name", Field::FormattedName)
+        .column("Mail", Field::Email(Selector::typed(&["home"])))
+        .column(
+            "Town",
+            Field::Address(Selector::nth(0), AddressPart::Locality),
+        )
+        .column("Shoe size", Field::Property("X-SHOE-SIZE".into()));
+    let input = "full name,mail,town,shoe size,ignored\n\
+                 \"Doe, Jane\",jane@example.com,Springfield,38,x\n\
+                 ,,,,\n";
+    let vcf = csv::vcf_from_str(input, &columns).unwrap();
+    assert_eq!(1, vcf.0.len());
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:Doe\\, Jane",
+            "EMAIL;TYPE=home:jane@example.com",
+            "ADR:;;;Springfield;;;",
+            "X-SHOE-SIZE:38",
+        ],
+        vcf.0[0].iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        "Full name,Mail,Town,Shoe size\n\"Doe, Jane\",jane@example.com,Springfield,38\n",
+        csv::vcf_to_string(&vcf, &columns)
+    );
+}
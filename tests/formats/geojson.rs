@@ -0,0 +1,55 @@
+use rolodex::formats::geojson;
+use rolodex::*;
+use serde_json::json;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+GEO:geo:30.0,-94.9,12
+ADR:;;42 Plantation St.;Baytown;LA;30314;USA
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Jenny Curran
+ADR;GEO=\"geo:51.5074,-0.1278\":;;10 Downing St;London;;;UK
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Bubba Blue
+END:VCARD
+";
+
+#[test]
+fn write() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    assert_eq!(
+        json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": {"type": "Point", "coordinates": [-94.9, 30.0, 12.0]},
+                    "properties": {
+                        "name": "Forrest Gump",
+                        "address": "42 Plantation St., Baytown, LA, 30314, USA"
+                    }
+                },
+                {
+                    "type": "Feature",
+                    "geometry": {"type": "Point", "coordinates": [-0.1278, 51.5074]},
+                    "properties": {"name": "Jenny Curran", "address": "10 Downing St, London, UK"}
+                }
+            ]
+        }),
+        geojson::vcf_to_value(&vcf)
+    );
+}
+
+#[test]
+fn unpositioned() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    assert_eq!(
+        r#"{"type":"FeatureCollection","features":[]}"#,
+        geojson::to_string(&vcf.0[2])
+    );
+}
@@ -0,0 +1,133 @@
+use rolodex::formats::hcard;
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+N:Gump;Forrest;;Mr.;
+ORG:Bubba Gump Shrimp Co.
+TITLE:Shrimp Man
+EMAIL:forrestgump@example.com
+TEL:+1-111-555-1212
+ADR:;;100 Waters Edge;Baytown;LA;30314;United States of America
+URL:http://www.example.com
+BDAY:19850412
+NOTE:Life is like a box of chocolates\\, you never know
+END:VCARD\r\n";
+
+#[test]
+fn write() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(
+        "<div class=\"h-card\">
+<span class=\"p-name\">Forrest Gump</span>
+<data class=\"p-family-name\" value=\"Gump\"></data>
+<data class=\"p-given-name\" value=\"Forrest\"></data>
+<data class=\"p-honorific-prefix\" value=\"Mr.\"></data>
+<span class=\"p-org\">Bubba Gump Shrimp Co.</span>
+<span class=\"p-job-title\">Shrimp Man</span>
+<a class=\"u-email\" href=\"mailto:forrestgump@example.com\">forrestgump@example.com</a>
+<a class=\"p-tel\" href=\"tel:+1-111-555-1212\">+1-111-555-1212</a>
+<div class=\"p-adr h-adr\"><span class=\"p-street-address\">100 Waters Edge</span> \
+<span class=\"p-locality\">Baytown</span> <span class=\"p-region\">LA</span> \
+<span class=\"p-postal-code\">30314</span> \
+<span class=\"p-country-name\">United States of America</span></div>
+<a class=\"u-url\" href=\"http://www.example.com\">http://www.example.com</a>
+<time class=\"dt-bday\" datetime=\"1985-04-12\">1985-04-12</time>
+<p class=\"p-note\">Life is like a box of chocolates, you never know</p>
+</div>
+",
+        hcard::to_string(&vcard)
+    );
+}
+
+#[test]
+fn unsafe_links() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD\nVERSION:4.0\nFN:Jane\nURL:javascript:alert(1)\nPHOTO:data:text/html;base64,PHNjcmlwdD4=\nLOGO:data:image/png;base64,iVBORw0=\nEND:VCARD\n",
+    )
+    .unwrap();
+    let html = hcard::to_string(&vcard);
+    assert!(!html.contains("href=\"javascript:"));
+    assert!(html.contains("<span class=\"u-url\">javascript:alert(1)</span>"));
+    assert!(html.contains("<span class=\"u-photo\">data:text/html;base64,PHNjcmlwdD4=</span>"));
+    assert!(html.contains("<img class=\"u-logo\" src=\"data:image/png;base64,iVBORw0=\" alt=\"\">"));
+}
+
+#[test]
+fn round_trip() {
+    let vcard = VCard::parse(DATA).unwrap();
+    let html = format!("<html><body>{}</body></html>", hcard::to_string(&vcard));
+    assert_eq!(Ok(vcard), hcard::from_str(&html));
+}
+
+#[test]
+fn read() {
+    let html = r#"<!DOCTYPE html>
+<html><body>
+  <article class="h-entry">
+    <a class="p-author h-card" href="https://example.com/jane">
+      <img src="jane.jpg" alt="Jane Doe">
+    </a>
+  </article>
+  <div class="h-card">
+    <span class="p-name">John <b>Doe</b></span>
+    <span class="p-tel"><span class="value">+1</span>-<span class="value">555-0100</span></span>
+    <a class="u-email" href="mailto:john@example.com">Mail</a>
+    <p class="p-adr h-adr" title="ignored">
+      <span class="p-locality">Springfield</span>,
+      <abbr class="p-country-name" title="United States">US</abbr>
+    </p>
+    <span class="p-geo h-geo">
+      <data class="p-latitude" value="37.386013"></data>
+      <data class="p-longitude" value="-122.082932"></data>
+    </span>
+    <time class="dt-bday" datetime="--04-12">April 12</time>
+    <div class="h-card"><span class="p-name">Nested child</span></div>
+  </div>
+</body></html>"#;
+    let vcf = hcard::vcf_from_str(html).unwrap();
+    assert_eq!(2, vcf.0.len());
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:Jane Doe",
+            "URL:https://example.com/jane",
+            "PHOTO:jane.jpg",
+        ],
+        vcf.0[0].iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:John Doe",
+            "EMAIL:john@example.com",
+            "TEL:+1555-0100",
+            "ADR:;;;Springfield;;;United States",
+            "GEO:geo:37.386013,-122.082932",
+            "BDAY:--0412",
+        ],
+        vcf.0[1].iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    assert!(hcard::from_str("<p>nothing</p>").is_err());
+}
+
+#[test]
+fn mailto_email() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nEMAIL:mailto:jane@example.com\nEND:VCARD\n").unwrap();
+    assert!(hcard::to_string(&vcard).contains(
+        "<a class=\"u-email\" href=\"mailto:jane@example.com\">jane@example.com</a>"
+    ));
+}
+
+#[test]
+fn social_profiles() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nSOCIALPROFILE:@jdoe@Example.com\nEND:VCARD\n",
+    )
+    .unwrap();
+    let html = hcard::to_string(&vcard);
+    assert!(html.contains(
+        "<a class=\"u-url\" href=\"https://example.com/@jdoe\">@jdoe@Example.com</a>"
+    ));
+}
@@ -0,0 +1,86 @@
+use chrono::{Duration, NaiveDate};
+use rolodex::formats::icalendar::{self, Options};
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+UID:urn:uuid:a9d5c4c5-6ab0-4a0e-9a31-2f1c6c7b9e2d
+BDAY:19850412
+ANNIVERSARY:--0415
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Jenny Curran
+BDAY;VALUE=DATE:1964-02-29
+END:VCARD\r\n";
+
+fn options() -> Options {
+    Options {
+        stamp: NaiveDate::from_ymd_opt(2021, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap(),
+        alarm: None,
+    }
+}
+
+#[test]
+fn write() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    assert_eq!(
+        "BEGIN:VCALENDAR\r\n\
+         VERSION:2.0\r\n\
+         PRODID:-//rolodex//Birthdays//EN\r\n\
+         CALSCALE:GREGORIAN\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:a9d5c4c5-6ab0-4a0e-9a31-2f1c6c7b9e2d-birthday@rolodex\r\n\
+         DTSTAMP:20210101T000000Z\r\n\
+         DTSTART;VALUE=DATE:19850412\r\n\
+         RRULE:FREQ=YEARLY\r\n\
+         SUMMARY:Birthday: Forrest Gump\r\n\
+         TRANSP:TRANSPARENT\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:a9d5c4c5-6ab0-4a0e-9a31-2f1c6c7b9e2d-anniversary@rolodex\r\n\
+         DTSTAMP:20210101T000000Z\r\n\
+         DTSTART;VALUE=DATE:20000415\r\n\
+         RRULE:FREQ=YEARLY\r\n\
+         SUMMARY:Anniversary: Forrest Gump\r\n\
+         TRANSP:TRANSPARENT\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:3630f020495b16cd-birthday@rolodex\r\n\
+         DTSTAMP:20210101T000000Z\r\n\
+         DTSTART;VALUE=DATE:19640229\r\n\
+         RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=-1\r\n\
+         SUMMARY:Birthday: Jenny Curran\r\n\
+         TRANSP:TRANSPARENT\r\n\
+         END:VEVENT\r\n\
+         END:VCALENDAR\r\n",
+        icalendar::vcf_to_string(&vcf, &options())
+    );
+}
+
+#[test]
+fn alarm() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    let options = Options {
+        alarm: Some(Duration::days(1) + Duration::hours(2)),
+        ..options()
+    };
+    let calendar = icalendar::to_string(&vcf.0[1], &options);
+    assert!(calendar.contains(
+        "BEGIN:VALARM\r\n\
+         ACTION:DISPLAY\r\n\
+         DESCRIPTION:Birthday: Jenny Curran\r\n\
+         TRIGGER:-P1DT2H\r\n\
+         END:VALARM\r\n"
+    ));
+    // The same card gets the same UIDs every time.
+    assert_eq!(calendar, icalendar::to_string(&vcf.0[1], &options));
+    // However its date is written.
+    let basic = DATA.replace("BDAY;VALUE=DATE:1964-02-29", "BDAY:19640229");
+    let (_, vcf) = Vcf::parse(&basic).unwrap();
+    assert_eq!(calendar, icalendar::to_string(&vcf.0[1], &options));
+}
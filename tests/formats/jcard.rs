@@ -0,0 +1,99 @@
+use rolodex::formats::jcard;
+use rolodex::*;
+
+use serde_json::json;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+N:Gump;Forrest;;Mr.;
+FN:Forrest Gump
+NICKNAME:Forrest,Gump
+ORG:Bubba Gump Shrimp Co.
+PHOTO;MEDIATYPE=image/gif:http://www.example.com/dir_photos/my_photo.gif
+TEL;TYPE=work,voice;VALUE=uri:tel:+1-111-555-1212
+ADR;TYPE=WORK;LABEL=\"100 Waters Edge\\nBaytown\\, LA 30314\":;;100 Waters Edge;Baytown;LA;30314;United States of America
+EMAIL:forrestgump@example.com
+NOTE:Life is like a box of chocolates\\, you never know
+BDAY:--0412
+REV:20080424T195243Z
+X-QQ:21588891
+END:VCARD\r\n";
+
+fn expected() -> serde_json::Value {
+    json!(["vcard", [
+        ["version", {}, "text", "4.0"],
+        ["n", {}, "text", ["Gump", "Forrest", "", "Mr.", ""]],
+        ["fn", {}, "text", "Forrest Gump"],
+        ["nickname", {}, "text", "Forrest", "Gump"],
+        ["org", {}, "text", "Bubba Gump Shrimp Co."],
+        ["photo", {"mediatype": "image/gif"}, "uri", "http://www.example.com/dir_photos/my_photo.gif"],
+        ["tel", {"type": ["work", "voice"]}, "uri", "tel:+1-111-555-1212"],
+        ["adr", {"type": "WORK", "label": "100 Waters Edge\nBaytown, LA 30314"}, "text",
+            ["", "", "100 Waters Edge", "Baytown", "LA", "30314", "United States of America"]],
+        ["email", {}, "text", "forrestgump@example.com"],
+        ["note", {}, "text", "Life is like a box of chocolates, you never know"],
+        ["bday", {}, "date-and-or-time", "--04-12"],
+        ["rev", {}, "timestamp", "2008-04-24T19:52:43Z"],
+        ["x-qq", {}, "unknown", "21588891"]
+    ]])
+}
+
+#[test]
+fn write() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(expected(), jcard::to_value(&vcard));
+}
+
+#[test]
+fn round_trip() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(Ok(vcard.clone()), jcard::from_str(&jcard::to_string(&vcard)));
+}
+
+#[test]
+fn read() {
+    let data = json!(["vcard", [
+        ["version", {}, "text", "4.0"],
+        ["fn", {}, "text", "J. Doe"],
+        ["email", {"group": "item1", "type": ["work", "internet"]}, "text", "jdoe@example.com"],
+        ["bday", {}, "date", "1985-04-12"],
+        ["x-karma-points", {}, "integer", 42]
+    ]]);
+    let vcard = jcard::from_value(&data).unwrap();
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:J. Doe",
+            "item1.EMAIL;TYPE=work,internet:jdoe@example.com",
+            "BDAY;VALUE=date:19850412",
+            "X-KARMA-POINTS;VALUE=integer:42",
+        ],
+        vcard.iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn vcf() {
+    let data = "[[\"vcard\",[[\"fn\",{},\"text\",\"A\"]]],[\"vcard\",[[\"fn\",{},\"text\",\"B\"]]]]";
+    let vcf = jcard::vcf_from_str(data).unwrap();
+    assert_eq!(2, vcf.0.len());
+    assert!(jcard::from_str("[\"vcard\"]").is_err());
+}
+
+#[test]
+fn trailing_backslash() {
+    let data = json!(["vcard", [
+        ["version", {}, "text", "4.0"],
+        ["note", {}, "text", "C:\\"],
+        ["fn", {}, "text", "A"]
+    ]]);
+    let vcard = jcard::from_value(&data).unwrap();
+    assert_eq!(
+        vec!["VERSION:4.0", "NOTE:C:\\\\", "FN:A"],
+        vcard.iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    assert_eq!(data, jcard::to_value(&vcard));
+
+    let vcard = VCard::parse("BEGIN:VCARD\nNOTE:C:\\\\\nFN:A\nEND:VCARD\n").unwrap();
+    assert_eq!(2, vcard.iter().count());
+}
@@ -0,0 +1,125 @@
+use rolodex::formats::jscontact;
+use rolodex::*;
+
+use serde_json::json;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+KIND:individual
+UID:urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6
+FN:Forrest Gump
+N:Gump;Forrest;;Mr.;
+NICKNAME:Forrest
+ORG:Bubba Gump Shrimp Co.;Sales
+TITLE:Shrimp Man
+EMAIL;TYPE=work;PREF=1:forrestgump@example.com
+TEL;TYPE=work,voice;VALUE=uri:tel:+1-111-555-1212
+ADR;TYPE=work;LABEL=\"100 Waters Edge\\nBaytown\\, LA 30314\":;;100 Waters Edge;Baytown;LA;30314;United States of America
+IMPP;PREF=1:xmpp:forrest@example.com
+URL:http://www.example.com
+BDAY:--0412
+CATEGORIES:shrimp,running
+NOTE;LANGUAGE=en:Life is like a box of chocolates\\, you never know
+REV:20080424T195243Z
+X-QQ:21588891
+END:VCARD\r\n";
+
+#[test]
+fn write() {
+    let vcard = VCard::parse(DATA).unwrap();
+    let card = jscontact::to_value(&vcard);
+    assert_eq!(json!("Card"), card["@type"]);
+    assert_eq!(json!("individual"), card["kind"]);
+    assert_eq!(
+        json!({
+            "full": "Forrest Gump",
+            "components": [
+                {"kind": "surname", "value": "Gump"},
+                {"kind": "given", "value": "Forrest"},
+                {"kind": "title", "value": "Mr."}
+            ]
+        }),
+        card["name"]
+    );
+    assert_eq!(
+        json!({"name": "Bubba Gump Shrimp Co.", "units": [{"name": "Sales"}]}),
+        card["organizations"]["o1"]
+    );
+    assert_eq!(
+        json!({"address": "forrestgump@example.com", "contexts": {"work": true}, "pref": 1}),
+        card["emails"]["e1"]
+    );
+    assert_eq!(
+        json!({
+            "number": "tel:+1-111-555-1212",
+            "features": {"voice": true},
+            "contexts": {"work": true}
+        }),
+        card["phones"]["p1"]
+    );
+    assert_eq!(json!("Baytown"), card["addresses"]["a1"]["components"][1]["value"]);
+    assert_eq!(
+        json!("100 Waters Edge\nBaytown, LA 30314"),
+        card["addresses"]["a1"]["full"]
+    );
+    assert_eq!(
+        json!({"@type": "PartialDate", "month": 4, "day": 12}),
+        card["anniversaries"]["d1"]["date"]
+    );
+    assert_eq!(json!({"language": "en"}), card["notes"]["n1"]["vCardParams"]);
+    assert_eq!(json!("2008-04-24T19:52:43Z"), card["updated"]);
+    assert_eq!(
+        json!([["x-qq", {}, "unknown", "21588891"]]),
+        card["vCardProps"]
+    );
+}
+
+#[test]
+fn round_trip() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(
+        Ok(vcard.clone()),
+        jscontact::from_str(&jscontact::to_string(&vcard))
+    );
+}
+
+#[test]
+fn read() {
+    let data = json!({
+        "@type": "Card",
+        "version": "1.0",
+        "name": {"full": "J. Doe"},
+        "onlineServices": {
+            "s1": {"service": "Mastodon", "uri": "https://example.com/@jdoe"}
+        },
+        "anniversaries": {
+            "k1": {"kind": "birth", "date": {"@type": "PartialDate", "year": 1985, "month": 4, "day": 12}}
+        }
+    });
+    let vcard = jscontact::from_value(&data).unwrap();
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:J. Doe",
+            "SOCIALPROFILE;SERVICE-TYPE=Mastodon:https://example.com/@jdoe",
+            "BDAY:19850412",
+        ],
+        vcard.iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    assert!(jscontact::from_str("{\"@type\": \"Group\"}").is_err());
+}
+
+#[test]
+fn mailto_email() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nEMAIL:mailto:jane@example.com\nEND:VCARD\n").unwrap();
+    let emails = &jscontact::to_value(&vcard)["emails"];
+    assert_eq!(json!("jane@example.com"), emails["e1"]["address"]);
+}
+
+#[test]
+fn text_birthday() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nBDAY;VALUE=text:日本語\nEND:VCARD\n").unwrap();
+    let card = jscontact::to_value(&vcard);
+    assert_eq!(None, card.get("anniversaries"));
+    assert_eq!(Ok(vcard), jscontact::from_value(&card));
+}
@@ -0,0 +1,135 @@
+use rolodex::formats::jsonld;
+use rolodex::*;
+
+use serde_json::json;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+N:Gump;Forrest;;Mr.;
+ORG:Bubba Gump Shrimp Co.
+TITLE:Shrimp Man
+EMAIL:forrestgump@example.com
+TEL;TYPE=work:+1-111-555-1212
+ADR:;;100 Waters Edge;Baytown;LA;30314;United States of America
+GEO:30.0,-94.9
+URL:https://twitter.com/forrest
+URL:https://example.com/forrest
+BDAY:19850412
+END:VCARD\r\n";
+
+#[test]
+fn person() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(
+        json!({
+            "@context": "https://schema.org",
+            "@type": "Person",
+            "name": "Forrest Gump",
+            "familyName": "Gump",
+            "givenName": "Forrest",
+            "honorificPrefix": "Mr.",
+            "worksFor": {"@type": "Organization", "name": "Bubba Gump Shrimp Co."},
+            "jobTitle": "Shrimp Man",
+            "email": "forrestgump@example.com",
+            "telephone": "+1-111-555-1212",
+            "address": {
+                "@type": "PostalAddress",
+                "streetAddress": "100 Waters Edge",
+                "addressLocality": "Baytown",
+                "addressRegion": "LA",
+                "postalCode": "30314",
+                "addressCountry": "United States of America"
+            },
+            "geo": {"@type": "GeoCoordinates", "latitude": 30.0, "longitude": -94.9},
+            "sameAs": ["https://twitter.com/forrest", "https://example.com/forrest"],
+            "birthDate": "1985-04-12"
+        }),
+        jsonld::to_value(&vcard)
+    );
+}
+
+#[test]
+fn organization() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nKIND:org\nFN:ABC\\, Inc.\nORG:ABC\\, Inc.;North American Division\nEND:VCARD\n").unwrap();
+    assert_eq!(
+        json!({
+            "@context": "https://schema.org",
+            "@type": "Organization",
+            "name": "ABC, Inc.",
+            "legalName": "ABC, Inc.",
+            "department": {"@type": "Organization", "name": "North American Division"}
+        }),
+        jsonld::to_value(&vcard)
+    );
+    let read = jsonld::from_value(&jsonld::to_value(&vcard)).unwrap();
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "KIND:org",
+            "FN:ABC\\, Inc.",
+            "ORG:ABC\\, Inc.;North American Division",
+        ],
+        read.iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn read() {
+    let data = r#"{
+        "@context": "https://schema.org",
+        "@graph": [
+            {"@type": "WebPage", "name": "About"},
+            {
+                "@type": "Person",
+                "name": "Jane Doe",
+                "email": "mailto:jane@example.com",
+                "telephone": ["+1-555-0100", "+1-555-0101"],
+                "address": {"@type": "PostalAddress", "addressLocality": "Seattle"},
+                "geo": {"latitude": "47.6", "longitude": "-122.3"},
+                "url": "https://example.com/jane",
+                "image": {"@type": "ImageObject", "contentUrl": "https://example.com/jane.jpg"},
+                "gender": "https://schema.org/Female"
+            }
+        ]
+    }"#;
+    let vcf = jsonld::vcf_from_str(data).unwrap();
+    assert_eq!(1, vcf.0.len());
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:Jane Doe",
+            "EMAIL:jane@example.com",
+            "TEL:+1-555-0100",
+            "TEL:+1-555-0101",
+            "ADR:;;;Seattle;;;",
+            "GEO:geo:47.6,-122.3",
+            "URL:https://example.com/jane",
+            "GENDER:F;",
+            "PHOTO:https://example.com/jane.jpg",
+        ],
+        vcf.0[0].iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    assert!(jsonld::from_str("{\"@type\": \"Event\"}").is_err());
+}
+
+#[test]
+fn mailto_email() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nEMAIL:mailto:jane@example.com\nEND:VCARD\n").unwrap();
+    assert_eq!(json!("jane@example.com"), jsonld::to_value(&vcard)["email"]);
+}
+
+#[test]
+fn vcard2_1_fax() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:2.1\nFN:Jane Doe\nTEL;WORK;FAX:+1-555-0100\nEND:VCARD\n").unwrap();
+    assert_eq!(json!("+1-555-0100"), jsonld::to_value(&vcard)["faxNumber"]);
+}
+
+#[test]
+fn social_profiles() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nSOCIALPROFILE:@jdoe@Example.com\nX-SOCIALPROFILE;TYPE=twitter:https://x.com/jdoe\nEND:VCARD\n").unwrap();
+    assert_eq!(
+        json!(["https://example.com/@jdoe", "https://x.com/jdoe"]),
+        jsonld::to_value(&vcard)["sameAs"]
+    );
+}
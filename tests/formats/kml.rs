@@ -0,0 +1,37 @@
+use rolodex::formats::kml;
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest & Jenny
+GEO:geo:30.0,-94.9
+ADR:;;42 Plantation St.;Baytown;LA;30314;USA
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Bubba Blue
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Dan Taylor
+GEO:geo:120,0;crs=moon
+END:VCARD
+";
+
+#[test]
+fn write() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    assert_eq!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <kml xmlns=\"http://www.opengis.net/kml/2.2\">\n\
+         <Document>\n\
+         <Placemark>\n\
+         <name>Forrest &amp; Jenny</name>\n\
+         <address>42 Plantation St., Baytown, LA, 30314, USA</address>\n\
+         <Point><coordinates>-94.9,30</coordinates></Point>\n\
+         </Placemark>\n\
+         </Document>\n\
+         </kml>\n",
+        kml::vcf_to_string(&vcf)
+    );
+}
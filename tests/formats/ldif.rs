@@ -0,0 +1,140 @@
+use rolodex::formats::ldif;
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+N:Gump;Forrest;;Mr.;
+ORG:Bubba Gump Shrimp Co.
+TITLE:Shrimp Man
+EMAIL;PREF=1:forrestgump@example.com
+TEL;TYPE=work,voice:+1-111-555-1212
+TEL;TYPE=cell:+1-404-555-1212
+ADR;TYPE=home:;;42 Plantation St.;Baytown;LA;30314;United States of America
+NOTE:Life is like a box of chocolates\\nYou never know
+REV:20080424T195243Z
+END:VCARD\r\n";
+
+const LDIF: &str = "dn: cn=Forrest Gump,mail=forrestgump@example.com
+objectclass: top
+objectclass: person
+objectclass: organizationalPerson
+objectclass: inetOrgPerson
+objectclass: mozillaAbPersonAlpha
+cn: Forrest Gump
+givenName: Forrest
+sn: Gump
+o: Bubba Gump Shrimp Co.
+title: Shrimp Man
+telephoneNumber: +1-111-555-1212
+mobile: +1-404-555-1212
+description:: TGlmZSBpcyBsaWtlIGEgYm94IG9mIGNob2NvbGF0ZXMKWW91IG5ldmVyIGtub3
+ c=
+modifytimestamp: 20080424195243Z
+mail: forrestgump@example.com
+mozillaHomeStreet: 42 Plantation St.
+mozillaHomeLocalityName: Baytown
+mozillaHomeState: LA
+mozillaHomePostalCode: 30314
+mozillaHomeCountryName: United States of America
+";
+
+#[test]
+fn write() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(LDIF, ldif::to_string(&vcard));
+}
+
+#[test]
+fn read() {
+    let vcard = ldif::from_str(LDIF).unwrap();
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:Forrest Gump",
+            "N:Gump;Forrest;;;",
+            "ORG:Bubba Gump Shrimp Co.",
+            "TITLE:Shrimp Man",
+            "EMAIL;PREF=1:forrestgump@example.com",
+            "TEL;TYPE=work:+1-111-555-1212",
+            "TEL;TYPE=cell:+1-404-555-1212",
+            "ADR;TYPE=home:;;42 Plantation St.;Baytown;LA;30314;United States of America",
+            "NOTE:Life is like a box of chocolates\\nYou never know",
+            "REV:20080424T195243Z",
+        ],
+        vcard.iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn thunderbird() {
+    let data = "version: 1
+dn: cn=J. Doe,mail=jdoe@example.com
+objectclass: top
+objectclass: mozillaAbPersonAlpha
+cn:: Si4gRG/DqQ==
+mail: jdoe@
+ example.com
+mozillaSecondEmail: john@example.com
+postalAddress: 100 Waters Edge$Baytown, LA 30314
+birthmonth: 4
+birthday: 12
+
+dn: cn=Other
+cn: Other
+";
+    let vcf = ldif::vcf_from_str(data).unwrap();
+    assert_eq!(2, vcf.0.len());
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:J. Doé",
+            "EMAIL;PREF=1:jdoe@example.com",
+            "EMAIL:john@example.com",
+            "ADR;TYPE=work;LABEL=\"100 Waters Edge\\nBaytown\\, LA 30314\":;;;;;;",
+            "BDAY:--0412",
+        ],
+        vcf.0[0].iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    assert!(ldif::from_str("dn: cn=x\nbroken\n").is_err());
+}
+
+#[test]
+fn partial_birthday() {
+    let bday = |data: &str| {
+        let vcard = ldif::from_str(data).unwrap();
+        let bday = vcard
+            .iter()
+            .find(|x| matches!(x.value, Value::Birthday(_)))
+            .map(|x| x.value.to_string());
+        bday
+    };
+    assert_eq!(
+        Some("1985-04".to_string()),
+        bday("dn: cn=A\ncn: A\nbirthyear: 1985\nbirthmonth: 4\n")
+    );
+    assert_eq!(Some("--04".to_string()), bday("dn: cn=A\ncn: A\nbirthmonth: 4\n"));
+    assert_eq!(Some("1985".to_string()), bday("dn: cn=A\ncn: A\nbirthyear: 1985\n"));
+}
+
+#[test]
+fn malformed_timestamp() {
+    let vcard = ldif::from_str("dn: cn=A\ncn: A\nmodifytimestamp:: MjAwODA0MsOpMTk1MjQzWg==\n").unwrap();
+    assert!(!vcard.iter().any(|x| matches!(x.value, Value::Rev(_))));
+}
+
+#[test]
+fn mailto_email() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nEMAIL:mailto:jane@example.com\nEND:VCARD\n").unwrap();
+    let ldif = ldif::to_string(&vcard);
+    assert!(ldif.contains("dn: cn=Jane Doe,mail=jane@example.com\n"));
+    assert!(ldif.contains("mail: jane@example.com\n"));
+}
+
+#[test]
+fn vcard2_1_telephones() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:2.1\nFN:Jane Doe\nTEL;HOME;FAX:+1-555-0100\nTEL;CELL:+1-555-0101\nEND:VCARD\n").unwrap();
+    let ldif = ldif::to_string(&vcard);
+    assert!(ldif.contains("facsimileTelephoneNumber: +1-555-0100\n"));
+    assert!(ldif.contains("mobile: +1-555-0101\n"));
+}
@@ -0,0 +1,109 @@
+use rolodex::formats::mecard::{self, Representation};
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+N:Gump;Forrest;;Mr.;
+ORG:Bubba Gump Shrimp Co.
+TITLE:Shrimp Man
+TEL;TYPE=work:+1-111-555-1212
+TEL;TYPE=cell:+1-404-555-1212
+EMAIL:forrestgump@example.com
+ADR:;;100 Waters Edge;Baytown;LA;30314;United States of America
+URL:http://www.example.com
+BDAY:19850412
+NOTE:Life is like a box of chocolates\\, you never know
+END:VCARD\r\n";
+
+#[test]
+fn write() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(
+        "MECARD:N:Gump,Forrest;ORG:Bubba Gump Shrimp Co.;TEL:+1-111-555-1212;\
+         TEL:+1-404-555-1212;EMAIL:forrestgump@example.com;\
+         ADR:,,100 Waters Edge,Baytown,LA,30314,United States of America;\
+         URL:http\\://www.example.com;BDAY:19850412;\
+         NOTE:Life is like a box of chocolates\\, you never know;;",
+        mecard::to_string(&vcard)
+    );
+    assert_eq!(
+        "BIZCARD:N:Forrest;X:Gump;C:Bubba Gump Shrimp Co.;T:Shrimp Man;\
+         B:+1-111-555-1212;M:+1-404-555-1212;E:forrestgump@example.com;\
+         A:100 Waters Edge\\, Baytown\\, LA\\, 30314\\, United States of America;;",
+        mecard::bizcard_to_string(&vcard)
+    );
+}
+
+#[test]
+fn read() {
+    let vcard = mecard::from_str(
+        "MECARD:N:Owen,Sean;TEL:+12125551212;EMAIL:srowen@example.com;\
+         ADR:,,76 9th Avenue,New York,NY,10011,USA;NOTE:a\\;b;;",
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:Sean Owen",
+            "N:Owen;Sean;;;",
+            "TEL:+12125551212",
+            "EMAIL:srowen@example.com",
+            "ADR:;;76 9th Avenue;New York;NY;10011;USA",
+            "NOTE:a\\;b",
+        ],
+        vcard.iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    let vcard = mecard::from_str(
+        "BIZCARD:N:Sean;X:Owen;T:Software Engineer;C:Google;M:+12125551212;E:srowen@example.com;;",
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:Sean Owen",
+            "N:Owen;Sean;;;",
+            "TITLE:Software Engineer",
+            "ORG:Google",
+            "TEL;TYPE=cell:+12125551212",
+            "EMAIL:srowen@example.com",
+        ],
+        vcard.iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    assert!(mecard::from_str("BEGIN:VCARD").is_err());
+}
+
+#[test]
+fn encode() {
+    let vcard = VCard::parse(DATA).unwrap();
+    let encoded = mecard::encode(&vcard, 1000).unwrap();
+    assert_eq!(Representation::VCard, encoded.representation);
+    let encoded = mecard::encode(&vcard, 300).unwrap();
+    assert_eq!(Representation::MeCard, encoded.representation);
+    assert!(encoded.dropped.is_empty());
+    let encoded = mecard::encode(&vcard, 80).unwrap();
+    assert_eq!(Representation::MeCard, encoded.representation);
+    assert_eq!(
+        "MECARD:N:Gump,Forrest;TEL:+1-111-555-1212;EMAIL:forrestgump@example.com;;",
+        encoded.text
+    );
+    assert_eq!(
+        vec!["NOTE", "BDAY", "ADR", "URL", "TEL", "ORG"],
+        encoded.dropped
+    );
+    assert_eq!(None, mecard::encode(&vcard, 10));
+}
+
+#[test]
+fn mailto_email() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nEMAIL:mailto:jane@example.com\nEND:VCARD\n").unwrap();
+    assert!(mecard::to_string(&vcard).contains(";EMAIL:jane@example.com;"));
+}
+
+#[test]
+fn vcard2_1_bizcard() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:2.1\nFN:Jane Doe\nTEL;WORK;FAX:+1-555-0100\nTEL;CELL:+1-555-0101\nEND:VCARD\n").unwrap();
+    let bizcard = mecard::bizcard_to_string(&vcard);
+    assert!(bizcard.contains(";F:+1-555-0100;"));
+    assert!(bizcard.contains(";M:+1-555-0101;"));
+}
@@ -0,0 +1,26 @@
+#[cfg(feature = "jcard")]
+mod jcard;
+#[cfg(feature = "xcard")]
+mod xcard;
+#[cfg(feature = "jscontact")]
+mod jscontact;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "ldif")]
+mod ldif;
+#[cfg(feature = "hcard")]
+mod hcard;
+#[cfg(feature = "jsonld")]
+mod jsonld;
+#[cfg(feature = "mecard")]
+mod mecard;
+#[cfg(feature = "qr")]
+mod qr;
+#[cfg(feature = "ndef")]
+mod ndef;
+#[cfg(feature = "icalendar")]
+mod icalendar;
+#[cfg(feature = "geojson")]
+mod geojson;
+#[cfg(feature = "kml")]
+mod kml;
@@ -0,0 +1,74 @@
+use rolodex::formats::ndef;
+use rolodex::formats::FormatError;
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+N:Gump;Forrest;;Mr.;
+TEL;TYPE=work:+1-111-555-1212
+EMAIL:forrestgump@example.com
+END:VCARD\r\n";
+
+#[test]
+fn write() {
+    let vcard = VCard::parse(DATA).unwrap();
+    let text = format!("{}\r\n", vcard);
+    let message = ndef::to_bytes(&vcard);
+    assert_eq!(0xd2, message[0]);
+    assert_eq!(10, message[1]);
+    assert_eq!(text.len(), message[2] as usize);
+    assert_eq!(b"text/vcard", &message[3..13]);
+    assert_eq!(text.as_bytes(), &message[13..]);
+
+    let mut vcard = vcard;
+    vcard
+        .0
+        .push(Property::from(Value::Note("x".repeat(300).into())));
+    let message = ndef::to_bytes(&vcard);
+    assert_eq!(0xc2, message[0]);
+    let length = vcard.to_string().len() as u32 + 2;
+    assert_eq!(length.to_be_bytes(), message[2..6]);
+    assert_eq!(16 + length as usize, message.len());
+}
+
+#[test]
+fn read() {
+    let vcard = VCard::parse(DATA).unwrap();
+    let vcf = ndef::from_bytes(&ndef::to_bytes(&vcard)).unwrap();
+    assert_eq!(vec![vcard.clone()], vcf.0);
+
+    let text = format!("{}\r\n", vcard);
+    let (first, second) = text.as_bytes().split_at(20);
+    // A URI record, then the vcard in two chunks with an ID on the first.
+    let mut message = vec![0x91, 0x01, 0x06, b'U', 0x04];
+    message.extend_from_slice(b"a.com");
+    message.extend_from_slice(&[0x3a, 0x0c, first.len() as u8, 0x02]);
+    message.extend_from_slice(b"text/x-vcard");
+    message.extend_from_slice(b"id");
+    message.extend_from_slice(first);
+    message.extend_from_slice(&[0x56, 0x00, second.len() as u8]);
+    message.extend_from_slice(second);
+    let vcf = ndef::from_bytes(&message).unwrap();
+    assert_eq!(vec![vcard], vcf.0);
+
+    assert_eq!(
+        Some(FormatError::Syntax("truncated record".into())),
+        ndef::from_bytes(&message[..message.len() - 1]).err()
+    );
+}
+
+#[test]
+fn fit() {
+    let mut vcard = VCard::parse(DATA).unwrap();
+    vcard
+        .0
+        .push(Property::from(Value::Note("x".repeat(400).into())));
+    let encoded = ndef::encode(&vcard, ndef::NTAG215).unwrap();
+    assert_eq!(vec!["NOTE"], encoded.dropped);
+    assert!(encoded.message.len() <= ndef::NTAG215);
+    let vcf = ndef::from_bytes(&encoded.message).unwrap();
+    assert_eq!(VCard::parse(DATA).unwrap(), vcf.0[0]);
+
+    assert_eq!(Some(FormatError::TooLarge), ndef::encode(&vcard, 40).err());
+}
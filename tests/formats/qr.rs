@@ -0,0 +1,102 @@
+use rolodex::formats::qr::{self, EcLevel};
+use rolodex::formats::FormatError;
+use rolodex::types::Image;
+use rolodex::*;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+N:Gump;Forrest;;Mr.;
+TEL;TYPE=work:+1-111-555-1212
+EMAIL:forrestgump@example.com
+NOTE:Life is like a box of chocolates\\, you never know
+END:VCARD\r\n";
+
+#[test]
+fn render() {
+    let vcard = VCard::parse(DATA).unwrap();
+    let code = qr::encode(&vcard, EcLevel::M).unwrap();
+    assert!(code.dropped.is_empty());
+    assert_eq!(vcard.to_string(), code.text);
+    let svg = code.to_svg(4);
+    assert!(svg.starts_with("<?xml"));
+    assert!(svg.contains("<svg"));
+    let png = code.to_png(2);
+    assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+    let terminal = code.to_terminal();
+    assert!(terminal.contains('\u{2588}'));
+    assert!(terminal.lines().count() >= code.width() / 2);
+}
+
+#[test]
+fn drop_to_fit() {
+    let photo = format!("PHOTO:data:image/jpeg;base64,{}\nEND:VCARD", "a".repeat(2200));
+    let data = DATA.replace("END:VCARD", &photo);
+    let mut vcard = VCard::parse(&data).unwrap();
+    assert!(matches!(vcard.0[6].value, Value::Photo(_)));
+    let code = qr::encode(&vcard, EcLevel::L).unwrap();
+    assert!(code.dropped.is_empty());
+
+    let code = qr::encode(&vcard, EcLevel::M).unwrap();
+    assert_eq!(vec!["PHOTO"], code.dropped);
+    assert!(!code.text.contains("PHOTO"));
+    assert!(code.text.contains("NOTE"));
+
+    let note = "x".repeat(1300);
+    vcard.0.push(Property::from(Value::Note(note.into())));
+    let code = qr::encode(&vcard, EcLevel::H).unwrap();
+    assert_eq!(vec!["PHOTO", "NOTE"], code.dropped);
+    assert!(code.text.contains("NOTE:Life"));
+
+    let name = "x".repeat(3000);
+    let vcard = VCard(vec![
+        Property::from(Value::Begin),
+        Property::from(Value::Fn(name.into())),
+        Property::from(Value::End),
+    ]);
+    assert_eq!(
+        Some(FormatError::TooLarge),
+        qr::encode(&vcard, EcLevel::L).err()
+    );
+}
+
+/// A PNG of noise, which does not compress, `side` pixels square.
+fn noise(side: u32) -> String {
+    let mut state = 1u32;
+    let image = image::RgbImage::from_fn(side, side, |_, _| {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        let [r, g, b, _] = state.to_be_bytes();
+        image::Rgb([r, g, b])
+    });
+    let mut png = std::io::Cursor::new(vec![]);
+    image.write_to(&mut png, image::ImageOutputFormat::Png).unwrap();
+    STANDARD.encode(png.into_inner())
+}
+
+#[test]
+fn shrink_to_fit() {
+    let photo = format!("PHOTO:data:image/png;base64,{}\nEND:VCARD", noise(96));
+    let data = DATA.replace("END:VCARD", &photo);
+    let vcard = VCard::parse(&data).unwrap();
+    let code = qr::encode(&vcard, EcLevel::L).unwrap();
+    assert!(code.dropped.is_empty());
+    let text = format!("{}\r\n", code.text);
+    let shrunk = VCard::parse(&text).unwrap();
+    let data = match &shrunk.0[6].value {
+        Value::Photo(TypeOrRaw::Type(Image::Data { ty, data, .. })) => {
+            assert_eq!("image/jpeg", ty);
+            STANDARD.decode(data.as_ref()).unwrap()
+        }
+        value => panic!("not an embedded photo: {:?}", value),
+    };
+    let photo = image::load_from_memory(&data).unwrap();
+    assert!(photo.width() < 96 && photo.width() > 16);
+    assert!(code.text.contains("NOTE:Life"));
+
+    let smaller = qr::encode(&vcard, EcLevel::H).unwrap();
+    assert!(smaller.dropped.is_empty());
+    assert!(smaller.text.len() < code.text.len());
+}
@@ -0,0 +1,74 @@
+use rolodex::formats::xcard;
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+N:Stevenson;John;Philip,Paul;Dr.;Jr.,M.D.,A.C.P.
+FN:John Stevenson
+NICKNAME:Johnny,JP
+ORG:ABC\\, Inc.;North American Division;Marketing
+TEL;TYPE=work,voice;VALUE=uri:tel:+1-111-555-1212
+item1.EMAIL;TYPE=work:john@example.com
+item1.X-ABLABEL:Work & Play
+ADR;TYPE=home:;;42 Plantation St.;Baytown;LA;30314;USA
+BDAY:19850412
+END:VCARD\r\n";
+
+const XML: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<vcards xmlns=\"urn:ietf:params:xml:ns:vcard-4.0\"><vcard>\
+<n><surname>Stevenson</surname><given>John</given><additional>Philip</additional>\
+<additional>Paul</additional><prefix>Dr.</prefix><suffix>Jr.</suffix><suffix>M.D.</suffix>\
+<suffix>A.C.P.</suffix></n>\
+<fn><text>John Stevenson</text></fn>\
+<nickname><text>Johnny</text><text>JP</text></nickname>\
+<org><text>ABC, Inc.</text><text>North American Division</text><text>Marketing</text></org>\
+<tel><parameters><type><text>work</text><text>voice</text></type></parameters>\
+<uri>tel:+1-111-555-1212</uri></tel>\
+<group name=\"item1\"><email><parameters><type><text>work</text></type></parameters>\
+<text>john@example.com</text></email>\
+<x-ablabel><unknown>Work &amp; Play</unknown></x-ablabel></group>\
+<adr><parameters><type><text>home</text></type></parameters><pobox/><ext/>\
+<street>42 Plantation St.</street><locality>Baytown</locality><region>LA</region>\
+<code>30314</code><country>USA</country></adr>\
+<bday><date-and-or-time>19850412</date-and-or-time></bday>\
+</vcard></vcards>
+";
+
+#[test]
+fn write() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(XML, xcard::to_string(&vcard));
+}
+
+#[test]
+fn round_trip() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(Ok(vcard.clone()), xcard::from_str(&xcard::to_string(&vcard)));
+}
+
+#[test]
+fn read() {
+    let data = "<?xml version=\"1.0\"?>
+<v:vcards xmlns:v=\"urn:ietf:params:xml:ns:vcard-4.0\" xmlns:ex=\"http://example.com/ns\">
+  <v:vcard>
+    <v:fn><v:text>J. Doe</v:text></v:fn>
+    <v:bday><v:date>--0412</v:date></v:bday>
+    <v:gender><v:sex>F</v:sex></v:gender>
+    <ex:badge level=\"3\">gold</ex:badge>
+  </v:vcard>
+  <v:vcard><v:fn><v:text>Second</v:text></v:fn></v:vcard>
+</v:vcards>";
+    let vcf = xcard::vcf_from_str(data).unwrap();
+    assert_eq!(2, vcf.0.len());
+    assert_eq!(
+        vec![
+            "VERSION:4.0",
+            "FN:J. Doe",
+            "BDAY;VALUE=date:--0412",
+            "GENDER:F;",
+            "XML:<ex:badge xmlns:ex=\"http://example.com/ns\" level=\"3\">gold</ex:badge>",
+        ],
+        vcf.0[0].iter().map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+    assert!(xcard::from_str("<vcards><vcard/></vcards>").is_err());
+}
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+BDAY:19850412
+ANNIVERSARY:--0415
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Jenny Curran
+BDAY;VALUE=DATE:1964-02-29
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Bubba Blue
+BDAY:--05
+END:VCARD\r\n";
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn occasions() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    assert_eq!(
+        vec![
+            Occasion {
+                kind: OccasionKind::Birthday,
+                year: Some(1985),
+                month: 4,
+                day: 12
+            },
+            Occasion {
+                kind: OccasionKind::Anniversary,
+                year: None,
+                month: 4,
+                day: 15
+            }
+        ],
+        vcf.0[0].occasions()
+    );
+    assert!(vcf.0[2].occasions().is_empty());
+}
+
+#[test]
+fn next_and_age() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    let leap = vcf.0[1].occasions()[0];
+    assert_eq!(Some(date(2021, 2, 28)), leap.next(date(2021, 1, 1)));
+    assert_eq!(Some(date(2024, 2, 29)), leap.next(date(2024, 2, 1)));
+    assert_eq!(Some(date(2022, 2, 28)), leap.next(date(2021, 3, 1)));
+    assert_eq!(Some(57), leap.age(date(2021, 2, 28)));
+    assert_eq!(Some(56), leap.age(date(2021, 2, 27)));
+    assert_eq!(None, leap.age(date(1960, 1, 1)));
+    assert_eq!(Some(date(1964, 2, 29)), leap.next(date(1950, 1, 1)));
+
+    let anniversary = vcf.0[0].occasions()[1];
+    assert_eq!(Some(date(2021, 4, 15)), anniversary.next(date(2021, 4, 15)));
+    assert_eq!(None, anniversary.age(date(2021, 4, 15)));
+}
+
+#[test]
+fn upcoming() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    let upcoming = vcf.upcoming(date(2021, 4, 1), 14);
+    assert_eq!(2, upcoming.len());
+    assert_eq!(&vcf.0[0], upcoming[0].vcard);
+    assert_eq!(date(2021, 4, 12), upcoming[0].date);
+    assert_eq!(Some(36), upcoming[0].age);
+    assert_eq!(OccasionKind::Anniversary, upcoming[1].occasion.kind);
+    assert_eq!(date(2021, 4, 15), upcoming[1].date);
+    assert_eq!(None, upcoming[1].age);
+
+    let upcoming = vcf.upcoming(date(2021, 12, 25), 70);
+    assert_eq!(1, upcoming.len());
+    assert_eq!(date(2022, 2, 28), upcoming[0].date);
+    assert_eq!(Some(58), upcoming[0].age);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "phone")]
+
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Chicago Office
+ADR;TYPE=work:;;233 S Wacker Dr;Chicago;IL;60606;USA
+TEL;TYPE=work:312-555-1515
+TEL;TYPE=cell:(312) 555-1515
+TEL;VALUE=uri:tel:+1-312-555-1515;ext=22
+TEL:+44 20 7946 0018
+END:VCARD
+";
+
+#[test]
+fn same_number_in_every_form() {
+    let vcard = VCard::parse(DATA).unwrap();
+    let numbers: Vec<_> = vcard
+        .phone_numbers(None)
+        .into_iter()
+        .map(|(_, x)| x.e164())
+        .collect();
+    assert_eq!(
+        numbers,
+        vec!["+13125551515", "+13125551515", "+13125551515", "+442079460018"]
+    );
+}
+
+#[test]
+fn region_from_address() {
+    let vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(vcard.phone_region().as_deref(), Some("US"));
+}
+
+#[test]
+fn region_from_caller() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nTEL:020 7946 0018\nEND:VCARD\n").unwrap();
+    assert_eq!(vcard.phone_region(), None);
+    assert!(vcard.phone_numbers(None).is_empty());
+    let numbers = vcard.phone_numbers(Some("United Kingdom"));
+    assert_eq!(numbers[0].1.e164(), "+442079460018");
+    assert_eq!(numbers[0].1.region().as_deref(), Some("GB"));
+}
+
+#[test]
+fn formats() {
+    let number = PhoneNumber::parse("312.555.1515", Some("us")).unwrap();
+    assert_eq!(number.national(), "(312) 555-1515");
+    assert_eq!(number.international(), "+1 312-555-1515");
+    assert_eq!(number.country_code(), 1);
+    assert_eq!(number.to_string(), "+13125551515");
+}
+
+#[test]
+fn extension() {
+    let vcard = VCard::parse(DATA).unwrap();
+    let numbers = vcard.phone_numbers(None);
+    assert_eq!(numbers[2].1.extension.as_deref(), Some("22"));
+    assert_eq!(numbers[2].1.to_string(), "+13125551515 x22");
+}
+
+#[test]
+fn possible() {
+    let number = PhoneNumber::parse("+1 312 555 1515", None).unwrap();
+    assert!(number.is_possible());
+    let short = PhoneNumber::parse("+1 312 555 151500", None).unwrap();
+    assert!(!short.is_possible());
+    assert!(!short.is_valid());
+}
+
+#[test]
+fn unknown_region() {
+    assert_eq!(PhoneNumber::parse("312-555-1515", Some("Atlantis")), None);
+    assert_eq!(PhoneNumber::parse("312-555-1515", None), None);
+    let number = PhoneNumber::parse("+13125551515", Some("Narnia")).unwrap();
+    assert_eq!("+13125551515", number.e164());
+}
@@ -0,0 +1,105 @@
+use rolodex::types::{BoundingBox, GeoPosition};
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Paris Office
+GEO:geo:48.8566,2.3522
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:London Office
+ADR;GEO=\"geo:51.5074,-0.1278\":;;10 Downing St;London;;SW1A 2AA;UK
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Versailles
+GEO:geo:48.8049,2.1204
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Nowhere
+END:VCARD
+BEGIN:VCARD
+VERSION:4.0
+FN:Fiji
+GEO:geo:-17.7134,178.065
+END:VCARD
+";
+
+fn names(vcards: &[&VCard]) -> Vec<String> {
+    vcards
+        .iter()
+        .flat_map(|x| x.iter())
+        .filter(|x| matches!(x.value, Value::Fn(_)))
+        .map(|x| x.value.to_string())
+        .collect()
+}
+
+#[test]
+fn distance() {
+    let paris = GeoPosition::new(48.8566, 2.3522);
+    let london = GeoPosition::new(51.5074, -0.1278);
+    assert!((paris.distance(&london) - 343_556.5).abs() < 1.0);
+    assert_eq!(paris.distance(&london), london.distance(&paris));
+    assert_eq!(0.0, paris.distance(&paris));
+}
+
+#[test]
+fn position() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    assert_eq!(Some(GeoPosition::new(48.8566, 2.3522)), vcf.0[0].position());
+    assert_eq!(
+        Some(GeoPosition::new(51.5074, -0.1278)),
+        vcf.0[1].position()
+    );
+    assert_eq!(None, vcf.0[3].position());
+}
+
+#[test]
+fn within() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    let center = GeoPosition::new(48.8566, 2.3522);
+
+    let nearby = vcf.within(&center, 50_000.0);
+    let vcards = nearby.iter().map(|x| x.vcard).collect::<Vec<_>>();
+    assert_eq!(vec!["Paris Office", "Versailles"], names(&vcards));
+    assert_eq!(0.0, nearby[0].distance);
+    assert!((nearby[1].distance - 17_915.0).abs() < 1.0);
+
+    assert_eq!(3, vcf.within(&center, 500_000.0).len());
+}
+
+#[test]
+fn in_box() {
+    let (_, vcf) = Vcf::parse(DATA).unwrap();
+    let france = BoundingBox {
+        south: 41.0,
+        west: -5.0,
+        north: 51.0,
+        east: 9.5,
+    };
+    assert_eq!(
+        vec!["Paris Office", "Versailles"],
+        names(&vcf.in_box(&france))
+    );
+
+    // West of the east edge crosses the antimeridian.
+    let pacific = BoundingBox {
+        south: -30.0,
+        west: 170.0,
+        north: 0.0,
+        east: -170.0,
+    };
+    assert_eq!(vec!["Fiji"], names(&vcf.in_box(&pacific)));
+}
+
+#[test]
+fn other_system() {
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Base\nGEO:geo:4.5,137.4;crs=mars\nADR;GEO=\"geo:48.8566,2.3522\":;;;Paris;;;France\nEND:VCARD\n").unwrap();
+    assert_eq!(Some("mars"), vcard.position().unwrap().crs.as_deref());
+    let vcf = Vcf(vec![vcard]);
+    let nearby = vcf.within(&GeoPosition::new(48.8566, 2.3522), 1_000.0);
+    assert_eq!(1, nearby.len());
+    assert_eq!(GeoPosition::new(48.8566, 2.3522), nearby[0].position);
+}
@@ -0,0 +1,73 @@
+use rolodex::types::Timestamp;
+use rolodex::*;
+
+const DATA: &str = "BEGIN:VCARD
+VERSION:4.0
+FN:Forrest Gump
+REV:20080424T195243Z
+END:VCARD\n";
+
+fn rev(value: &str) -> Timestamp {
+    Timestamp::parse(value).unwrap().1
+}
+
+#[test]
+fn mutate() {
+    let mut vcard = VCard::parse(DATA).unwrap();
+    assert_eq!(Some(rev("20080424T195243Z")), vcard.rev());
+
+    vcard.push(Property::from(Value::Note("Run".into())));
+    vcard.insert(1, Property::from(Value::Title("Shrimp Man".into())));
+    vcard.set(Property::from(Value::Fn("Forrest".into())));
+    assert_eq!(
+        "BEGIN:VCARD\r\nVERSION:4.0\r\nTITLE:Shrimp Man\r\nFN:Forrest\r\n\
+         REV:20080424T195243Z\r\nNOTE:Run\r\nEND:VCARD",
+        vcard.to_string()
+    );
+    assert_eq!(Value::Note("Run".into()), vcard.remove(4).value);
+    vcard.retain(|x| !matches!(x.value, Value::Title(_)));
+    assert_eq!(3, vcard.0.len());
+    // Plain mutation leaves the revision alone.
+    assert_eq!(Some(rev("20080424T195243Z")), vcard.rev());
+}
+
+#[test]
+fn bump() {
+    let mut vcard = VCard::parse(DATA).unwrap();
+    vcard.bump_rev(rev("20210101T000000Z"));
+    assert_eq!(Some(rev("20210101T000000Z")), vcard.rev());
+    vcard.bump_rev(rev("20200101T000000Z"));
+    assert_eq!(Some(rev("20210101T000001Z")), vcard.rev());
+    assert_eq!(
+        1,
+        vcard
+            .iter()
+            .filter(|x| matches!(x.value, Value::Rev(_)))
+            .count()
+    );
+}
+
+#[test]
+fn revise() {
+    let mut vcard = VCard::parse(DATA).unwrap();
+    vcard.revise();
+    assert_eq!(Some(rev("20080424T195243Z")), vcard.rev());
+
+    vcard
+        .revise()
+        .push(Property::from(Value::Note("Run".into())));
+    assert!(vcard.rev() > Some(rev("20210101T000000Z")));
+
+    let mut vcard = VCard::parse(DATA).unwrap();
+    {
+        let mut revise = vcard.revise();
+        revise.remove(1);
+        assert_eq!(Some(rev("20080424T195243Z")), revise.rev());
+    }
+    assert!(vcard.rev() > Some(rev("20210101T000000Z")));
+
+    // Only touching REV is not a change.
+    let mut vcard = VCard::parse(DATA).unwrap();
+    vcard.revise().remove(2);
+    assert_eq!(None, vcard.rev());
+}
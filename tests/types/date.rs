@@ -0,0 +1,106 @@
+use rolodex::types::{Date, DateAndOrTime, Time, Zone};
+use rolodex::*;
+
+fn date(value: &str) -> DateAndOrTime {
+    match DateAndOrTime::parse(value) {
+        Ok(("", date)) => date,
+        other => panic!("{}: {:?}", value, other),
+    }
+}
+
+#[test]
+fn dates() {
+    let full = DateAndOrTime::Date(Date::builder().year(1985).month(4).day(12).build());
+    assert_eq!(full, date("19850412"));
+    assert_eq!(full, date("1985-04-12"));
+    assert_eq!(
+        full,
+        chrono::NaiveDate::from_ymd_opt(1985, 4, 12).unwrap().into()
+    );
+    assert_eq!("19850412", full.to_string());
+
+    for (input, output) in &[
+        ("1985", "1985"),
+        ("1985-04", "1985-04"),
+        ("--0415", "--0415"),
+        ("--04-15", "--0415"),
+        ("--04", "--04"),
+        ("---12", "---12"),
+        ("--0229", "--0229"),
+    ] {
+        assert_eq!(*output, date(input).to_string());
+    }
+    assert_eq!(Some(12), date("---12").date().unwrap().day);
+    assert_eq!(None, date("--0415").date().unwrap().to_naive_date());
+}
+
+#[test]
+fn times() {
+    let time = Time::builder()
+        .hour(10)
+        .minute(22)
+        .second(0)
+        .zone(Zone::Offset(-480))
+        .build();
+    assert_eq!(DateAndOrTime::Time(time), date("T102200-0800"));
+    assert_eq!(DateAndOrTime::Time(time), date("T10:22:00-08:00"));
+
+    for (input, output) in &[
+        ("T10", "T10"),
+        ("T1022", "T1022"),
+        ("T-2200", "T-2200"),
+        ("T--00", "T--00"),
+        ("T102200Z", "T102200Z"),
+        ("T10+0530", "T10+0530"),
+        ("19961022T140000", "19961022T140000"),
+        ("1996-10-22T14:00:00Z", "19961022T140000Z"),
+        ("--1022T1400", "--1022T1400"),
+        ("---22T14", "---22T14"),
+    ] {
+        assert_eq!(*output, date(input).to_string());
+    }
+}
+
+#[test]
+fn invalid() {
+    for input in &[
+        "198504",
+        "19851304",
+        "19850230",
+        "--0230",
+        "T25",
+        "T1",
+        "circa 1800",
+        "ábcd",
+    ] {
+        assert!(
+            !matches!(DateAndOrTime::parse(input), Ok(("", _))),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn properties() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD
+BDAY;VALUE=DATE:1963-09-21
+ANNIVERSARY:--0415
+DEATHDATE:20160101T0900Z
+BDAY;VALUE=text:circa 1800
+END:VCARD\n",
+    )
+    .unwrap();
+    let values: Vec<_> = vcard.iter().map(|x| x.value.clone()).collect();
+    assert_eq!(
+        vec![
+            Value::Birthday(TypeOrRaw::Type(date("19630921"))),
+            Value::Anniversary(TypeOrRaw::Type(date("--0415"))),
+            Value::DeathDate(TypeOrRaw::Type(date("20160101T0900Z"))),
+            Value::Birthday(TypeOrRaw::Raw("circa 1800".into())),
+        ],
+        values
+    );
+    assert_eq!("BDAY;VALUE=DATE:19630921", vcard.0[0].to_string());
+}
@@ -0,0 +1,110 @@
+use rolodex::types::Email;
+use rolodex::*;
+
+fn email(value: &str) -> Email<'_> {
+    match Email::parse(value) {
+        Ok(("", email)) => email,
+        other => panic!("{}: {:?}", value, other),
+    }
+}
+
+#[test]
+fn addresses() {
+    for (input, user, domain) in &[
+        ("john.doe@example.com", "john.doe", "example.com"),
+        ("john+tag@Example.COM", "john+tag", "example.com"),
+        ("o'brien@example.ie", "o'brien", "example.ie"),
+        ("root@localhost", "root", "localhost"),
+        ("\"john doe\"@example.com", "\"john doe\"", "example.com"),
+        (
+            "\"john@\\\"home\\\"\"@example.com",
+            "\"john@\\\"home\\\"\"",
+            "example.com",
+        ),
+        ("postmaster@[192.0.2.1]", "postmaster", "[192.0.2.1]"),
+        (
+            "postmaster@[IPv6:2001:db8::1]",
+            "postmaster",
+            "[IPv6:2001:db8::1]",
+        ),
+    ] {
+        let email = email(input);
+        assert_eq!(*user, email.user, "{}", input);
+        assert_eq!(*domain, email.domain, "{}", input);
+        assert!(!email.uri, "{}", input);
+    }
+}
+
+#[test]
+fn keeps_local_case() {
+    let email = email("John.Doe@EXAMPLE.com");
+    assert_eq!("John.Doe", email.user);
+    assert_eq!("John.Doe@example.com", email.to_string());
+}
+
+#[test]
+fn idn() {
+    let email = email("jürgen@Bücher.example");
+    assert_eq!("jürgen", email.user);
+    assert_eq!("xn--bcher-kva.example", email.domain);
+    assert_eq!("bücher.example", email.unicode_domain());
+
+    let punycode = self::email("info@xn--mnchen-3ya.de");
+    assert_eq!("xn--mnchen-3ya.de", punycode.domain);
+    assert_eq!("münchen.de", punycode.unicode_domain());
+
+    let full_width = self::email("info@\u{ff25}xample.com");
+    assert_eq!("example.com", full_width.domain);
+}
+
+#[test]
+fn mailto() {
+    let email = email("mailto:John.Doe@Example.com");
+    assert!(email.uri);
+    assert_eq!("John.Doe", email.user);
+    assert_eq!("example.com", email.domain);
+    assert_eq!("John.Doe@example.com", email.address());
+    assert_eq!("mailto:John.Doe@example.com", email.to_string());
+
+    let quoted = self::email("mailto:%22john%20doe%22@example.com");
+    assert_eq!("\"john doe\"", quoted.user);
+    assert_eq!("mailto:%22john%20doe%22@example.com", quoted.to_string());
+}
+
+#[test]
+fn reject() {
+    for input in &[
+        "",
+        "john.doe",
+        "@example.com",
+        "john.doe@",
+        "john..doe@example.com",
+        ".john@example.com",
+        "john doe@example.com",
+        "\"john\"doe@example.com",
+        "\"john@example.com",
+        "john@example.com trailing",
+        "john@example.com>",
+        "john@-example.com",
+        "john@example..com",
+        "john@example.com.",
+        "john@[300.0.0.1]",
+        "john@xn--99999999999.com",
+        "mailto:john@example.com?subject=hi",
+        "mailto:a@example.com,b@example.com",
+    ] {
+        assert!(Email::from_text(input).is_none(), "{}", input);
+    }
+}
+
+#[test]
+fn raw_fallback() {
+    let vcard =
+        VCard::parse("BEGIN:VCARD\nVERSION:4.0\nEMAIL:John Doe <john@example.com>\nEND:VCARD\n")
+            .unwrap();
+    let value = &vcard.iter().nth(1).unwrap().value;
+    assert_eq!(
+        &Value::Email(TypeOrRaw::Raw("John Doe <john@example.com>".into())),
+        value
+    );
+}
@@ -0,0 +1,113 @@
+use rolodex::types::GeoPosition;
+use rolodex::*;
+
+fn geo(value: &str) -> GeoPosition {
+    match GeoPosition::parse(value) {
+        Ok(("", geo)) => geo,
+        other => panic!("{}: {:?}", value, other),
+    }
+}
+
+#[test]
+fn parse_uri() {
+    assert_eq!(
+        GeoPosition::new(37.386, -122.082),
+        geo("geo:37.386,-122.082")
+    );
+    assert_eq!(
+        GeoPosition::new(37.386, -122.082),
+        geo("GEO:37.386,-122.082")
+    );
+
+    let full = geo("geo:48.2010,16.3695,183;crs=wgs84;u=40;name=home");
+    assert_eq!(Some(183.0), full.altitude);
+    assert_eq!(Some("wgs84".into()), full.crs);
+    assert_eq!(Some(40.0), full.uncertainty);
+    assert_eq!(
+        vec![("name".to_string(), "home".to_string())],
+        full.parameters
+    );
+    assert!(full.uri);
+
+    let mut expected = GeoPosition::new(37.386, -122.082);
+    expected.uncertainty = Some(10.0);
+    assert_eq!(expected, geo("geo:37.386,-122.082;u=10"));
+}
+
+#[test]
+fn parse_text() {
+    let position = geo("37.386;-122.082");
+    assert_eq!((37.386, -122.082), (position.latitude, position.longitude));
+    assert!(!position.uri);
+
+    // A bare comma pair is read as a uri without its scheme.
+    assert_eq!(GeoPosition::new(30.0, -94.9), geo("30.0,-94.9"));
+}
+
+#[test]
+fn reject() {
+    for input in &[
+        "",
+        "geo:",
+        "geo:37.386",
+        "geo:1,2,3,4",
+        "geo:91,0",
+        "geo:0,181",
+        "geo:0,0;u=-1",
+        "geo:0,0;u=ten",
+        "geo:inf,0",
+        "geo:1e3,0",
+        "37.386;-122.082;0",
+        "somewhere",
+    ] {
+        assert!(
+            !matches!(GeoPosition::parse(input), Ok(("", _))),
+            "{:?}",
+            input
+        );
+    }
+    // Bounds only apply to WGS-84.
+    assert_eq!(
+        Some(120.0),
+        GeoPosition::from_text("geo:120,0;crs=moon").map(|x| x.latitude)
+    );
+}
+
+#[test]
+fn display() {
+    for input in &[
+        "geo:37.386013,-122.082932",
+        "geo:48.201,16.3695,183;crs=wgs84;u=40;name=home",
+        "geo:0,0;flag",
+        "37.386013;-122.082932",
+    ] {
+        assert_eq!(*input, geo(input).to_string());
+        assert_eq!(geo(input), geo(&geo(input).to_string()));
+    }
+}
+
+#[test]
+fn vcard() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD\nVERSION:3.0\nFN:Forrest Gump\nGEO:37.386013;-122.082932\n\
+         ADR;GEO=\"geo:12.3457,78.910\":;;Main St;Any Town;;;USA\nNOTE:Run\nEND:VCARD\n",
+    )
+    .unwrap();
+    let position = vcard
+        .iter()
+        .find_map(|x| match &x.value {
+            Value::Geo(TypeOrRaw::Type(geo)) => Some(geo.clone()),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(37.386013, position.latitude);
+    assert!(vcard
+        .to_string()
+        .contains("\r\nGEO:37.386013;-122.082932\r\n"));
+
+    let address = vcard
+        .iter()
+        .find(|x| matches!(x.value, Value::Adr(_)))
+        .unwrap();
+    assert_eq!(Some(GeoPosition::new(12.3457, 78.91)), address.params.geo());
+}
@@ -5,9 +5,9 @@ const DATA: &str = "https://testurl\n";
 const RAW_DATA: &str = "data:image/gif;base64,R0lGODlhEAAQAMQAAORHHOVSKudfOulrSOp3WOyDZu6QdvCchPGolfO0o/XBs/fNwfjZ0frl3/zy7////wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACH5BAkAABAALAAAAAAQABAAAAVVICSOZGlCQAosJ6mu7fiyZeKqNKToQGDsM8hBADgUXoGAiqhSvp5QAnQKGIgUhwFUYLCVDFCrKUE1lBavAViFIDlTImbKC5Gm2hB0SlBCBMQiB0UjIQA7\n";
 
 fn url() -> types::Image<'static> {
-    types::Image::Url(types::Url::builder()
-        .schema("https")
-        .domain("testurl")
+    types::Image::Uri(types::Uri::builder()
+        .scheme("https")
+        .authority(types::Authority::builder().host("testurl").build())
         .build())
 }
 
@@ -0,0 +1,201 @@
+use rolodex::types::{Key, Uri};
+use rolodex::*;
+
+#[cfg(feature = "key")]
+use chrono::{Duration, TimeZone, Utc};
+#[cfg(feature = "key")]
+use rolodex::types::{Certificate, KeyInfo};
+
+const PGP: &str = "mDMEZZIAgBYJKwYBBAHaRw8BAQdA/Gbq3iZZFZUTArVu6gKEMxwGFoKP6Jk3YM0zW7c1mES0G0phbmUgRG9lIDxqYW5lQGV4YW1wbGUuY29tPoiWBBMWCAA+FiEEy5Ik2jI8GrEbnlSfTPduR4qJAUcFAmWSAIACGwEFCQtKgMAFCwkIBwIGFQoJCAsCBBYCAwECHgECF4AACgkQTPduR4qJAUfc7gD9GuVpqlg8V16Cvg1gX7xhPVc7A42TufwvFh1VeuGpPX4A/RsTWE+OYXCcrJEURq8tI0xvZISp1Fu8D/FjAnAaka4H";
+
+#[cfg(feature = "key")]
+const X509: &str = "MIIBxTCCAXegAwIBAgIUQGVuj0BT198PaVS6toC8IHpgyawwBQYDK2VwMFgxCzAJBgNVBAYTAlVTMRUwEwYDVQQKDAxFeGFtcGxlIENvcnAxETAPBgNVBAMMCEphbmUgRG9lMR8wHQYJKoZIhvcNAQkBFhBqYW5lQGV4YW1wbGUuY29tMB4XDTI0MDEwMTAwMDAwMFoXDTMwMDEwMTAwMDAwMFowWDELMAkGA1UEBhMCVVMxFTATBgNVBAoMDEV4YW1wbGUgQ29ycDERMA8GA1UEAwwISmFuZSBEb2UxHzAdBgkqhkiG9w0BCQEWEGphbmVAZXhhbXBsZS5jb20wKjAFBgMrZXADIQAS07s9FZYdankYMVZ3tm/DH7svGzErqUxNSCNhLJO4k6NTMFEwHQYDVR0OBBYEFPI9M2SS83PDoEEbTzkQeIQ2IVmQMB8GA1UdIwQYMBaAFPI9M2SS83PDoEEbTzkQeIQ2IVmQMA8GA1UdEwEB/wQFMAMBAf8wBQYDK2VwA0EAcTfeGDn5QlC7UB75td5YUy7xwsuUAnpc2AV5zRl5zUCoUogA5o7azAgHtp75LmSrDQ/JxMAtaGhYYiyotGOaAg==";
+
+fn key(value: &str) -> Key<'_> {
+    match Key::parse(value) {
+        Ok(("", key)) => key,
+        other => panic!("{}: {:?}", value, other),
+    }
+}
+
+#[test]
+fn forms() {
+    assert_eq!(
+        key("https://example.com/jane.asc"),
+        Key::Uri(Uri::from_text("https://example.com/jane.asc").unwrap())
+    );
+    assert_eq!(
+        key("data:application/pgp-keys;base64,mDMEZZIAgBY="),
+        Key::Data {
+            media_type: "application/pgp-keys".into(),
+            base64: true,
+            data: "mDMEZZIAgBY=".into(),
+        }
+    );
+    assert_eq!(
+        key("DATA:,abc"),
+        Key::Data {
+            media_type: "".into(),
+            base64: false,
+            data: "abc".into(),
+        }
+    );
+    assert_eq!(key(PGP), Key::Binary(PGP.into()));
+    assert!(Key::parse("not a key").is_err());
+}
+
+#[test]
+fn display() {
+    for input in &[
+        "https://example.com/jane.asc",
+        "data:application/pgp-keys;base64,mDMEZZIAgBY=",
+        "data:text/plain,abc",
+        PGP,
+    ] {
+        assert_eq!(key(input).to_string(), *input);
+    }
+}
+
+#[test]
+fn vcard() {
+    let text = format!(
+        "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Jane Doe\r\nKEY;ENCODING=b;TYPE=PGP:{}\r\nKEY:https://example.com/jane.asc\r\nEND:VCARD\r\n",
+        PGP
+    );
+    let vcard = VCard::parse(&text).unwrap();
+    let keys: Vec<_> = vcard
+        .iter()
+        .filter_map(|x| match &x.value {
+            Value::Key(TypeOrRaw::Type(key)) => Some(key),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(keys.len(), 2);
+    assert!(matches!(keys[0], Key::Binary(_)));
+    assert!(matches!(keys[1], Key::Uri(_)));
+}
+
+#[cfg(feature = "key")]
+fn armored() -> String {
+    let lines: Vec<_> = PGP
+        .as_bytes()
+        .chunks(64)
+        .map(|x| std::str::from_utf8(x).unwrap())
+        .collect();
+    format!(
+        "-----BEGIN PGP PUBLIC KEY BLOCK-----\nComment: Jane Doe\n\n{}\n=AzfJ\n-----END PGP PUBLIC KEY BLOCK-----\n",
+        lines.join("\n")
+    )
+}
+
+#[cfg(feature = "key")]
+fn assert_pgp(info: Option<KeyInfo>) {
+    let key = match info {
+        Some(KeyInfo::OpenPgp(key)) => key,
+        other => panic!("{:?}", other),
+    };
+    assert_eq!(key.version, 4);
+    assert_eq!(
+        key.fingerprint.as_deref(),
+        Some("CB9224DA323C1AB11B9E549F4CF76E478A890147")
+    );
+    assert_eq!(key.key_id.as_deref(), Some("4CF76E478A890147"));
+    assert_eq!(
+        key.created,
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    );
+    assert_eq!(
+        key.expires,
+        Some(Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap())
+    );
+    assert_eq!(key.user_ids, vec!["Jane Doe <jane@example.com>"]);
+}
+
+#[cfg(feature = "key")]
+#[test]
+fn openpgp() {
+    assert_pgp(key(PGP).inspect());
+    let data = format!("data:application/pgp-keys;base64,{}", PGP);
+    assert_pgp(key(&data).inspect());
+    let data = format!(
+        "data:application/pgp-keys,{}",
+        armored().replace('\n', "%0A")
+    );
+    assert_pgp(key(&data).inspect());
+
+    let info = key(PGP).inspect().unwrap();
+    assert_eq!(
+        info.fingerprint(),
+        Some("CB9224DA323C1AB11B9E549F4CF76E478A890147")
+    );
+    assert_eq!(
+        info.expires(),
+        Some(Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap())
+    );
+}
+
+#[cfg(feature = "key")]
+#[test]
+fn x509() {
+    let data = format!("data:application/pkix-cert;base64,{}", X509);
+    let certificate = match key(&data).inspect() {
+        Some(KeyInfo::X509(certificate)) => certificate,
+        other => panic!("{:?}", other),
+    };
+    let name = "emailAddress=jane@example.com,CN=Jane Doe,O=Example Corp,C=US";
+    assert_eq!(certificate.subject, name);
+    assert_eq!(certificate.issuer, name);
+    assert_eq!(
+        certificate.not_before,
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    );
+    assert_eq!(
+        certificate.not_after,
+        Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap()
+    );
+    assert_eq!(
+        certificate.fingerprint,
+        "B0E689D410AF860FDB7305B2AEE73461C8D55B09"
+    );
+    assert_eq!(key(X509).inspect(), Some(KeyInfo::X509(certificate)));
+}
+
+#[cfg(feature = "key")]
+#[test]
+fn generalized_time() {
+    fn der(tag: u8, contents: &[u8]) -> Vec<u8> {
+        [&[tag, contents.len() as u8], contents].concat()
+    }
+    let attribute = [der(0x06, &[0x55, 0x04, 0x03]), der(0x0c, b"Jane Doe")].concat();
+    let name = der(0x30, &der(0x31, &der(0x30, &attribute)));
+    let validity = [
+        der(0x18, b"20240101000000.123Z"),
+        der(0x18, b"20500101000000Z"),
+    ];
+    let tbs = [
+        der(0x02, &[1]),
+        der(0x30, &[]),
+        name.clone(),
+        der(0x30, &validity.concat()),
+        name,
+    ];
+    let certificate = Certificate::from_der(&der(0x30, &der(0x30, &tbs.concat()))).unwrap();
+    assert_eq!("CN=Jane Doe", certificate.subject);
+    assert_eq!(
+        certificate.not_before,
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::milliseconds(123)
+    );
+    assert_eq!(
+        certificate.not_after,
+        Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap()
+    );
+}
+
+#[cfg(feature = "key")]
+#[test]
+fn unknown() {
+    assert_eq!(key("https://example.com/jane.asc").inspect(), None);
+    assert_eq!(key("data:,hello").inspect(), None);
+    assert_eq!(key("aGVsbG8=").inspect(), None);
+    assert_eq!(key("data:;base64,!!").bytes(), None);
+}
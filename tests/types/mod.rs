@@ -1,3 +1,12 @@
+mod date;
+mod email;
 mod formatted_name;
+mod geo;
 mod image;
-mod url;
+mod key;
+mod organization;
+mod social;
+mod telephone;
+mod timestamp;
+mod timezone;
+mod uri;
@@ -0,0 +1,57 @@
+use rolodex::types::Organization;
+use rolodex::*;
+
+fn organization(value: &str) -> Organization<'_> {
+    match Organization::parse(value) {
+        Ok(("", organization)) => organization,
+        other => panic!("{}: {:?}", value, other),
+    }
+}
+
+#[test]
+fn units() {
+    let org = organization("ABC\\, Inc.;North American Division;Marketing");
+    assert_eq!("ABC, Inc.", org.name);
+    assert_eq!(
+        vec!["North American Division", "Marketing"],
+        org.units.iter().map(|x| x.as_ref()).collect::<Vec<_>>()
+    );
+    assert_eq!("ABC\\, Inc.;North American Division;Marketing", org.to_string());
+}
+
+#[test]
+fn escaping() {
+    let org = organization(r"R\;D Labs\\Ltd;Q\nA");
+    assert_eq!("R;D Labs\\Ltd", org.name);
+    assert_eq!(vec!["Q\nA"], org.units);
+    assert_eq!(r"R\;D Labs\\Ltd;Q\nA", org.to_string());
+}
+
+#[test]
+fn trailing_units() {
+    let org = organization("Acme;");
+    assert_eq!("Acme", org.name);
+    assert!(org.units.is_empty());
+    assert_eq!("Acme", org.to_string());
+    assert_eq!(vec!["", "", "Sales"], organization(";;Sales").parts().collect::<Vec<_>>());
+}
+
+#[test]
+fn sort_as() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD
+VERSION:4.0
+ORG;PREF=2:Old Co.
+ORG;PREF=1;SORT-AS=\"ABC,,Sales\":The ABC Company;North America;Marketing
+END:VCARD
+",
+    )
+    .unwrap();
+    let org = vcard.organization().unwrap();
+    assert_eq!("The ABC Company", org.name);
+    let property = vcard.iter().nth(2).unwrap();
+    let sort_as = property.params.sort_as();
+    assert_eq!(vec!["ABC", "", "Sales"], sort_as);
+    assert_eq!(vec!["ABC", "North America", "Sales"], org.sort_keys(&sort_as));
+    assert_eq!(vec!["The ABC Company", "North America", "Marketing"], org.sort_keys(&[]));
+}
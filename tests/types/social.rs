@@ -0,0 +1,241 @@
+use rolodex::types::{Service, SocialProfile};
+use rolodex::*;
+
+fn profile(value: &str) -> SocialProfile<'_> {
+    match SocialProfile::parse(value) {
+        Ok(("", profile)) => profile,
+        other => panic!("{}: {:?}", value, other),
+    }
+}
+
+#[test]
+fn schemes() {
+    for (input, service, username, domain, uri) in &[
+        (
+            "xmpp:alice@Example.com",
+            Service::Xmpp,
+            "alice",
+            Some("example.com"),
+            "xmpp:alice@example.com",
+        ),
+        (
+            "xmpp:alice@example.com?message",
+            Service::Xmpp,
+            "alice",
+            Some("example.com"),
+            "xmpp:alice@example.com",
+        ),
+        (
+            "sip:alice@example.com;transport=tcp",
+            Service::Sip,
+            "alice",
+            Some("example.com"),
+            "sip:alice@example.com",
+        ),
+        (
+            "skype:echo123?call",
+            Service::Skype,
+            "echo123",
+            None,
+            "skype:echo123",
+        ),
+        (
+            "matrix:u/alice:matrix.org",
+            Service::Matrix,
+            "alice",
+            Some("matrix.org"),
+            "matrix:u/alice:matrix.org",
+        ),
+        (
+            "aim:goim?screenname=bob",
+            Service::Aim,
+            "bob",
+            None,
+            "aim:goim?screenname=bob",
+        ),
+        (
+            "aim:goim?screenname=bob&message=hello",
+            Service::Aim,
+            "bob",
+            None,
+            "aim:goim?screenname=bob",
+        ),
+    ] {
+        let profile = profile(input);
+        assert_eq!(*service, profile.service, "{}", input);
+        assert_eq!(*username, profile.username, "{}", input);
+        assert_eq!(domain.map(Into::into), profile.domain, "{}", input);
+        assert_eq!(Some(uri.to_string()), profile.uri(), "{}", input);
+        assert_eq!(*input, profile.to_string(), "{}", input);
+    }
+}
+
+#[test]
+fn sites() {
+    for (input, service, username, uri) in &[
+        (
+            "https://twitter.com/jdoe",
+            Service::Twitter,
+            "jdoe",
+            "https://twitter.com/jdoe",
+        ),
+        (
+            "http://www.twitter.com/jdoe/",
+            Service::Twitter,
+            "jdoe",
+            "https://twitter.com/jdoe",
+        ),
+        (
+            "https://x.com/jdoe?s=20",
+            Service::Twitter,
+            "jdoe",
+            "https://twitter.com/jdoe",
+        ),
+        (
+            "https://x.com/alice/status/123",
+            Service::Twitter,
+            "alice",
+            "https://twitter.com/alice",
+        ),
+        (
+            "https://linkedin.com/in/jdoe",
+            Service::LinkedIn,
+            "jdoe",
+            "https://www.linkedin.com/in/jdoe",
+        ),
+        (
+            "https://m.facebook.com/jdoe",
+            Service::Facebook,
+            "jdoe",
+            "https://www.facebook.com/jdoe",
+        ),
+        (
+            "https://github.com/jdoe",
+            Service::GitHub,
+            "jdoe",
+            "https://github.com/jdoe",
+        ),
+        (
+            "https://instagram.com/jdoe",
+            Service::Instagram,
+            "jdoe",
+            "https://www.instagram.com/jdoe",
+        ),
+    ] {
+        let profile = profile(input);
+        assert_eq!(*service, profile.service, "{}", input);
+        assert_eq!(*username, profile.username, "{}", input);
+        assert_eq!(Some(uri.to_string()), profile.uri(), "{}", input);
+        assert_eq!(*input, profile.to_string(), "{}", input);
+    }
+    let matrix = profile("https://matrix.to/#/@alice:matrix.org");
+    assert_eq!(Service::Matrix, matrix.service);
+    assert_eq!("@alice:matrix.org", matrix.handle());
+}
+
+#[test]
+fn handles() {
+    let mastodon = profile("@Gargron@Mastodon.Social");
+    assert_eq!(Service::Mastodon, mastodon.service);
+    assert_eq!("Gargron", mastodon.username);
+    assert_eq!(Some("mastodon.social".into()), mastodon.domain);
+    assert_eq!(
+        Some("https://mastodon.social/@Gargron".into()),
+        mastodon.uri()
+    );
+    assert_eq!("@Gargron@Mastodon.Social", mastodon.to_string());
+    assert_eq!("@Gargron@mastodon.social", mastodon.handle());
+
+    let matrix = profile("@alice:matrix.org");
+    assert_eq!(Service::Matrix, matrix.service);
+    assert_eq!(Some("matrix:u/alice:matrix.org".into()), matrix.uri());
+    assert_eq!("@alice:matrix.org", matrix.to_string());
+}
+
+#[test]
+fn with_service() {
+    let twitter = SocialProfile::with_service("Twitter", "x-apple:jdoe").unwrap();
+    assert_eq!(Service::Twitter, twitter.service);
+    assert_eq!("@jdoe", twitter.handle());
+    let mastodon = SocialProfile::with_service("mastodon", "https://example.com/@jdoe").unwrap();
+    assert_eq!("@jdoe@example.com", mastodon.handle());
+    let other = SocialProfile::with_service("SomeSite", "peter94").unwrap();
+    assert_eq!(Service::Other("somesite".into()), other.service);
+    assert_eq!(None, other.uri());
+    assert_eq!("peter94", other.to_string());
+    assert_eq!(None, SocialProfile::with_service("skype", "two words"));
+
+    let made = SocialProfile {
+        service: Service::GitHub,
+        username: "jdoe".into(),
+        domain: None,
+        text: None,
+    };
+    assert_eq!("https://github.com/jdoe", made.to_string());
+
+    let mut changed = profile("http://www.twitter.com/jdoe/");
+    changed.username = "alice".into();
+    assert_eq!("https://twitter.com/alice", changed.to_string());
+    let mut changed = profile("@Gargron@Mastodon.Social");
+    changed.domain = Some("example.com".into());
+    assert_eq!("https://example.com/@Gargron", changed.to_string());
+    let mut changed = SocialProfile::with_service("Twitter", "x-apple:jdoe").unwrap();
+    assert_eq!("x-apple:jdoe", changed.to_string());
+    changed.username = "alice".into();
+    assert_eq!("https://twitter.com/alice", changed.to_string());
+}
+
+#[test]
+fn reject() {
+    for input in &[
+        "",
+        "jdoe",
+        "xmpp:example.com",
+        "skype:",
+        "aim:goim",
+        "https://example.com/jdoe",
+        "https://linkedin.com/company/acme",
+        "ftp://twitter.com/jdoe",
+    ] {
+        assert!(SocialProfile::from_text(input).is_none(), "{}", input);
+    }
+}
+
+#[test]
+fn vcard_profiles() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD
+VERSION:4.0
+IMPP;PREF=1:xmpp:alice@example.com
+IMPP;X-SERVICE-TYPE=Skype:echo123
+SOCIALPROFILE;SERVICE-TYPE=Mastodon:https://example.com/@jdoe
+X-SOCIALPROFILE;TYPE=twitter;X-USER=jdoe:x-apple:
+X-SOCIALPROFILE;TYPE=linkedin:http://www.linkedin.com/in/jdoe
+END:VCARD
+",
+    )
+    .unwrap();
+    let services: Vec<_> = vcard
+        .social_profiles()
+        .into_iter()
+        .map(|x| (x.service.clone(), x.handle()))
+        .collect();
+    assert_eq!(
+        services,
+        vec![
+            (Service::Xmpp, "alice@example.com".to_string()),
+            (Service::Skype, "echo123".to_string()),
+            (Service::Mastodon, "@jdoe@example.com".to_string()),
+            (Service::Twitter, "@jdoe".to_string()),
+            (Service::LinkedIn, "jdoe".to_string()),
+        ]
+    );
+    assert!(matches!(
+        vcard.iter().nth(1).unwrap().value,
+        Value::Impp(TypeOrRaw::Type(_))
+    ));
+    assert!(matches!(
+        vcard.iter().nth(3).unwrap().value,
+        Value::SocialProfile(TypeOrRaw::Raw(_))
+    ));
+}
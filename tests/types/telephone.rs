@@ -0,0 +1,120 @@
+use rolodex::types::{Telephone, TelephoneType};
+use rolodex::*;
+
+fn telephone(value: &str) -> Telephone<'_> {
+    match Telephone::parse(value) {
+        Ok(("", telephone)) => telephone,
+        other => panic!("{}: {:?}", value, other),
+    }
+}
+
+#[test]
+fn text() {
+    let plain = telephone("(111) 555-1212");
+    assert_eq!("(111) 555-1212", plain.number);
+    assert_eq!(None, plain.extension);
+    assert!(!plain.uri);
+    assert!(!plain.is_global());
+    assert_eq!("1115551212", plain.digits());
+
+    for input in &[
+        "+1 555 1212 x102",
+        "+1 555 1212 ext. 102",
+        "+1 555 1212 Ext 102",
+        "+1 555 1212 extension 102",
+    ] {
+        let number = telephone(input);
+        assert_eq!("+1 555 1212", number.number, "{}", input);
+        assert_eq!(Some("102".into()), number.extension, "{}", input);
+        assert_eq!(*input, number.to_string());
+    }
+    let mut number = telephone("+1 555 1212");
+    number.extension = Some("102".into());
+    assert_eq!("+1 555 1212 x102", number.to_string());
+}
+
+#[test]
+fn uri() {
+    let number = telephone("tel:+1-555-1212;ext=102;isub=1234;phone-context=example.com");
+    assert!(number.uri);
+    assert!(number.is_global());
+    assert_eq!("+1-555-1212", number.number);
+    assert_eq!(Some("102".into()), number.extension);
+    assert_eq!(Some("1234".into()), number.isdn_subaddress);
+    assert_eq!(
+        vec![("phone-context".into(), Some("example.com".into()))],
+        number.parameters
+    );
+    assert_eq!("+15551212", number.digits());
+    assert_eq!("*21#", telephone("tel:*21%23;phone-context=+1").digits());
+
+    for input in &[
+        "tel:+1-555-1212",
+        "tel:+1-555-1212;ext=102;isub=1234;phone-context=example.com",
+        "tel:*21%23;phone-context=+1",
+    ] {
+        assert_eq!(*input, telephone(input).to_string());
+    }
+}
+
+#[test]
+fn reject() {
+    for input in &[
+        "",
+        "unknown",
+        "555x",
+        "tel:",
+        "tel:1-800-FLOWERS",
+        "tel:+1 555",
+    ] {
+        assert!(
+            !matches!(Telephone::parse(input), Ok(("", _))),
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn types() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD\nVERSION:4.0\nFN:Forrest Gump\n\
+         TEL;VALUE=uri;TYPE=\"voice,home\":tel:+1-404-555-1212\n\
+         TEL;VALUE=uri;TYPE=cell;PREF=2:tel:+1-404-555-0001\n\
+         TEL;VALUE=uri;TYPE=cell,text;PREF=1:tel:+1-404-555-0002\n\
+         TEL;VALUE=uri;TYPE=fax:tel:+1-404-555-0003\n\
+         TEL:+1-404-555-0004\nEND:VCARD\n",
+    )
+    .unwrap();
+    let number = |ty| match &vcard.telephone(ty)?.value {
+        Value::Tel(TypeOrRaw::Type(tel)) => Some(tel.number.to_string()),
+        _ => None,
+    };
+    assert_eq!(Some("+1-404-555-1212".into()), number(TelephoneType::Voice));
+    assert_eq!(Some("+1-404-555-0002".into()), number(TelephoneType::Cell));
+    assert_eq!(Some("+1-404-555-0002".into()), number(TelephoneType::Text));
+    assert_eq!(Some("+1-404-555-0003".into()), number(TelephoneType::Fax));
+    assert_eq!(None, number(TelephoneType::Pager));
+
+    let types = vcard
+        .iter()
+        .filter(|x| matches!(x.value, Value::Tel(_)))
+        .map(|x| x.params.telephone_types())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![TelephoneType::Cell, TelephoneType::Text], types[2]);
+    assert_eq!(vec![TelephoneType::Voice], types[4]);
+}
+
+#[test]
+fn legacy_types() {
+    let vcard = VCard::parse(
+        "BEGIN:VCARD\nVERSION:2.1\nFN:Forrest Gump\n\
+         TEL;WORK;VOICE:(111) 555-1212\nTEL;CELL;PREF:(111) 555-0001\n\
+         TEL;TYPE=CELL:(111) 555-0002\nEND:VCARD\n",
+    )
+    .unwrap();
+    let cell = vcard.telephone(TelephoneType::Cell).unwrap();
+    assert_eq!(Some(1), cell.params.preference());
+    assert_eq!(vec!["cell", "pref"], cell.params.types());
+    assert_eq!("(111) 555-0001", cell.value.to_string());
+}
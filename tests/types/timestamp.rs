@@ -0,0 +1,42 @@
+use chrono::{TimeZone, Utc};
+use rolodex::types::Timestamp;
+use rolodex::*;
+
+fn timestamp(value: &str) -> Timestamp {
+    match Timestamp::parse(value) {
+        Ok(("", timestamp)) => timestamp,
+        other => panic!("{}: {:?}", value, other),
+    }
+}
+
+#[test]
+fn parse() {
+    let expected = Timestamp(Utc.with_ymd_and_hms(2008, 4, 24, 19, 52, 43).unwrap());
+    assert_eq!(expected, timestamp("20080424T195243Z"));
+    assert_eq!(expected, timestamp("2008-04-24T19:52:43Z"));
+    assert_eq!(expected, timestamp("20080424T195243"));
+    assert_eq!(expected, timestamp("20080424T145243-0500"));
+    assert_eq!(expected, timestamp("20080425T015243+06"));
+    assert_eq!("20080424T195243Z", expected.to_string());
+
+    let minutes = Timestamp(Utc.with_ymd_and_hms(2008, 4, 24, 19, 52, 0).unwrap());
+    assert_eq!(minutes, timestamp("20080424T1952Z"));
+    assert_eq!(minutes, timestamp("20080424T1952"));
+
+    for input in &["20080424", "20080424T19", "T195243Z", "yesterday"] {
+        assert!(!matches!(Timestamp::parse(input), Ok(("", _))), "{}", input);
+    }
+}
+
+#[test]
+fn compare() {
+    assert!(timestamp("20080424T195243Z") < timestamp("20080424T195244Z"));
+    assert!(timestamp("20080424T205243+0200") < timestamp("20080424T195243Z"));
+    assert_eq!(
+        Some(timestamp("20080424T195243Z")),
+        [timestamp("20080424T195243Z"), timestamp("19991231T235959Z")]
+            .iter()
+            .copied()
+            .max()
+    );
+}
@@ -0,0 +1,92 @@
+use chrono::{FixedOffset, TimeZone as _, Utc};
+use rolodex::types::TimeZone;
+use rolodex::*;
+
+fn zone(value: &str) -> TimeZone<'_> {
+    match TimeZone::parse(value) {
+        Ok(("", zone)) => zone,
+        other => panic!("{}: {:?}", value, other),
+    }
+}
+
+#[test]
+fn parse() {
+    assert_eq!(TimeZone::Offset(-300), zone("-05:00"));
+    assert_eq!(TimeZone::Offset(-300), zone("-0500"));
+    assert_eq!(TimeZone::Offset(330), zone("+0530"));
+    assert_eq!(TimeZone::Offset(0), zone("Z"));
+    assert_eq!(
+        TimeZone::Name("America/New_York".into()),
+        zone("America/New_York")
+    );
+    assert_eq!(
+        TimeZone::Uri("http://example.com/tz/America-New_York".into()),
+        zone("http://example.com/tz/America-New_York")
+    );
+    assert_eq!("-0500", zone("-05:00").to_string());
+    assert_eq!("America/New_York", zone("America/New_York").to_string());
+
+    let text = "-05:00\\; EST\\; Raleigh/North America";
+    assert_eq!(
+        TimeZone::Name("-05:00; EST; Raleigh/North America".into()),
+        zone(text)
+    );
+    assert_eq!(text, zone(text).to_string());
+    assert!(TimeZone::parse("").is_err());
+}
+
+#[test]
+fn offset() {
+    let winter = Utc.with_ymd_and_hms(2021, 1, 15, 12, 0, 0).unwrap();
+    assert_eq!(
+        FixedOffset::west_opt(5 * 3600),
+        zone("-05:00").offset_at(winter)
+    );
+    assert_eq!(None, zone("http://example.com/tz/1").offset_at(winter));
+    assert_eq!(None, zone("Nowhere/Special").offset_at(winter));
+
+    let local = zone("+0530").local_time(winter).unwrap();
+    assert_eq!("2021-01-15T17:30:00+05:30", local.to_rfc3339());
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn named() {
+    let winter = Utc.with_ymd_and_hms(2021, 1, 15, 12, 0, 0).unwrap();
+    let summer = Utc.with_ymd_and_hms(2021, 7, 15, 12, 0, 0).unwrap();
+    let new_york = zone("America/New_York");
+    assert_eq!(FixedOffset::west_opt(5 * 3600), new_york.offset_at(winter));
+    assert_eq!(FixedOffset::west_opt(4 * 3600), new_york.offset_at(summer));
+}
+
+#[test]
+fn vcard() {
+    let at = Utc.with_ymd_and_hms(2021, 1, 15, 12, 0, 0).unwrap();
+    let vcard =
+        VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Forrest Gump\nTZ:-05:00\nNOTE:Run\nEND:VCARD\n")
+            .unwrap();
+    assert_eq!(Some(TimeZone::Offset(-300)), vcard.timezone());
+    assert_eq!(
+        "2021-01-15T07:00:00-05:00",
+        vcard.local_time(at).unwrap().to_rfc3339()
+    );
+    assert!(vcard.to_string().contains("\r\nTZ:-0500\r\n"));
+
+    let vcard = VCard::parse(
+        "BEGIN:VCARD\nVERSION:4.0\nFN:Forrest Gump\nADR;TZ=\"+01:00\":;;Main St;Paris;;;France\nEND:VCARD\n",
+    )
+    .unwrap();
+    assert_eq!(Some(TimeZone::Offset(60)), vcard.timezone());
+    assert_eq!(
+        Some(TimeZone::Offset(60)),
+        vcard.iter().find_map(|x| x.params.timezone())
+    );
+    assert_eq!(
+        "2021-01-15T13:00:00+01:00",
+        vcard.local_time(at).unwrap().to_rfc3339()
+    );
+
+    let vcard = VCard::parse("BEGIN:VCARD\nVERSION:4.0\nFN:Forrest Gump\nEND:VCARD\n").unwrap();
+    assert_eq!(None, vcard.timezone());
+    assert_eq!(None, vcard.local_time(at));
+}
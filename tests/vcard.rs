@@ -1,3 +1,11 @@
+#[cfg(feature = "directory")]
+mod directory;
 mod examples;
+mod formats;
+mod occasion;
 mod parameters;
+#[cfg(feature = "phone")]
+mod phone;
+mod proximity;
+mod revise;
 mod types;